@@ -0,0 +1,20 @@
+// Generated by bmfont_rs, do not edit.
+
+pub static SMALL: ::bmfont_rs::StaticFont = ::bmfont_rs::StaticFont::new(
+    "Small Test",
+    32,
+    ::bmfont_rs::Common::new(32, 24, 1024, 2048, 1, false, ::bmfont_rs::Packing::Glyph, ::bmfont_rs::Packing::GlyphOutline, ::bmfont_rs::Packing::One, ::bmfont_rs::Packing::Zero),
+    &["small_sheet_0.png"],
+    &SMALL_CHARS,
+    &SMALL_KERNINGS,
+);
+
+pub static SMALL_CHARS: [::bmfont_rs::Char; 2] = [
+    ::bmfont_rs::Char::new(10, 281, 9, 4, 7, 2, 24, 8, 0, ::bmfont_rs::Chnl::ALL),
+    ::bmfont_rs::Char::new(32, 0, 0, 7, 20, 4, 17, 9, 0, ::bmfont_rs::Chnl::RED),
+];
+
+pub static SMALL_KERNINGS: [::bmfont_rs::Kerning; 2] = [
+    ::bmfont_rs::Kerning::new(10, 32, -2),
+    ::bmfont_rs::Kerning::new(32, 10, 1),
+];
@@ -0,0 +1,43 @@
+//! Minimal async read/ write helpers over the `futures-io` traits.
+//!
+//! We avoid a dependency on `futures-util` by polling the traits directly.
+use futures_io::{AsyncRead, AsyncWrite};
+
+use std::future;
+use std::io;
+use std::pin::Pin;
+
+/// Initial read chunk length.
+const CHUNK_LEN: usize = 0x2000;
+
+/// Read all bytes until EOF, appending them to `dst`.
+pub async fn read_to_end<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    dst: &mut Vec<u8>,
+) -> io::Result<usize> {
+    let mark = dst.len();
+    let mut chunk = vec![0; CHUNK_LEN];
+    loop {
+        let n = future::poll_fn(|cx| Pin::new(&mut *reader).poll_read(cx, &mut chunk)).await;
+        match n {
+            Ok(0) => return Ok(dst.len() - mark),
+            Ok(n) => dst.extend_from_slice(&chunk[..n]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Write all bytes, then flush.
+pub async fn write_all<W: AsyncWrite + Unpin>(writer: &mut W, mut src: &[u8]) -> io::Result<()> {
+    while !src.is_empty() {
+        let n = future::poll_fn(|cx| Pin::new(&mut *writer).poll_write(cx, src)).await;
+        match n {
+            Ok(0) => return Err(io::ErrorKind::WriteZero.into()),
+            Ok(n) => src = &src[n..],
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    future::poll_fn(|cx| Pin::new(&mut *writer).poll_flush(cx)).await
+}
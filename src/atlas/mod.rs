@@ -0,0 +1,58 @@
+//! Sprite atlas operations.
+//!
+//! Export font pages as TexturePacker style JSON (hash) sprite atlases, one atlas per page, so
+//! that glyphs may be reused as sprites.
+//!
+//! Each character on the page becomes a named frame:
+//! * `frame`: `x`, `y`, `width`, `height`
+//! * `spriteSourceSize`: `xoffset`, `yoffset`, `width`, `height`
+//! * `sourceSize`: `xadvance`, `lineHeight`
+//!
+//! The source box spans the character advance and line height. Negative offsets, or glyphs that
+//! overhang the advance or line height, extend the source box to contain the glyph, with the
+//! `spriteSourceSize` offsets adjusted accordingly.
+//!
+//! ```json
+//! {"frames": {
+//! "U+0041":
+//! {
+//!     "frame": {"x":0,"y":0,"w":7,"h":20},
+//!     "rotated": false,
+//!     "trimmed": true,
+//!     "spriteSourceSize": {"x":1,"y":4,"w":7,"h":20},
+//!     "sourceSize": {"w":9,"h":32}
+//! }},
+//! "meta": {
+//!     "app": "bmfont_rs",
+//!     "version": "1.0",
+//!     "image": "font_0.png",
+//!     "format": "RGBA8888",
+//!     "size": {"w":256,"h":256},
+//!     "scale": "1"
+//! }
+//! }
+//! ```
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::fs;
+//!
+//! fn main() -> bmfont_rs::Result<()> {
+//!     let font = bmfont_rs::text::from_bytes(&fs::read("font.fnt")?)?;
+//!     let settings = bmfont_rs::atlas::AtlasSettings::default();
+//!     for (page, json) in bmfont_rs::atlas::to_strings(&font, &settings)?.iter().enumerate() {
+//!         fs::write(format!("font_{}.json", page), json)?;
+//!     }
+//!     Ok(())
+//! }
+//! ```
+
+mod settings;
+mod store;
+
+pub use settings::{AtlasSettings, Naming};
+pub use store::{to_string, to_strings, to_vec};
+
+#[cfg(feature = "std")]
+pub use store::to_writer;
@@ -0,0 +1,67 @@
+use crate::prelude::*;
+
+/// Sprite atlas export settings.
+///
+/// # Example
+///
+/// ```
+/// use bmfont_rs::atlas::{AtlasSettings, Naming};
+///
+/// let settings = AtlasSettings::default().naming(Naming::Decimal).prefix("glyph_");
+/// assert_eq!(settings.name(65), "glyph_65");
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct AtlasSettings {
+    /// Frame naming scheme.
+    pub naming: Naming,
+    /// Frame name prefix.
+    pub prefix: String,
+}
+
+impl AtlasSettings {
+    /// Set naming. Returns self.
+    pub fn naming(mut self, naming: Naming) -> Self {
+        self.naming = naming;
+        self
+    }
+
+    /// Set prefix. Returns self.
+    pub fn prefix(mut self, prefix: &str) -> Self {
+        self.prefix = prefix.to_owned();
+        self
+    }
+
+    /// The frame name for the specified character id.
+    pub fn name(&self, id: u32) -> String {
+        let mut name = self.prefix.clone();
+        match self.naming {
+            Naming::Unicode => name.push_str(&format!("U+{:04X}", id)),
+            Naming::Decimal => name.push_str(&id.to_string()),
+            Naming::Character => match core::char::from_u32(id) {
+                Some(c) if !c.is_control() => name.push(c),
+                _ => name.push_str(&format!("U+{:04X}", id)),
+            },
+        }
+        name
+    }
+}
+
+/// Frame naming scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Naming {
+    /// Unicode code point notation, e.g. `U+0041`.
+    Unicode,
+    /// Decimal character id, e.g. `65`.
+    Decimal,
+    /// The character itself, e.g. `A`. Control characters and ids that are not Unicode scalar
+    /// values fall back to Unicode code point notation.
+    Character,
+}
+
+impl Default for Naming {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::Unicode
+    }
+}
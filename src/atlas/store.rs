@@ -0,0 +1,165 @@
+use crate::font::{Char, Font};
+use crate::json_escape::Escape;
+use crate::prelude::*;
+
+use alloc::collections::BTreeSet;
+use core::fmt::{self, Write};
+
+#[cfg(feature = "std")]
+use std::io;
+
+use super::AtlasSettings;
+
+/// Store sprite atlas.
+///
+/// Store the specified font page into a [String] as a TexturePacker style JSON (hash) sprite
+/// atlas.
+///
+/// Packed fonts, those with character data in individual texture channels, are not supported.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) if the page does not exist, the font is packed or frame names are not
+///   unique.
+///
+/// # Example
+///
+/// ```
+/// fn main() -> bmfont_rs::Result<()> {
+///     let mut font = bmfont_rs::Font::default();
+///     font.pages.push("font_0.png".to_owned());
+///     font.chars.push(bmfont_rs::Char { id: 65, ..Default::default() });
+///     let settings = bmfont_rs::atlas::AtlasSettings::default();
+///     let string = bmfont_rs::atlas::to_string(&font, 0, &settings)?;
+///     assert!(string.contains("\"U+0041\":"));
+///     Ok(())
+/// }
+/// ```
+pub fn to_string(font: &Font, page: usize, settings: &AtlasSettings) -> crate::Result<String> {
+    if font.common.packed {
+        return Err(unsupported("packed fonts are not supported".to_owned()));
+    }
+    let image = font.pages.get(page).ok_or(crate::Error::InvalidPage { id: page as u32 })?;
+    let mut names = BTreeSet::default();
+    let mut frames = Vec::default();
+    for char in font.chars.iter().filter(|u| u.page as usize == page) {
+        let name = settings.name(char.id);
+        if !names.insert(name.clone()) {
+            return Err(crate::Error::DuplicateFrameName { name });
+        }
+        frames.push((name, char));
+    }
+    let mut dst = String::default();
+    store(&mut dst, font, image, &frames)
+        .map_err(|e| crate::Error::Internal { err: e.to_string() })?;
+    Ok(dst)
+}
+
+/// Store sprite atlases.
+///
+/// Store each font page into a [String] as a TexturePacker style JSON (hash) sprite atlas. The
+/// index corresponds to the page id.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) if the font is packed or frame names are not unique.
+pub fn to_strings(font: &Font, settings: &AtlasSettings) -> crate::Result<Vec<String>> {
+    (0..font.pages.len()).map(|page| to_string(font, page, settings)).collect()
+}
+
+/// Store sprite atlas.
+///
+/// Store the specified font page into a [Vec] as a TexturePacker style JSON (hash) sprite atlas.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) if the page does not exist, the font is packed or frame names are not
+///   unique.
+pub fn to_vec(font: &Font, page: usize, settings: &AtlasSettings) -> crate::Result<Vec<u8>> {
+    to_string(font, page, settings).map(String::into_bytes)
+}
+
+/// Write sprite atlas.
+///
+/// Write the specified font page to the specified writer as a TexturePacker style JSON (hash)
+/// sprite atlas.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) if the page does not exist, the font is packed or frame names are not
+///   unique.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let settings = bmfont_rs::atlas::AtlasSettings::default();
+///     let mut writer = File::create("font_0.json")?;
+///     bmfont_rs::atlas::to_writer(&mut writer, &font, 0, &settings)?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn to_writer<W: io::Write>(
+    mut writer: W,
+    font: &Font,
+    page: usize,
+    settings: &AtlasSettings,
+) -> crate::Result<()> {
+    let string = to_string(font, page, settings)?;
+    writer.write_all(string.as_bytes())?;
+    Ok(())
+}
+
+fn store(dst: &mut String, font: &Font, image: &str, frames: &[(String, &Char)]) -> fmt::Result {
+    let line_height = font.common.line_height as i32;
+    writeln!(dst, "{{\"frames\": {{")?;
+    for (i, (name, char)) in frames.iter().enumerate() {
+        let (width, height) = (char.width as i32, char.height as i32);
+        let (xoffset, yoffset) = (char.xoffset as i32, char.yoffset as i32);
+        let (left, top) = (xoffset.min(0), yoffset.min(0));
+        let right = (char.xadvance as i32).max(xoffset + width);
+        let bottom = line_height.max(yoffset + height);
+        let (x, y) = (xoffset - left, yoffset - top);
+        let (source_w, source_h) = (right - left, bottom - top);
+        let trimmed = x != 0 || y != 0 || width != source_w || height != source_h;
+        writeln!(dst, "\"{}\":", Escape(name))?;
+        writeln!(dst, "{{")?;
+        writeln!(
+            dst,
+            "\t\"frame\": {{\"x\":{},\"y\":{},\"w\":{},\"h\":{}}},",
+            char.x, char.y, width, height
+        )?;
+        writeln!(dst, "\t\"rotated\": false,")?;
+        writeln!(dst, "\t\"trimmed\": {},", trimmed)?;
+        writeln!(
+            dst,
+            "\t\"spriteSourceSize\": {{\"x\":{},\"y\":{},\"w\":{},\"h\":{}}},",
+            x, y, width, height
+        )?;
+        writeln!(dst, "\t\"sourceSize\": {{\"w\":{},\"h\":{}}}", source_w, source_h)?;
+        if i + 1 == frames.len() {
+            writeln!(dst, "}}")?;
+        } else {
+            writeln!(dst, "}},")?;
+        }
+    }
+    writeln!(dst, "}},")?;
+    writeln!(dst, "\"meta\": {{")?;
+    writeln!(dst, "\t\"app\": \"bmfont_rs\",")?;
+    writeln!(dst, "\t\"version\": \"1.0\",")?;
+    writeln!(dst, "\t\"image\": \"{}\",", Escape(image))?;
+    writeln!(dst, "\t\"format\": \"RGBA8888\",")?;
+    writeln!(dst, "\t\"size\": {{\"w\":{},\"h\":{}}},", font.common.scale_w, font.common.scale_h)?;
+    writeln!(dst, "\t\"scale\": \"1\"")?;
+    writeln!(dst, "}}")?;
+    writeln!(dst, "}}")
+}
+
+fn unsupported(err: String) -> crate::Error {
+    crate::Error::UnsupportedEncoding { line: None, entity: "atlas".to_owned(), err }
+}
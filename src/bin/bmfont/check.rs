@@ -0,0 +1,116 @@
+use bmfont_rs::Font;
+
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Diagnostic severity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Level {
+    Error,
+    Warning,
+}
+
+impl Level {
+    pub fn name(self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warning => "warning",
+        }
+    }
+}
+
+/// Validation diagnostic.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub level: Level,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn error(message: String) -> Self {
+        Self { level: Level::Error, message }
+    }
+
+    pub fn warning(message: String) -> Self {
+        Self { level: Level::Warning, message }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.level.name(), self.message)
+    }
+}
+
+/// Check a loaded font.
+///
+/// Errors, see [Font::validation_errors]:
+/// * a common `pages` count that disagrees with the page list
+/// * characters referencing non-existent pages
+/// * kerning pairs referencing non-existent characters
+///
+/// Warnings:
+/// * duplicate characters and kerning pairs
+/// * characters that extend beyond the texture, `scaleW` x `scaleH`
+pub fn check(font: &Font) -> Vec<Diagnostic> {
+    let mut diagnostics: Vec<Diagnostic> =
+        font.validation_errors().iter().map(|u| Diagnostic::error(u.to_string())).collect();
+    let mut ids = BTreeSet::default();
+    for char in &font.chars {
+        if !ids.insert(char.id) {
+            diagnostics.push(Diagnostic::warning(format!("char {}: duplicate", char.id)));
+        }
+        let right = char.x as u32 + char.width as u32;
+        let bottom = char.y as u32 + char.height as u32;
+        if right > font.common.scale_w as u32 || bottom > font.common.scale_h as u32 {
+            diagnostics.push(Diagnostic::warning(format!(
+                "char {}: extends beyond the {}x{} texture",
+                char.id, font.common.scale_w, font.common.scale_h
+            )));
+        }
+    }
+    let mut pairs = BTreeSet::default();
+    for kerning in &font.kernings {
+        if !pairs.insert((kerning.first, kerning.second)) {
+            diagnostics.push(Diagnostic::warning(format!(
+                "kerning {} {}: duplicate",
+                kerning.first, kerning.second
+            )));
+        }
+    }
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use bmfont_rs::{Char, Kerning};
+
+    #[test]
+    fn check_ok() {
+        let mut font = Font::default();
+        font.common.pages = 1;
+        font.common.scale_w = 16;
+        font.common.scale_h = 16;
+        font.pages.push("font_0.png".to_owned());
+        font.chars.push(Char { id: 65, width: 8, height: 8, ..Default::default() });
+        font.kernings.push(Kerning::new(65, 65, -1));
+        assert_eq!(check(&font), Vec::default());
+    }
+
+    #[test]
+    fn check_diagnostics() {
+        let mut font = Font::default();
+        font.chars.push(Char { id: 65, width: 8, ..Default::default() });
+        font.chars.push(Char { id: 65, ..Default::default() });
+        font.kernings.push(Kerning::new(65, 66, -1));
+        let diagnostics = check(&font);
+        let levels = diagnostics.iter().map(|u| u.level).collect::<Vec<_>>();
+        assert_eq!(
+            levels,
+            [Level::Error, Level::Error, Level::Error, Level::Warning, Level::Warning]
+        );
+        assert_eq!(diagnostics[2].to_string(), "error: invalid kerning char: 66");
+    }
+}
@@ -0,0 +1,173 @@
+use bmfont_rs::spritefont::SpriteFont;
+use bmfont_rs::{Font, LoadReport, LoadSettings, TextEncoding};
+
+use clap::ValueEnum;
+
+use std::path::Path;
+
+/// Font file format.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum FontFormat {
+    /// BMFont text
+    Text,
+    /// BMFont binary
+    Binary,
+    /// BMFont XML
+    Xml,
+    /// BMFont JSON
+    Json,
+    /// YAML
+    Yaml,
+    /// TOML
+    Toml,
+    /// MessagePack
+    Msgpack,
+    /// CBOR
+    Cbor,
+    /// XML property list (Cocos2d/ Sparrow)
+    Plist,
+    /// Godot 4 FontFile text resource
+    Godot,
+    /// XNA/ MonoGame sprite font XML
+    Spritefont,
+    /// Unity TextMesh Pro font asset JSON, store only
+    Textmeshpro,
+}
+
+impl FontFormat {
+    /// The format name, as accepted on the command line.
+    pub fn name(self) -> &'static str {
+        match self {
+            FontFormat::Text => "text",
+            FontFormat::Binary => "binary",
+            FontFormat::Xml => "xml",
+            FontFormat::Json => "json",
+            FontFormat::Yaml => "yaml",
+            FontFormat::Toml => "toml",
+            FontFormat::Msgpack => "msgpack",
+            FontFormat::Cbor => "cbor",
+            FontFormat::Plist => "plist",
+            FontFormat::Godot => "godot",
+            FontFormat::Spritefont => "spritefont",
+            FontFormat::Textmeshpro => "textmeshpro",
+        }
+    }
+
+    /// Detect the format from the file extension.
+    ///
+    /// The `.fnt` extension is shared by the text, binary and XML formats and is not detected.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let name = path.file_name()?.to_str()?.to_ascii_lowercase();
+        if name.ends_with(".spritefont.xml") || name.ends_with(".spritefont") {
+            return Some(FontFormat::Spritefont);
+        }
+        if name.ends_with(".tmp.json") || name.ends_with(".textmeshpro.json") {
+            return Some(FontFormat::Textmeshpro);
+        }
+        let format = match path.extension()?.to_str()?.to_ascii_lowercase().as_str() {
+            "txt" => FontFormat::Text,
+            "bin" => FontFormat::Binary,
+            "xml" => FontFormat::Xml,
+            "json" => FontFormat::Json,
+            "yaml" | "yml" => FontFormat::Yaml,
+            "toml" => FontFormat::Toml,
+            "msgpack" | "mpk" => FontFormat::Msgpack,
+            "cbor" => FontFormat::Cbor,
+            "plist" => FontFormat::Plist,
+            "tres" => FontFormat::Godot,
+            _ => return None,
+        };
+        Some(format)
+    }
+
+    /// Detect the format from the file content.
+    pub fn detect(bytes: &[u8]) -> Option<Self> {
+        if bytes.starts_with(b"BMF") {
+            return Some(FontFormat::Binary);
+        }
+        match bytes.first()? {
+            // MessagePack/ CBOR five entry maps: info, common, pages, chars, kernings.
+            0x85 => return Some(FontFormat::Msgpack),
+            0xA5 => return Some(FontFormat::Cbor),
+            _ => {}
+        }
+        let text = match bmfont_rs::text::detect_encoding(bytes) {
+            TextEncoding::Utf8 => {
+                String::from_utf8_lossy(bytes.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(bytes))
+            }
+            // UTF-16 and legacy encodings are only produced by the text format.
+            _ => return Some(FontFormat::Text),
+        };
+        let text = text.trim_start();
+        let format = if text.starts_with('<') {
+            if text.contains("<plist") {
+                FontFormat::Plist
+            } else if text.contains("<XnaContent") {
+                FontFormat::Spritefont
+            } else {
+                FontFormat::Xml
+            }
+        } else if text.starts_with('{') {
+            if text.contains("\"m_FaceInfo\"") {
+                FontFormat::Textmeshpro
+            } else {
+                FontFormat::Json
+            }
+        } else if text.starts_with("[gd_resource") {
+            FontFormat::Godot
+        } else if text.starts_with("info ") || text.starts_with("info\t") {
+            FontFormat::Text
+        } else if text.starts_with("info:") || text.starts_with("---") {
+            FontFormat::Yaml
+        } else if text.starts_with('[') || text.lines().next()?.contains(" = ") {
+            FontFormat::Toml
+        } else {
+            return None;
+        };
+        Some(format)
+    }
+
+    /// Load a font, with warnings where the format reports them.
+    pub fn load(self, bytes: &[u8]) -> bmfont_rs::Result<LoadReport> {
+        let settings = LoadSettings::default().detect_encoding();
+        let font = match self {
+            FontFormat::Text => return bmfont_rs::text::from_bytes_report(bytes, &settings),
+            FontFormat::Xml => return bmfont_rs::xml::from_bytes_report(bytes, &settings),
+            FontFormat::Plist => return bmfont_rs::plist::from_bytes_report(bytes, &settings),
+            FontFormat::Binary => bmfont_rs::binary::from_bytes_ext(bytes, &settings)?,
+            FontFormat::Json => bmfont_rs::json::from_bytes_ext(bytes, &settings)?,
+            FontFormat::Yaml => bmfont_rs::yaml::from_bytes_ext(bytes, &settings)?,
+            FontFormat::Toml => bmfont_rs::toml::from_bytes_ext(bytes, &settings)?,
+            FontFormat::Msgpack => bmfont_rs::msgpack::from_bytes_ext(bytes, &settings)?,
+            FontFormat::Cbor => bmfont_rs::cbor::from_bytes_ext(bytes, &settings)?,
+            FontFormat::Godot => bmfont_rs::godot::from_bytes_ext(bytes, &settings)?,
+            FontFormat::Spritefont => bmfont_rs::spritefont::from_bytes(bytes)?.to_font()?,
+            FontFormat::Textmeshpro => {
+                return Err(bmfont_rs::Error::UnsupportedEncoding {
+                    line: None,
+                    entity: "textmeshpro".to_owned(),
+                    err: "load is not supported".to_owned(),
+                })
+            }
+        };
+        Ok(LoadReport::new(font, TextEncoding::Utf8, Vec::default()))
+    }
+
+    /// Store a font.
+    pub fn store(self, font: &Font) -> bmfont_rs::Result<Vec<u8>> {
+        match self {
+            FontFormat::Text => bmfont_rs::text::to_vec(font),
+            FontFormat::Binary => bmfont_rs::binary::to_vec(font),
+            FontFormat::Xml => bmfont_rs::xml::to_vec(font),
+            FontFormat::Json => bmfont_rs::json::to_vec_pretty(font),
+            FontFormat::Yaml => bmfont_rs::yaml::to_vec(font),
+            FontFormat::Toml => bmfont_rs::toml::to_vec(font),
+            FontFormat::Msgpack => bmfont_rs::msgpack::to_vec(font),
+            FontFormat::Cbor => bmfont_rs::cbor::to_vec(font),
+            FontFormat::Plist => bmfont_rs::plist::to_vec(font),
+            FontFormat::Godot => bmfont_rs::godot::to_vec(font),
+            FontFormat::Spritefont => bmfont_rs::spritefont::to_vec(&SpriteFont::from_font(font)?),
+            FontFormat::Textmeshpro => bmfont_rs::textmeshpro::to_vec(font),
+        }
+    }
+}
@@ -0,0 +1,365 @@
+//! BMFont command line tool.
+//!
+//! Convert, inspect, validate and normalize BMFont descriptor files.
+//!
+//! `cargo run --features cli --bin bmfont -- --help`
+
+mod check;
+mod format;
+mod map;
+
+use bmfont_rs::{Font, LoadReport, Padding};
+use check::{Diagnostic, Level};
+use format::FontFormat;
+
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use serde_json::json;
+
+use std::error::Error;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+use std::result;
+
+type Result<T> = result::Result<T, Box<dyn Error>>;
+
+/// BMFont descriptor conversion and inspection.
+///
+/// Input and output paths default to stdin/ stdout, `-` may also be used. Input formats are
+/// detected from the content, output formats from the file extension.
+#[derive(Debug, Parser)]
+#[command(name = "bmfont", version)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Convert a font from one format to another.
+    Convert(Convert),
+    /// Summarize a font: info, common, pages, character and kerning pair counts.
+    Info(Inspect),
+    /// Validate a font, exit code 1 on errors.
+    Validate(Inspect),
+    /// Normalize a font: sort characters and kerning pairs and remove duplicates.
+    Normalize(Convert),
+    /// Retain the specified characters and the kerning pairs between them.
+    Subset(Subset),
+    /// Merge fonts, characters already present take precedence.
+    Merge(Merge),
+    /// Scale font metrics, page textures should be resampled separately.
+    Scale(Scale),
+    /// Remap character ids, as listed in a map file of `from to` id lines.
+    Remap(Remap),
+}
+
+#[derive(Debug, Args)]
+struct Input {
+    /// Input file, stdin if absent or `-`.
+    input: Option<PathBuf>,
+    /// Input format, detected if absent.
+    #[arg(short, long, value_enum)]
+    from: Option<FontFormat>,
+}
+
+#[derive(Debug, Args)]
+struct Output {
+    /// Output file, stdout if absent or `-`.
+    #[arg(short, long)]
+    output: Option<PathBuf>,
+    /// Output format, detected from the output file extension if absent.
+    #[arg(short, long, value_enum)]
+    to: Option<FontFormat>,
+}
+
+#[derive(Debug, Args)]
+struct Convert {
+    #[command(flatten)]
+    input: Input,
+    #[command(flatten)]
+    output: Output,
+}
+
+#[derive(Debug, Args)]
+#[command(group(ArgGroup::new("set").required(true).multiple(true)))]
+struct Subset {
+    #[command(flatten)]
+    input: Input,
+    /// UTF-8 file listing the characters to retain, line breaks are ignored.
+    #[arg(long, group = "set")]
+    chars_file: Option<PathBuf>,
+    /// Characters to retain.
+    #[arg(long, group = "set")]
+    chars: Option<String>,
+    #[command(flatten)]
+    output: Output,
+}
+
+#[derive(Debug, Args)]
+struct Merge {
+    /// Input files, in order of precedence.
+    #[arg(required = true, num_args = 2..)]
+    inputs: Vec<PathBuf>,
+    /// Input format, detected if absent.
+    #[arg(short, long, value_enum)]
+    from: Option<FontFormat>,
+    #[command(flatten)]
+    output: Output,
+}
+
+#[derive(Debug, Args)]
+struct Scale {
+    /// Scale factor, e.g. `0.5`.
+    factor: f64,
+    #[command(flatten)]
+    input: Input,
+    #[command(flatten)]
+    output: Output,
+}
+
+#[derive(Debug, Args)]
+struct Remap {
+    /// Map file, `from to` id lines, decimal, `0x` hexadecimal or `U+` code point.
+    map: PathBuf,
+    #[command(flatten)]
+    input: Input,
+    #[command(flatten)]
+    output: Output,
+}
+
+#[derive(Debug, Args)]
+struct Inspect {
+    #[command(flatten)]
+    input: Input,
+    /// JSON output.
+    #[arg(long)]
+    json: bool,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Convert(args) => convert(&args, false),
+        Command::Info(args) => info(&args),
+        Command::Validate(args) => validate(&args),
+        Command::Normalize(args) => convert(&args, true),
+        Command::Subset(args) => subset(&args),
+        Command::Merge(args) => merge(&args),
+        Command::Scale(args) => scale(&args),
+        Command::Remap(args) => remap(&args),
+    };
+    match result {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        // Closed pipe, e.g. `bmfont convert ... | head`.
+        Err(err) if is_broken_pipe(&*err) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("bmfont: error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn convert(args: &Convert, normalize: bool) -> Result<bool> {
+    let (from, mut report) = load(&args.input)?;
+    print_warnings(&report);
+    if normalize {
+        report.font.normalize();
+    }
+    // Normalize in place, format wise.
+    store(&args.output, if normalize { Some(from) } else { None }, &report.font)?;
+    Ok(true)
+}
+
+fn subset(args: &Subset) -> Result<bool> {
+    let (from, mut report) = load(&args.input)?;
+    print_warnings(&report);
+    let mut chars = args.chars.clone().unwrap_or_default();
+    if let Some(path) = &args.chars_file {
+        chars.push_str(&fs::read_to_string(path)?);
+    }
+    let ids = chars.chars().filter(|&u| u != '\n' && u != '\r').map(u32::from).collect();
+    report.font.subset(&ids);
+    edited(&args.output, from, &report.font)
+}
+
+fn merge(args: &Merge) -> Result<bool> {
+    let mut font: Option<(FontFormat, Font)> = None;
+    for path in &args.inputs {
+        let input = Input { input: Some(path.clone()), from: args.from };
+        let (from, report) = load(&input)?;
+        print_warnings(&report);
+        match &mut font {
+            Some((_, font)) => font.merge(&report.font)?,
+            None => font = Some((from, report.font)),
+        }
+    }
+    let (from, font) = font.ok_or("no inputs")?;
+    edited(&args.output, from, &font)
+}
+
+fn scale(args: &Scale) -> Result<bool> {
+    let (from, mut report) = load(&args.input)?;
+    print_warnings(&report);
+    report.font.scale(args.factor)?;
+    edited(&args.output, from, &report.font)
+}
+
+fn remap(args: &Remap) -> Result<bool> {
+    let map = map::parse(&fs::read_to_string(&args.map)?)
+        .map_err(|err| format!("{}: {}", args.map.display(), err))?;
+    let (from, mut report) = load(&args.input)?;
+    print_warnings(&report);
+    report.font.remap(&map)?;
+    edited(&args.output, from, &report.font)
+}
+
+/// Validate and store an edited font, by default in the input format.
+fn edited(output: &Output, from: FontFormat, font: &Font) -> Result<bool> {
+    font.validate_references().map_err(|err| format!("invalid result: {}", err))?;
+    store(output, Some(from), font)?;
+    Ok(true)
+}
+
+fn store(output: &Output, default: Option<FontFormat>, font: &Font) -> Result<()> {
+    let to = match (output.to, path(&output.output), default) {
+        (Some(format), _, _) => format,
+        (None, Some(path), default) => FontFormat::from_path(path)
+            .or(default)
+            .ok_or_else(|| format!("unknown output format: {}, use --to", path.display()))?,
+        (None, None, Some(format)) => format,
+        (None, None, None) => return Err("output format required, use --to".into()),
+    };
+    let bytes = to.store(font)?;
+    match path(&output.output) {
+        Some(path) => fs::write(path, bytes)?,
+        None => io::stdout().lock().write_all(&bytes)?,
+    }
+    Ok(())
+}
+
+fn info(args: &Inspect) -> Result<bool> {
+    let (format, report) = load(&args.input)?;
+    let font = &report.font;
+    if args.json {
+        let value = json!({
+            "format": format.name(),
+            "info": font.info,
+            "common": font.common,
+            "pages": font.pages,
+            "chars": font.chars.len(),
+            "kernings": font.kernings.len(),
+        });
+        println!("{}", serde_json::to_string_pretty(&value)?);
+    } else {
+        print_warnings(&report);
+        print_info(format, font);
+    }
+    Ok(true)
+}
+
+fn validate(args: &Inspect) -> Result<bool> {
+    let bytes = read(&args.input)?;
+    let format = detect(&args.input, &bytes);
+    let diagnostics = match format.as_ref().map(|u| u.load(&bytes)) {
+        Ok(Ok(report)) => {
+            let warnings = report.warnings.iter().map(|u| Diagnostic::warning(u.to_string()));
+            warnings.chain(check::check(&report.font)).collect()
+        }
+        Ok(Err(err)) => vec![Diagnostic::error(err.to_string())],
+        Err(err) => vec![Diagnostic::error(err.to_string())],
+    };
+    let format = format.ok();
+    let valid = diagnostics.iter().all(|u| u.level != Level::Error);
+    if args.json {
+        let diagnostics = diagnostics
+            .iter()
+            .map(|u| json!({ "level": u.level.name(), "message": u.message }))
+            .collect::<Vec<_>>();
+        let value = json!({
+            "valid": valid,
+            "format": format.map(FontFormat::name),
+            "diagnostics": diagnostics,
+        });
+        println!("{}", serde_json::to_string_pretty(&value)?);
+    } else {
+        for diagnostic in &diagnostics {
+            println!("{}", diagnostic);
+        }
+        println!("{}", if valid { "valid" } else { "invalid" });
+    }
+    Ok(valid)
+}
+
+fn load(input: &Input) -> Result<(FontFormat, LoadReport)> {
+    let bytes = read(input)?;
+    let format = detect(input, &bytes)?;
+    Ok((format, format.load(&bytes)?))
+}
+
+fn read(input: &Input) -> Result<Vec<u8>> {
+    let bytes = match path(&input.input) {
+        Some(path) => fs::read(path)?,
+        None => {
+            let mut bytes = Vec::default();
+            io::stdin().lock().read_to_end(&mut bytes)?;
+            bytes
+        }
+    };
+    Ok(bytes)
+}
+
+fn detect(input: &Input, bytes: &[u8]) -> Result<FontFormat> {
+    match input.from {
+        Some(format) => Ok(format),
+        None => Ok(FontFormat::detect(bytes).ok_or("unknown input format, use --from")?),
+    }
+}
+
+fn is_broken_pipe(err: &(dyn Error + 'static)) -> bool {
+    match err.downcast_ref::<io::Error>() {
+        Some(err) => err.kind() == io::ErrorKind::BrokenPipe,
+        None => false,
+    }
+}
+
+fn path(path: &Option<PathBuf>) -> Option<&Path> {
+    path.as_deref().filter(|u| *u != Path::new("-"))
+}
+
+fn print_warnings(report: &LoadReport) {
+    for warning in &report.warnings {
+        eprintln!("bmfont: warning: {}", warning);
+    }
+}
+
+fn print_info(format: FontFormat, font: &Font) {
+    let info = &font.info;
+    let common = &font.common;
+    println!("format:      {}", format.name());
+    println!("face:        {}", info.face);
+    println!("size:        {}", info.size);
+    println!("bold:        {}", info.bold);
+    println!("italic:      {}", info.italic);
+    println!("charset:     {}", info.charset);
+    println!("unicode:     {}", info.unicode);
+    println!("stretch_h:   {}", info.stretch_h);
+    println!("smooth:      {}", info.smooth);
+    println!("aa:          {}", info.aa);
+    let Padding { up, right, down, left } = info.padding;
+    println!("padding:     {},{},{},{}", up, right, down, left);
+    println!("spacing:     {},{}", info.spacing.horizontal, info.spacing.vertical);
+    println!("outline:     {}", info.outline);
+    println!("line_height: {}", common.line_height);
+    println!("base:        {}", common.base);
+    println!("scale:       {}x{}", common.scale_w, common.scale_h);
+    println!("packed:      {}", common.packed);
+    println!("pages:       {}", font.pages.len());
+    for (id, page) in font.pages.iter().enumerate() {
+        println!("  {}: {}", id, page);
+    }
+    println!("chars:       {}", font.chars.len());
+    println!("kernings:    {}", font.kernings.len());
+}
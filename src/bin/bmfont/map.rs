@@ -0,0 +1,55 @@
+use std::collections::BTreeMap;
+
+/// Parse a character id map.
+///
+/// One `from to` pair per line, whitespace separated. Ids are decimal, `0x` hexadecimal or `U+`
+/// code points. Empty lines and `#` comments are ignored.
+pub fn parse(src: &str) -> Result<BTreeMap<u32, u32>, String> {
+    let mut map = BTreeMap::default();
+    for (i, line) in src.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let err = |err: &str| format!("line: {}: {}: '{}'", i + 1, err, line);
+        let mut tokens = line.split_whitespace();
+        let (from, to) = match (tokens.next(), tokens.next(), tokens.next()) {
+            (Some(from), Some(to), None) => (from, to),
+            _ => return Err(err("expected 'from to'")),
+        };
+        let from = id(from).ok_or_else(|| err("invalid id"))?;
+        let to = id(to).ok_or_else(|| err("invalid id"))?;
+        if map.insert(from, to).is_some() {
+            return Err(err("duplicate id"));
+        }
+    }
+    Ok(map)
+}
+
+fn id(token: &str) -> Option<u32> {
+    if let Some(hex) = token.strip_prefix("0x").or_else(|| token.strip_prefix("0X")) {
+        u32::from_str_radix(hex, 16).ok()
+    } else if let Some(hex) = token.strip_prefix("U+").or_else(|| token.strip_prefix("u+")) {
+        u32::from_str_radix(hex, 16).ok()
+    } else {
+        token.parse().ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ok() {
+        let map = parse("# Latin to full width\n65 0xFF21\n\nU+0042 65314 # B\n").unwrap();
+        assert_eq!(map.into_iter().collect::<Vec<_>>(), [(65, 0xFF21), (0x42, 0xFF22)]);
+    }
+
+    #[test]
+    fn parse_err() {
+        assert_eq!(parse("65 66\n67").unwrap_err(), "line: 2: expected 'from to': '67'");
+        assert_eq!(parse("65 x").unwrap_err(), "line: 1: invalid id: '65 x'");
+        assert_eq!(parse("65 66\n65 67").unwrap_err(), "line: 2: duplicate id: '65 67'");
+    }
+}
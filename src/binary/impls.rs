@@ -14,13 +14,15 @@
 //!
 //! Note. These methods have limited validation checks, they are primarily concerned with the
 //! packing and unpacking of data structures, not with the validity of their contents.
-use std::convert::TryFrom;
+use core::convert::TryFrom;
 
 use crate::binary::constants::{CHARS, COMMON, INFO, KERNING_PAIRS, PAGES};
 use crate::builder::FontProto;
 use crate::charset::Charset;
 use crate::font::*;
+use crate::font_ref::InfoRef;
 use crate::parse::ParseError;
+use crate::prelude::*;
 
 use super::bits::BitField;
 use super::pack::{self, Pack, PackDyn, PackDynLen, PackLen, Unpack, UnpackDyn};
@@ -38,7 +40,7 @@ macro_rules! pack_len {
         {
             0
             $(
-                + std::mem::size_of::<$u>()
+                + core::mem::size_of::<$u>()
             )*
         }
     };
@@ -48,7 +50,7 @@ macro_rules! pack {
     ($dst:expr, $($u:expr),*) => {{
         #[allow(unused_assignments)]
         {
-            use std::mem::size_of_val;
+            use core::mem::size_of_val;
 
             let dst: &mut Vec<u8> = $dst;
             let mut len = 0;
@@ -75,7 +77,7 @@ macro_rules! unpack {
     ($src:expr, $($u:ty),*) => {{
     #[allow(unused_assignments)]
     {
-        use std::mem::size_of;
+        use core::mem::size_of;
 
         let mut len = 0;
         $(
@@ -305,51 +307,56 @@ impl PackDyn<V2> for Info {
 
 impl UnpackDyn<V2> for Info {
     fn unpack_dyn_next(src: &mut &[u8]) -> crate::Result<Self> {
-        match unpack!(src, i16, u8, u8, u16, u8, u8, u8, u8, u8, u8, u8, u8) {
-            Ok((
+        unpack_info_ref(src).map(|u| u.to_owned())
+    }
+}
+
+/// Unpack V2 info, borrowing the face from the source.
+pub fn unpack_info_ref<'a>(src: &mut &'a [u8]) -> crate::Result<InfoRef<'a>> {
+    match unpack!(src, i16, u8, u8, u16, u8, u8, u8, u8, u8, u8, u8, u8) {
+        Ok((
+            size,
+            bits,
+            charset,
+            stretch_h,
+            aa,
+            padding_up,
+            padding_right,
+            padding_down,
+            padding_left,
+            spacing_horiz,
+            spacing_vert,
+            outline,
+        )) => {
+            let face = unpack_c_str(src)?;
+            let padding = Padding::new(padding_up, padding_right, padding_down, padding_left);
+            let spacing = Spacing::new(spacing_horiz, spacing_vert);
+            let bits = BitField(bits);
+            let smooth = bits.get(SMOOTH);
+            let unicode = bits.get(UNICODE);
+            let italic = bits.get(ITALIC);
+            let bold = bits.get(BOLD);
+            let _fixed_height = bits.get(FIXED_HEIGHT);
+            let charset = match charset {
+                0 if unicode => Charset::Null,
+                u => Charset::Tagged(u),
+            };
+            Ok(InfoRef {
+                face,
                 size,
-                bits,
+                bold,
+                italic,
                 charset,
+                unicode,
                 stretch_h,
+                smooth,
                 aa,
-                padding_up,
-                padding_right,
-                padding_down,
-                padding_left,
-                spacing_horiz,
-                spacing_vert,
+                padding,
+                spacing,
                 outline,
-            )) => {
-                let face = UnpackDyn::<C>::unpack_dyn(src)?;
-                let padding = Padding::new(padding_up, padding_right, padding_down, padding_left);
-                let spacing = Spacing::new(spacing_horiz, spacing_vert);
-                let bits = BitField(bits);
-                let smooth = bits.get(SMOOTH);
-                let unicode = bits.get(UNICODE);
-                let italic = bits.get(ITALIC);
-                let bold = bits.get(BOLD);
-                let _fixed_height = bits.get(FIXED_HEIGHT);
-                let charset = match charset {
-                    0 if unicode => Charset::Null,
-                    u => Charset::Tagged(u),
-                };
-                Ok(Self {
-                    face,
-                    size,
-                    bold,
-                    italic,
-                    charset,
-                    unicode,
-                    stretch_h,
-                    smooth,
-                    aa,
-                    padding,
-                    spacing,
-                    outline,
-                })
-            }
-            Err(err) => Err(err),
+            })
         }
+        Err(err) => Err(err),
     }
 }
 
@@ -593,21 +600,24 @@ impl PackDynLen<C> for String {
 
 impl UnpackDyn<C> for String {
     fn unpack_dyn_next(src: &mut &[u8]) -> crate::Result<Self> {
-        let mut i = 0;
-        while i < src.len() {
-            if src[i] == 0 {
-                let (obj, overflow) = src.split_at(i);
-                let string = utf8_string((obj).into())?;
-                *src = &overflow[1..];
-                return Ok(string);
-            }
-            i += 1;
+        unpack_c_str(src).map(ToOwned::to_owned)
+    }
+}
+
+/// Unpack a NUL terminated string, borrowing from the source.
+pub fn unpack_c_str<'a>(src: &mut &'a [u8]) -> crate::Result<&'a str> {
+    match src.iter().position(|&u| u == 0) {
+        Some(i) => {
+            let bytes: &'a [u8] = src;
+            let string = utf8_str(&bytes[..i])?;
+            *src = &bytes[i + 1..];
+            Ok(string)
         }
-        Err(crate::Error::Parse {
+        None => Err(crate::Error::Parse {
             line: None,
             entity: "CString".to_owned(),
             err: "missing NUL".to_owned(),
-        })
+        }),
     }
 }
 
@@ -623,8 +633,8 @@ fn c_string(bytes: &[u8]) -> crate::Result<&[u8]> {
     }
 }
 
-fn utf8_string(vec: Vec<u8>) -> crate::Result<String> {
-    match String::from_utf8(vec) {
+fn utf8_str(bytes: &[u8]) -> crate::Result<&str> {
+    match core::str::from_utf8(bytes) {
         Ok(u) => Ok(u),
         Err(e) => {
             Err(crate::Error::Parse { line: None, entity: "String".to_owned(), err: e.to_string() })
@@ -1,8 +1,19 @@
-use crate::builder::FontProto;
+use crate::builder::{self, FontProto};
+use crate::font_ref::{CharsRef, FontRef, InfoRef, KerningsRef};
+use crate::prelude::*;
 use crate::{font::*, LoadSettings};
 
-use super::pack::UnpackDyn;
+use super::constants::{CHARS, COMMON, INFO, KERNING_PAIRS, PAGES};
+use super::impls::{self, Block, Magic, V3};
+use super::pack::{self, Unpack, UnpackDyn};
+use super::packed;
 
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncRead;
+
+#[cfg(feature = "std")]
 use std::io;
 
 /// Read binary format font.
@@ -28,6 +39,7 @@ use std::io;
 ///     Ok(())
 /// }
 /// ```
+#[cfg(feature = "std")]
 pub fn from_reader<R: io::Read>(reader: R) -> crate::Result<Font> {
     from_reader_ext(reader, &Default::default())
 }
@@ -36,12 +48,52 @@ pub fn from_reader<R: io::Read>(reader: R) -> crate::Result<Font> {
 ///
 /// This function specifies Font import behavior, allowing us to import certain partially
 /// broken/ non-compliant BMFont files.
+#[cfg(feature = "std")]
 pub fn from_reader_ext<R: io::Read>(mut reader: R, settings: &LoadSettings) -> crate::Result<Font> {
     let mut vec = Vec::default();
     reader.read_to_end(&mut vec)?;
     from_bytes_ext(vec.as_slice(), settings)
 }
 
+/// Read binary format font from an async reader.
+///
+/// As [from_reader], but reads from a `futures-io` [AsyncRead](futures_io::AsyncRead).
+/// This method buffers data internally, a buffered reader is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncRead;
+///
+/// async fn load<R: AsyncRead + Unpin>(reader: R) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::binary::from_async_reader(reader).await?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn from_async_reader<R: AsyncRead + Unpin>(reader: R) -> crate::Result<Font> {
+    from_async_reader_ext(reader, &Default::default()).await
+}
+
+/// Read binary format font from an async reader with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+#[cfg(feature = "async")]
+pub async fn from_async_reader_ext<R: AsyncRead + Unpin>(
+    mut reader: R,
+    settings: &LoadSettings,
+) -> crate::Result<Font> {
+    let mut vec = Vec::default();
+    async_io::read_to_end(&mut reader, &mut vec).await?;
+    from_bytes_ext(vec.as_slice(), settings)
+}
+
 /// Load binary format font.
 ///
 /// Load a font from the specified binary format byte slice.
@@ -76,3 +128,118 @@ pub fn from_bytes_ext(mut bytes: &[u8], settings: &LoadSettings) -> crate::Resul
     let proto: FontProto = Font::unpack_dyn(&mut bytes)?.into();
     proto.build(settings)
 }
+
+/// Load binary format font as a borrowed view.
+///
+/// Load a zero-copy [FontRef] from the specified binary format byte slice. The face and page
+/// strings borrow from the slice, the characters and kerning pairs are lazily decoded views over
+/// the block bytes, and are validated on access.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let buf = fs::read("font.bin")?;
+///     let font = bmfont_rs::binary::from_bytes_ref(&buf)?;
+///     println!("{:?}", font.chars.get(0));
+///     Ok(())
+/// }
+/// ```
+pub fn from_bytes_ref(bytes: &[u8]) -> crate::Result<FontRef<'_>> {
+    from_bytes_ref_ext(bytes, &Default::default())
+}
+
+/// Load binary format font as a borrowed view with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+pub fn from_bytes_ref_ext<'a>(
+    mut bytes: &'a [u8],
+    settings: &LoadSettings,
+) -> crate::Result<FontRef<'a>> {
+    let version = Magic::unpack_next(&mut bytes)?.version()?;
+    if version != 3 {
+        return Err(crate::Error::UnsupportedBinaryVersion { version });
+    }
+    let mut info: Option<InfoRef> = None;
+    let mut common: Option<Common> = None;
+    let mut pages: Vec<&str> = Vec::default();
+    let mut chars: Option<&[u8]> = None;
+    let mut kernings: Option<&[u8]> = None;
+    while !bytes.is_empty() {
+        let Block { id, len } = Block::unpack_next(&mut bytes)?;
+        if len as usize > bytes.len() {
+            return pack::underflow();
+        }
+        let (mut block, overflow) = bytes.split_at(len as usize);
+        bytes = overflow;
+        match id {
+            INFO => {
+                if info.is_some() {
+                    return Err(crate::Error::DuplicateInfoBlock { line: None });
+                }
+                info = Some(impls::unpack_info_ref(&mut block)?);
+                if !block.is_empty() {
+                    return pack::overflow();
+                }
+            }
+            COMMON => {
+                if common.is_some() {
+                    return Err(crate::Error::DuplicateCommonBlock { line: None });
+                }
+                common = Some(<_ as Unpack<V3>>::unpack_next(&mut block)?);
+                if !block.is_empty() {
+                    return pack::overflow();
+                }
+            }
+            PAGES => {
+                while !block.is_empty() {
+                    pages.push(impls::unpack_c_str(&mut block)?);
+                }
+            }
+            CHARS => {
+                check_packed(block, packed::CHAR_LEN)?;
+                set_packed(&mut chars, block, "chars")?;
+            }
+            KERNING_PAIRS => {
+                check_packed(block, packed::KERNING_LEN)?;
+                set_packed(&mut kernings, block, "kernings")?;
+            }
+            id => return Err(crate::Error::InvalidBinaryBlock { id }),
+        }
+    }
+    let info = info.ok_or(crate::Error::NoInfoBlock)?;
+    let common = common.ok_or(crate::Error::NoCommonBlock)?;
+    builder::check_pages(&common, pages.len(), settings)?;
+    if !settings.allow_string_control_characters {
+        builder::check_strings(&info.charset, info.face, &pages)?;
+    }
+    let chars = chars.map(CharsRef::packed).unwrap_or_default();
+    let kernings = kernings.map(KerningsRef::packed).unwrap_or_default();
+    Ok(FontRef { info, common, pages, chars, kernings })
+}
+
+/// Validate the packed block length. Entries are validated on access.
+fn check_packed(block: &[u8], len: usize) -> crate::Result<()> {
+    if block.len() % len != 0 {
+        pack::underflow()
+    } else {
+        Ok(())
+    }
+}
+
+/// Packed views cannot span multiple blocks.
+fn set_packed<'a>(dst: &mut Option<&'a [u8]>, block: &'a [u8], tag: &str) -> crate::Result<()> {
+    if dst.is_some() {
+        Err(crate::Error::DuplicateTag { line: None, tag: tag.to_owned() })
+    } else {
+        *dst = Some(block);
+        Ok(())
+    }
+}
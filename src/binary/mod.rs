@@ -5,7 +5,23 @@ mod constants;
 mod impls;
 mod load;
 mod pack;
+pub(crate) mod packed;
+mod parser;
 mod store;
+#[cfg(feature = "std")]
+mod stream;
 
-pub use load::{from_bytes, from_bytes_ext, from_reader, from_reader_ext};
-pub use store::{to_vec, to_writer};
+pub use load::{from_bytes, from_bytes_ext, from_bytes_ref, from_bytes_ref_ext};
+#[cfg(feature = "std")]
+pub use load::{from_reader, from_reader_ext};
+pub use parser::Parser;
+pub use store::to_vec;
+#[cfg(feature = "std")]
+pub use store::to_writer;
+#[cfg(feature = "std")]
+pub(crate) use stream::StoreBinary;
+
+#[cfg(feature = "async")]
+pub use load::{from_async_reader, from_async_reader_ext};
+#[cfg(feature = "async")]
+pub use store::to_async_writer;
@@ -6,6 +6,8 @@
 //!
 //! These packing traits are generic over the encoding type.
 
+use crate::prelude::*;
+
 pub trait PackLen<T = ()>: Sized {
     const PACK_LEN: usize;
 }
@@ -0,0 +1,20 @@
+//! Packed entry decoding for borrowed views.
+
+use crate::font::{Char, Kerning};
+
+use super::impls::V1;
+use super::pack::{PackLen, Unpack};
+
+pub const CHAR_LEN: usize = <Char as PackLen<V1>>::PACK_LEN;
+
+pub const KERNING_LEN: usize = <Kerning as PackLen<V1>>::PACK_LEN;
+
+#[inline(always)]
+pub fn unpack_char(mut bytes: &[u8]) -> crate::Result<Char> {
+    <Char as Unpack<V1>>::unpack(&mut bytes)
+}
+
+#[inline(always)]
+pub fn unpack_kerning(mut bytes: &[u8]) -> crate::Result<Kerning> {
+    <Kerning as Unpack<V1>>::unpack(&mut bytes)
+}
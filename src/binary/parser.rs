@@ -0,0 +1,233 @@
+use crate::event::Event;
+use crate::font::{Common, Info};
+use crate::prelude::*;
+
+use super::constants::{CHARS, COMMON, INFO, KERNING_PAIRS, PAGES};
+use super::impls::{self, Block, Magic, V2, V3};
+use super::pack::{self, Unpack, UnpackDyn};
+use super::packed;
+
+/// Binary format pull parser.
+///
+/// Yields the font descriptor blocks as typed [Event]s in source order, without materializing
+/// the [Font](crate::Font). Character and kerning pair blocks yield their entry count followed by
+/// the entries, which are decoded on demand. References are not validated.
+///
+/// Parsing halts on the first error.
+///
+/// # Example
+///
+/// ```no_run
+/// use bmfont_rs::Event;
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let buf = fs::read("font.bin")?;
+///     for event in bmfont_rs::binary::Parser::new(&buf) {
+///         if let Event::Kerning(kerning) = event? {
+///             println!("{:?}", kerning);
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+pub struct Parser<'a> {
+    bytes: &'a [u8],
+    state: State<'a>,
+    page_id: u16,
+}
+
+enum State<'a> {
+    Magic,
+    Blocks,
+    Pages(&'a [u8]),
+    Chars(&'a [u8]),
+    Kernings(&'a [u8]),
+    Done,
+}
+
+impl<'a> Parser<'a> {
+    /// Construct a new Parser over the specified binary format byte slice.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, state: State::Magic, page_id: 0 }
+    }
+
+    fn next_event(&mut self) -> crate::Result<Option<Event>> {
+        loop {
+            match &mut self.state {
+                State::Magic => {
+                    let version = Magic::unpack_next(&mut self.bytes)?.version()?;
+                    if version != 3 {
+                        return Err(crate::Error::UnsupportedBinaryVersion { version });
+                    }
+                    self.state = State::Blocks;
+                }
+                State::Blocks => {
+                    if self.bytes.is_empty() {
+                        self.state = State::Done;
+                        return Ok(None);
+                    }
+                    return self.next_block();
+                }
+                State::Pages(block) => {
+                    if block.is_empty() {
+                        self.state = State::Blocks;
+                        continue;
+                    }
+                    let file = impls::unpack_c_str(block)?.to_owned();
+                    let id = self.page_id;
+                    self.page_id = self.page_id.wrapping_add(1);
+                    return Ok(Some(Event::Page { id, file }));
+                }
+                State::Chars(block) => {
+                    if block.is_empty() {
+                        self.state = State::Blocks;
+                        continue;
+                    }
+                    let (char, tail) = block.split_at(packed::CHAR_LEN);
+                    *block = tail;
+                    return packed::unpack_char(char).map(|u| Some(Event::Char(u)));
+                }
+                State::Kernings(block) => {
+                    if block.is_empty() {
+                        self.state = State::Blocks;
+                        continue;
+                    }
+                    let (kerning, tail) = block.split_at(packed::KERNING_LEN);
+                    *block = tail;
+                    return packed::unpack_kerning(kerning).map(|u| Some(Event::Kerning(u)));
+                }
+                State::Done => return Ok(None),
+            }
+        }
+    }
+
+    fn next_block(&mut self) -> crate::Result<Option<Event>> {
+        let Block { id, len } = Block::unpack_next(&mut self.bytes)?;
+        if len as usize > self.bytes.len() {
+            return pack::underflow();
+        }
+        let (mut block, overflow) = self.bytes.split_at(len as usize);
+        self.bytes = overflow;
+        let event = match id {
+            INFO => {
+                let info = <Info as UnpackDyn<V2>>::unpack_dyn_next(&mut block)?;
+                if !block.is_empty() {
+                    return pack::overflow();
+                }
+                Event::Info(info)
+            }
+            COMMON => {
+                let common = <Common as Unpack<V3>>::unpack_next(&mut block)?;
+                if !block.is_empty() {
+                    return pack::overflow();
+                }
+                Event::Common(common)
+            }
+            PAGES => {
+                // Pages are yielded individually, an empty block yields nothing.
+                self.state = State::Pages(block);
+                return self.next_event();
+            }
+            CHARS => {
+                let count = packed_count(block, packed::CHAR_LEN)?;
+                self.state = State::Chars(block);
+                Event::CharCount(count)
+            }
+            KERNING_PAIRS => {
+                let count = packed_count(block, packed::KERNING_LEN)?;
+                self.state = State::Kernings(block);
+                Event::KerningCount(count)
+            }
+            id => return Err(crate::Error::InvalidBinaryBlock { id }),
+        };
+        Ok(Some(event))
+    }
+}
+
+impl Iterator for Parser<'_> {
+    type Item = crate::Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let event = self.next_event().transpose();
+        if let Some(Err(_)) = event {
+            self.state = State::Done;
+        }
+        event
+    }
+}
+
+impl core::iter::FusedIterator for Parser<'_> {}
+
+fn packed_count(block: &[u8], len: usize) -> crate::Result<u32> {
+    if block.len() % len == 0 {
+        Ok((block.len() / len) as u32)
+    } else {
+        pack::underflow()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::font::{Char, Font, Kerning};
+
+    fn font() -> Font {
+        let mut font = Font::default();
+        font.common.pages = 2;
+        font.pages = vec!["a.png".to_owned(), "b.png".to_owned()];
+        font.chars = vec![Char { id: 32, ..Default::default() }];
+        font.kernings = vec![Kerning::new(32, 32, -1)];
+        font
+    }
+
+    #[test]
+    fn events() -> crate::Result<()> {
+        let font = font();
+        let src = crate::binary::to_vec(&font)?;
+        let events = Parser::new(&src).collect::<crate::Result<Vec<_>>>()?;
+        assert_eq!(
+            events,
+            vec![
+                Event::Info(font.info),
+                Event::Common(font.common),
+                Event::Page { id: 0, file: "a.png".to_owned() },
+                Event::Page { id: 1, file: "b.png".to_owned() },
+                Event::CharCount(1),
+                Event::Char(font.chars[0]),
+                Event::KerningCount(1),
+                Event::Kerning(font.kernings[0]),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn empty_pages() -> crate::Result<()> {
+        let mut font = font();
+        font.common.pages = 0;
+        font.pages.clear();
+        let src = crate::binary::to_vec(&font)?;
+        let events = Parser::new(&src).collect::<crate::Result<Vec<_>>>()?;
+        assert_eq!(events.len(), 6);
+        Ok(())
+    }
+
+    #[test]
+    fn truncated() {
+        let src = crate::binary::to_vec(&font()).unwrap();
+        let mut parser = Parser::new(&src[..src.len() - 1]);
+        assert!(parser.by_ref().take_while(|u| u.is_ok()).count() > 0);
+        assert!(parser.next().is_none());
+    }
+
+    #[test]
+    fn unsupported_version() {
+        let mut parser = Parser::new(b"BMF\x02");
+        assert!(matches!(
+            parser.next(),
+            Some(Err(crate::Error::UnsupportedBinaryVersion { version: 2 }))
+        ));
+        assert!(parser.next().is_none());
+    }
+}
@@ -1,8 +1,15 @@
 use crate::font::*;
+use crate::prelude::*;
 
 use super::impls::V3;
 use super::pack::{PackDyn, PackDynLen};
 
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncWrite;
+
+#[cfg(feature = "std")]
 use std::io;
 
 /// Write binary format font.
@@ -31,12 +38,44 @@ use std::io;
 ///     Ok(())
 /// }
 /// ```
+#[cfg(feature = "std")]
 pub fn to_writer<W: io::Write>(mut writer: W, font: &Font) -> crate::Result<()> {
     let vec = to_vec(font)?;
     writer.write_all(&vec)?;
     Ok(())
 }
 
+/// Write binary format font to an async writer.
+///
+/// As [to_writer], but writes to a `futures-io` [AsyncWrite](futures_io::AsyncWrite). The writer
+/// is flushed on completion.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncWrite;
+///
+/// async fn store<W: AsyncWrite + Unpin>(writer: W) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     bmfont_rs::binary::to_async_writer(writer, &font).await?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn to_async_writer<W: AsyncWrite + Unpin>(
+    mut writer: W,
+    font: &Font,
+) -> crate::Result<()> {
+    let vec = to_vec(font)?;
+    async_io::write_all(&mut writer, &vec).await?;
+    Ok(())
+}
+
 /// Store binary format font.
 ///
 /// Store a font into a [Vec] in binary format.
@@ -67,7 +106,7 @@ pub fn to_vec(font: &Font) -> crate::Result<Vec<u8>> {
     Ok(dst)
 }
 
-fn check_page_names(pages: &[String]) -> crate::Result<()> {
+pub(super) fn check_page_names(pages: &[String]) -> crate::Result<()> {
     let mut len = None;
     for page in pages {
         let page_len = page.len();
@@ -79,7 +118,7 @@ fn check_page_names(pages: &[String]) -> crate::Result<()> {
     Ok(())
 }
 
-fn check_value(value: &str) -> crate::Result<&str> {
+pub(super) fn check_value(value: &str) -> crate::Result<&str> {
     for c in value.chars() {
         if c == '\x00' {
             return Err(crate::Error::UnsupportedValueEncoding {
@@ -0,0 +1,95 @@
+use crate::font::*;
+use crate::prelude::*;
+use crate::writer::StoreStream;
+
+use super::constants::{CHARS, COMMON, INFO, KERNING_PAIRS, PAGES};
+use super::impls::{Block, Magic, C, V1, V2, V3};
+use super::pack::{Pack, PackDyn, PackDynLen, PackLen};
+use super::packed::{CHAR_LEN, KERNING_LEN};
+use super::store::{check_page_names, check_value};
+
+use core::convert::TryFrom;
+use std::io;
+
+/// Binary format streaming store.
+///
+/// Pages are buffered until the character count is declared, all other blocks are written as
+/// they are supplied.
+#[derive(Debug, Default)]
+pub(crate) struct StoreBinary {
+    pages: Vec<String>,
+    buf: Vec<u8>,
+}
+
+impl StoreBinary {
+    fn flush<W: io::Write>(&mut self, writer: &mut W) -> crate::Result<()> {
+        writer.write_all(&self.buf)?;
+        self.buf.clear();
+        Ok(())
+    }
+}
+
+impl StoreStream for StoreBinary {
+    fn info<W: io::Write>(&mut self, writer: &mut W, info: &Info) -> crate::Result<()> {
+        check_value(&info.face)?;
+        // Magic V3
+        Magic::new(3).pack(&mut self.buf)?;
+        // Info V2
+        Block::new(INFO, PackDynLen::<V2>::dyn_len(info) as u32).pack(&mut self.buf)?;
+        PackDyn::<V2>::pack_dyn(info, &mut self.buf)?;
+        self.flush(writer)
+    }
+
+    fn common<W: io::Write>(&mut self, writer: &mut W, common: &Common) -> crate::Result<()> {
+        // Common V3
+        Block::new(COMMON, <Common as PackLen<V3>>::PACK_LEN as u32).pack(&mut self.buf)?;
+        Pack::<V3>::pack(common, &mut self.buf)?;
+        self.flush(writer)
+    }
+
+    fn page<W: io::Write>(&mut self, _: &mut W, _: u32, file: &str) -> crate::Result<()> {
+        self.pages.push(file.to_owned());
+        Ok(())
+    }
+
+    fn chars<W: io::Write>(&mut self, writer: &mut W, count: u32) -> crate::Result<()> {
+        // Pages C
+        check_page_names(&self.pages)?;
+        Block::new(PAGES, PackDynLen::<C>::dyn_len(&self.pages) as u32).pack(&mut self.buf)?;
+        PackDyn::<C>::pack_dyn(&self.pages, &mut self.buf)?;
+        self.pages = Vec::default();
+        // Chars V1
+        Block::new(CHARS, block_len("chars count", count, CHAR_LEN)?).pack(&mut self.buf)?;
+        self.flush(writer)
+    }
+
+    fn char<W: io::Write>(&mut self, writer: &mut W, char: &Char) -> crate::Result<()> {
+        Pack::<V1>::pack(char, &mut self.buf)?;
+        self.flush(writer)
+    }
+
+    fn kernings<W: io::Write>(&mut self, writer: &mut W, count: u32) -> crate::Result<()> {
+        // Kernings V1 optional
+        if count != 0 {
+            Block::new(KERNING_PAIRS, block_len("kernings count", count, KERNING_LEN)?)
+                .pack(&mut self.buf)?;
+        }
+        self.flush(writer)
+    }
+
+    fn kerning<W: io::Write>(&mut self, writer: &mut W, kerning: &Kerning) -> crate::Result<()> {
+        Pack::<V1>::pack(kerning, &mut self.buf)?;
+        self.flush(writer)
+    }
+
+    fn finish<W: io::Write>(&mut self, _: &mut W) -> crate::Result<()> {
+        Ok(())
+    }
+}
+
+/// Block length, `count` entries of `len` bytes.
+fn block_len(path: &str, count: u32, len: usize) -> crate::Result<u32> {
+    (count as usize).checked_mul(len).and_then(|u| u32::try_from(u).ok()).ok_or_else(|| {
+        crate::Error::UnsupportedValueEncoding { path: path.to_owned(), value: count.to_string() }
+    })
+}
@@ -0,0 +1,276 @@
+use crate::charset::Charset;
+use crate::diff::{diff_common, diff_info, FontDiff};
+use crate::font::{Common, Font, Info, Packing, Padding, Spacing};
+use crate::prelude::*;
+
+use core::ops::RangeInclusive;
+
+/// BMFont generator configuration.
+///
+/// The settings held by an AngelCode BMFont configuration (`.bmfc`) file, as used to drive the
+/// BMFont generator. Field names follow their configuration keys.
+///
+/// The [Default] implementation matches the BMFont defaults, with no characters selected.
+///
+/// # Example
+///
+/// ```
+/// let mut config = bmfont_rs::bmfc::Config::default();
+/// config.font_size = 24;
+/// config.is_bold = true;
+/// let info = config.info();
+/// assert_eq!(info.size, 24);
+/// assert!(info.bold);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    /// Configuration file version.
+    pub file_version: u8,
+    /// The name of the true type font.
+    pub font_name: String,
+    /// The font file, if loaded from a file rather than an installed font.
+    pub font_file: String,
+    /// The non-Unicode character set.
+    pub char_set: u8,
+    /// The font size. Negative if matching the char height.
+    pub font_size: i16,
+    /// The supersampling level used, 1 means no supersampling.
+    pub aa: u8,
+    /// The font height stretch in percentage.
+    pub scale_h: u16,
+    /// Font smoothing.
+    pub use_smoothing: bool,
+    /// Bold font.
+    pub is_bold: bool,
+    /// Italic font.
+    pub is_italic: bool,
+    /// Unicode character set.
+    pub use_unicode: bool,
+    /// Disable box characters.
+    pub disable_box_chars: bool,
+    /// Output the invalid character glyph.
+    pub output_invalid_char_glyph: bool,
+    /// Exclude kerning pairs.
+    pub dont_include_kerning_pairs: bool,
+    /// Font hinting.
+    pub use_hinting: bool,
+    /// Render from the true type outline.
+    pub render_from_outline: bool,
+    /// ClearType rendering.
+    pub use_clear_type: bool,
+    /// Auto fit the specified number of pages, 0 disables.
+    pub auto_fit_num_pages: u16,
+    /// Auto fit minimum font size.
+    pub auto_fit_font_size_min: i16,
+    /// Auto fit maximum font size.
+    pub auto_fit_font_size_max: i16,
+    /// The padding for each character.
+    pub padding: Padding,
+    /// The spacing for each character.
+    pub spacing: Spacing,
+    /// Fixed character height.
+    pub use_fixed_height: bool,
+    /// Force character offsets to zero.
+    pub force_zero: bool,
+    /// Width padding factor.
+    pub width_padding_factor: f32,
+    /// The texture width.
+    pub out_width: u16,
+    /// The texture height.
+    pub out_height: u16,
+    /// The texture bit depth, 8 or 32.
+    pub out_bit_depth: u8,
+    /// The font descriptor format: 0 text, 1 XML, 2 binary.
+    pub font_desc_format: u8,
+    /// Pack characters in each of the texture channels.
+    pub four_chnl_packed: bool,
+    /// The texture file format.
+    pub texture_format: String,
+    /// The texture compression.
+    pub texture_compression: u8,
+    /// Alpha channel packing.
+    pub alpha_chnl: Packing,
+    /// Red channel packing.
+    pub red_chnl: Packing,
+    /// Green channel packing.
+    pub green_chnl: Packing,
+    /// Blue channel packing.
+    pub blue_chnl: Packing,
+    /// Invert the alpha channel.
+    pub inv_a: bool,
+    /// Invert the red channel.
+    pub inv_r: bool,
+    /// Invert the green channel.
+    pub inv_g: bool,
+    /// Invert the blue channel.
+    pub inv_b: bool,
+    /// The outline thickness.
+    pub outline_thickness: u8,
+    /// Selected character id ranges.
+    pub chars: Vec<RangeInclusive<u32>>,
+    /// Imported icon images.
+    pub icons: Vec<Icon>,
+}
+
+impl Config {
+    /// Returns the [Info] block this configuration would generate.
+    pub fn info(&self) -> Info {
+        Info {
+            face: self.font_name.clone(),
+            size: self.font_size,
+            bold: self.is_bold,
+            italic: self.is_italic,
+            charset: if self.use_unicode { Charset::Null } else { Charset::Tagged(self.char_set) },
+            unicode: self.use_unicode,
+            stretch_h: self.scale_h,
+            smooth: self.use_smoothing,
+            aa: self.aa,
+            padding: self.padding,
+            spacing: self.spacing,
+            outline: self.outline_thickness,
+        }
+    }
+
+    /// Returns the [Common] block this configuration would generate.
+    ///
+    /// The line height, base and page count depend on the rasterized font and are left as zero.
+    pub fn common(&self) -> Common {
+        Common {
+            line_height: 0,
+            base: 0,
+            scale_w: self.out_width,
+            scale_h: self.out_height,
+            pages: 0,
+            packed: self.four_chnl_packed && self.out_bit_depth == 32,
+            alpha_chnl: self.alpha_chnl,
+            red_chnl: self.red_chnl,
+            green_chnl: self.green_chnl,
+            blue_chnl: self.blue_chnl,
+        }
+    }
+
+    /// Returns true if the specified character id is selected, either as a character or an icon.
+    pub fn selects(&self, id: u32) -> bool {
+        self.chars.iter().any(|u| u.contains(&id)) || self.icons.iter().any(|u| u.id == id)
+    }
+
+    /// Check a font against this configuration.
+    ///
+    /// Returns the differences between the font this configuration would generate and the
+    /// specified font, with the configuration as the old side and the font as the new side.
+    ///
+    /// * Info fields, as generated by [info](Self::info).
+    /// * Common fields, as generated by [common](Self::common), excluding the line height, base
+    ///   and page count.
+    /// * Characters that are not selected, as added characters. With
+    ///   [output_invalid_char_glyph](Self::output_invalid_char_glyph), id `u32::MAX` (-1) is
+    ///   selected.
+    /// * Kerning pairs, as added kerning pairs, if
+    ///   [dont_include_kerning_pairs](Self::dont_include_kerning_pairs) is set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let config = bmfont_rs::bmfc::Config::default();
+    /// let mut font = bmfont_rs::Font::default();
+    /// font.info = config.info();
+    /// font.common = config.common();
+    /// font.info.size = 16;
+    /// assert_eq!(config.check(&font).to_string(), "~ info size: 32 -> 16\n");
+    /// ```
+    pub fn check(&self, font: &Font) -> FontDiff {
+        let mut diff = FontDiff::default();
+        diff_info(&mut diff.info, &self.info(), &font.info);
+        let common = Common {
+            line_height: font.common.line_height,
+            base: font.common.base,
+            pages: font.common.pages,
+            ..self.common()
+        };
+        diff_common(&mut diff.common, &common, &font.common);
+        diff.added_chars = font
+            .chars
+            .iter()
+            .filter(|u| !self.selects(u.id))
+            .filter(|u| !(self.output_invalid_char_glyph && u.id == u32::MAX))
+            .copied()
+            .collect();
+        if self.dont_include_kerning_pairs {
+            diff.added_kernings = font.kernings.clone();
+        }
+        diff
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            file_version: 1,
+            font_name: "Arial".to_owned(),
+            font_file: String::default(),
+            char_set: 0,
+            font_size: 32,
+            aa: 1,
+            scale_h: 100,
+            use_smoothing: true,
+            is_bold: false,
+            is_italic: false,
+            use_unicode: true,
+            disable_box_chars: true,
+            output_invalid_char_glyph: false,
+            dont_include_kerning_pairs: false,
+            use_hinting: true,
+            render_from_outline: false,
+            use_clear_type: true,
+            auto_fit_num_pages: 0,
+            auto_fit_font_size_min: 0,
+            auto_fit_font_size_max: 0,
+            padding: Padding::default(),
+            spacing: Spacing::new(1, 1),
+            use_fixed_height: false,
+            force_zero: false,
+            width_padding_factor: 0.0,
+            out_width: 256,
+            out_height: 256,
+            out_bit_depth: 8,
+            font_desc_format: 0,
+            four_chnl_packed: false,
+            texture_format: "png".to_owned(),
+            texture_compression: 0,
+            alpha_chnl: Packing::Outline,
+            red_chnl: Packing::Glyph,
+            green_chnl: Packing::Glyph,
+            blue_chnl: Packing::Glyph,
+            inv_a: false,
+            inv_r: false,
+            inv_g: false,
+            inv_b: false,
+            outline_thickness: 0,
+            chars: Vec::default(),
+            icons: Vec::default(),
+        }
+    }
+}
+
+/// Imported icon image.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Icon {
+    /// The image file.
+    pub file: String,
+    /// The character id.
+    pub id: u32,
+    /// The x offset.
+    pub xoffset: i16,
+    /// The y offset.
+    pub yoffset: i16,
+    /// The x advance.
+    pub xadvance: i16,
+}
+
+impl Icon {
+    /// Construct a new Icon.
+    #[inline(always)]
+    pub fn new(file: String, id: u32, xoffset: i16, yoffset: i16, xadvance: i16) -> Self {
+        Self { file, id, xoffset, yoffset, xadvance }
+    }
+}
@@ -0,0 +1,223 @@
+use crate::parse::{Parse, ParseError, ParseResult};
+use crate::prelude::*;
+
+use super::config::{Config, Icon};
+
+use core::ops::RangeInclusive;
+
+#[cfg(feature = "std")]
+use std::io;
+
+/// Load BMFont configuration.
+///
+/// Load a configuration from the specified `.bmfc` format [str].
+///
+/// Blank lines and `#` comments are skipped. Unrecognized keys are ignored, absent keys take
+/// their [Config::default] values. Multiple `chars` and `icon` lines accumulate.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```
+/// fn main() -> bmfont_rs::Result<()> {
+///     let config = bmfont_rs::bmfc::from_str("fontName=Verdana\nfontSize=-24\nchars=32-126\n")?;
+///     assert_eq!(config.font_name, "Verdana");
+///     assert_eq!(config.font_size, -24);
+///     assert_eq!(config.chars, vec![32..=126]);
+///     Ok(())
+/// }
+/// ```
+pub fn from_str(src: &str) -> crate::Result<Config> {
+    let mut config = Config::default();
+    for (i, line) in src.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line.split_once('=').ok_or_else(|| crate::Error::Parse {
+            line: Some(i + 1),
+            entity: "bmfc".to_owned(),
+            err: format!("expected key=value: '{}'", line),
+        })?;
+        let key = key.trim();
+        set(&mut config, key, value.trim()).map_err(|e| crate::Error::Parse {
+            line: Some(i + 1),
+            entity: key.to_owned(),
+            err: e.to_string(),
+        })?;
+    }
+    Ok(config)
+}
+
+/// Load BMFont configuration.
+///
+/// Load a configuration from the specified `.bmfc` format byte slice.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let buf = fs::read("font.bmfc")?;
+///     let config = bmfont_rs::bmfc::from_bytes(&buf)?;
+///     println!("{:?}", config);
+///     Ok(())
+/// }
+/// ```
+pub fn from_bytes(bytes: &[u8]) -> crate::Result<Config> {
+    from_str(core::str::from_utf8(bytes).map_err(|e| crate::Error::Parse {
+        line: None,
+        entity: "bmfc".to_owned(),
+        err: e.to_string(),
+    })?)
+}
+
+/// Read BMFont configuration.
+///
+/// Read a configuration from the specified `.bmfc` format reader.
+/// This method buffers data internally, a buffered reader is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let f = File::open("font.bmfc")?;
+///     let config = bmfont_rs::bmfc::from_reader(f)?;
+///     println!("{:?}", config);
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn from_reader<R: io::Read>(mut reader: R) -> crate::Result<Config> {
+    let mut vec = Vec::default();
+    reader.read_to_end(&mut vec)?;
+    from_bytes(&vec)
+}
+
+fn set(config: &mut Config, key: &str, value: &str) -> ParseResult<()> {
+    match key {
+        "fileVersion" => config.file_version = Parse::parse(value)?,
+        "fontName" => config.font_name = value.to_owned(),
+        "fontFile" => config.font_file = value.to_owned(),
+        "charSet" => config.char_set = Parse::parse(value)?,
+        "fontSize" => config.font_size = Parse::parse(value)?,
+        "aa" => config.aa = Parse::parse(value)?,
+        "scaleH" => config.scale_h = Parse::parse(value)?,
+        "useSmoothing" => config.use_smoothing = Parse::parse(value)?,
+        "isBold" => config.is_bold = Parse::parse(value)?,
+        "isItalic" => config.is_italic = Parse::parse(value)?,
+        "useUnicode" => config.use_unicode = Parse::parse(value)?,
+        "disableBoxChars" => config.disable_box_chars = Parse::parse(value)?,
+        "outputInvalidCharGlyph" => config.output_invalid_char_glyph = Parse::parse(value)?,
+        "dontIncludeKerningPairs" => config.dont_include_kerning_pairs = Parse::parse(value)?,
+        "useHinting" => config.use_hinting = Parse::parse(value)?,
+        "renderFromOutline" => config.render_from_outline = Parse::parse(value)?,
+        "useClearType" => config.use_clear_type = Parse::parse(value)?,
+        "autoFitNumPages" => config.auto_fit_num_pages = Parse::parse(value)?,
+        "autoFitFontSizeMin" => config.auto_fit_font_size_min = Parse::parse(value)?,
+        "autoFitFontSizeMax" => config.auto_fit_font_size_max = Parse::parse(value)?,
+        "paddingDown" => config.padding.down = Parse::parse(value)?,
+        "paddingUp" => config.padding.up = Parse::parse(value)?,
+        "paddingRight" => config.padding.right = Parse::parse(value)?,
+        "paddingLeft" => config.padding.left = Parse::parse(value)?,
+        "spacingHoriz" => config.spacing.horizontal = Parse::parse(value)?,
+        "spacingVert" => config.spacing.vertical = Parse::parse(value)?,
+        "useFixedHeight" => config.use_fixed_height = Parse::parse(value)?,
+        "forceZero" => config.force_zero = Parse::parse(value)?,
+        "widthPaddingFactor" => {
+            config.width_padding_factor =
+                value.parse().map_err(|_| ParseError::Other(format!("float: '{}'", value)))?
+        }
+        "outWidth" => config.out_width = Parse::parse(value)?,
+        "outHeight" => config.out_height = Parse::parse(value)?,
+        "outBitDepth" => config.out_bit_depth = Parse::parse(value)?,
+        "fontDescFormat" => config.font_desc_format = Parse::parse(value)?,
+        "fourChnlPacked" => config.four_chnl_packed = Parse::parse(value)?,
+        "textureFormat" => config.texture_format = value.to_owned(),
+        "textureCompression" => config.texture_compression = Parse::parse(value)?,
+        "alphaChnl" => config.alpha_chnl = Parse::parse(value)?,
+        "redChnl" => config.red_chnl = Parse::parse(value)?,
+        "greenChnl" => config.green_chnl = Parse::parse(value)?,
+        "blueChnl" => config.blue_chnl = Parse::parse(value)?,
+        "invA" => config.inv_a = Parse::parse(value)?,
+        "invR" => config.inv_r = Parse::parse(value)?,
+        "invG" => config.inv_g = Parse::parse(value)?,
+        "invB" => config.inv_b = Parse::parse(value)?,
+        "outlineThickness" => config.outline_thickness = Parse::parse(value)?,
+        "chars" => {
+            for range in value.split(',').map(str::trim).filter(|u| !u.is_empty()) {
+                config.chars.push(parse_range(range)?);
+            }
+        }
+        "icon" => config.icons.push(parse_icon(value)?),
+        _ => {}
+    }
+    Ok(())
+}
+
+fn parse_range(src: &str) -> ParseResult<RangeInclusive<u32>> {
+    match src.split_once('-') {
+        Some((lo, hi)) => Ok(u32::parse(lo.trim())?..=u32::parse(hi.trim())?),
+        None => u32::parse(src).map(|u| u..=u),
+    }
+}
+
+fn parse_icon(src: &str) -> ParseResult<Icon> {
+    let err = || ParseError::Other(format!("icon: '{}'", src));
+    let (file, tail) = match src.strip_prefix('"') {
+        Some(quoted) => {
+            let (file, tail) = quoted.split_once('"').ok_or_else(err)?;
+            (file, tail.strip_prefix(',').ok_or_else(err)?)
+        }
+        None => src.split_once(',').ok_or_else(err)?,
+    };
+    let mut fields = tail.split(',').map(str::trim);
+    let mut next = || fields.next().ok_or_else(err);
+    let icon = Icon {
+        file: file.to_owned(),
+        id: Parse::parse(next()?)?,
+        xoffset: Parse::parse(next()?)?,
+        yoffset: Parse::parse(next()?)?,
+        xadvance: Parse::parse(next()?)?,
+    };
+    match fields.next() {
+        Some(_) => Err(err()),
+        None => Ok(icon),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn range() {
+        assert_eq!(parse_range("32-126"), Ok(32..=126));
+        assert_eq!(parse_range("65"), Ok(65..=65));
+        assert!(parse_range("32-").is_err());
+    }
+
+    #[test]
+    fn icon() {
+        assert_eq!(
+            parse_icon("\"icons/a,b.png\",256,0,-2,12"),
+            Ok(Icon::new("icons/a,b.png".to_owned(), 256, 0, -2, 12))
+        );
+        assert!(parse_icon("\"icons/a.png\",256,0,-2").is_err());
+        assert!(parse_icon("\"icons/a.png,256,0,-2,12").is_err());
+    }
+}
@@ -0,0 +1,35 @@
+//! BMFont configuration operations.
+//!
+//! Load and store AngelCode BMFont generator configuration (`.bmfc`) files, derive the [Info] and
+//! [Common] blocks a configuration would generate and check an existing [Font] against the
+//! configuration it was supposedly generated from.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::fs;
+//!
+//! fn main() -> bmfont_rs::Result<()> {
+//!     let config = bmfont_rs::bmfc::from_bytes(&fs::read("font.bmfc")?)?;
+//!     let font = bmfont_rs::text::from_bytes(&fs::read("font.fnt")?)?;
+//!     print!("{}", config.check(&font));
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [Info]: crate::Info
+//! [Common]: crate::Common
+//! [Font]: crate::Font
+
+mod config;
+mod load;
+mod store;
+
+pub use config::{Config, Icon};
+pub use load::{from_bytes, from_str};
+pub use store::{to_string, to_vec};
+
+#[cfg(feature = "std")]
+pub use load::from_reader;
+#[cfg(feature = "std")]
+pub use store::to_writer;
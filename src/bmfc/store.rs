@@ -0,0 +1,179 @@
+use crate::prelude::*;
+
+use super::config::Config;
+
+use core::fmt::{self, Write};
+
+#[cfg(feature = "std")]
+use std::io;
+
+/// Maximum number of character ranges per `chars` line.
+const CHARS_PER_LINE: usize = 16;
+
+/// Store BMFont configuration.
+///
+/// Store a configuration into a [String] in `.bmfc` format, with the key order and section
+/// comments of the BMFont generator.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```
+/// fn main() -> bmfont_rs::Result<()> {
+///     let config = bmfont_rs::bmfc::Config::default();
+///     let string = bmfont_rs::bmfc::to_string(&config)?;
+///     assert!(string.contains("fontName=Arial\n"));
+///     Ok(())
+/// }
+/// ```
+pub fn to_string(config: &Config) -> crate::Result<String> {
+    check_value("fontName", &config.font_name)?;
+    check_value("fontFile", &config.font_file)?;
+    check_value("textureFormat", &config.texture_format)?;
+    for icon in &config.icons {
+        if icon.file.contains(['"', '\n', '\r']) {
+            return Err(crate::Error::UnsupportedValueEncoding {
+                path: "icon".to_owned(),
+                value: icon.file.clone(),
+            });
+        }
+    }
+    let mut dst = String::default();
+    store(&mut dst, config).map_err(|e| crate::Error::Internal { err: e.to_string() })?;
+    Ok(dst)
+}
+
+/// Store BMFont configuration.
+///
+/// Store a configuration into a [Vec] in `.bmfc` format.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+pub fn to_vec(config: &Config) -> crate::Result<Vec<u8>> {
+    to_string(config).map(String::into_bytes)
+}
+
+/// Write BMFont configuration.
+///
+/// Write a configuration to the specified writer in `.bmfc` format.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let config = bmfont_rs::bmfc::Config::default();
+///     let mut writer = File::create("font.bmfc")?;
+///     bmfont_rs::bmfc::to_writer(&mut writer, &config)?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn to_writer<W: io::Write>(mut writer: W, config: &Config) -> crate::Result<()> {
+    let string = to_string(config)?;
+    writer.write_all(string.as_bytes())?;
+    Ok(())
+}
+
+fn check_value(key: &str, value: &str) -> crate::Result<()> {
+    if value.contains(['\n', '\r']) {
+        Err(crate::Error::UnsupportedValueEncoding {
+            path: key.to_owned(),
+            value: value.to_owned(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+fn store(dst: &mut String, config: &Config) -> fmt::Result {
+    let b = |v: bool| v as u8;
+    writeln!(dst, "# AngelCode Bitmap Font Generator configuration file")?;
+    writeln!(dst, "fileVersion={}", config.file_version)?;
+    writeln!(dst)?;
+    writeln!(dst, "# font settings")?;
+    writeln!(dst, "fontName={}", config.font_name)?;
+    writeln!(dst, "fontFile={}", config.font_file)?;
+    writeln!(dst, "charSet={}", config.char_set)?;
+    writeln!(dst, "fontSize={}", config.font_size)?;
+    writeln!(dst, "aa={}", config.aa)?;
+    writeln!(dst, "scaleH={}", config.scale_h)?;
+    writeln!(dst, "useSmoothing={}", b(config.use_smoothing))?;
+    writeln!(dst, "isBold={}", b(config.is_bold))?;
+    writeln!(dst, "isItalic={}", b(config.is_italic))?;
+    writeln!(dst, "useUnicode={}", b(config.use_unicode))?;
+    writeln!(dst, "disableBoxChars={}", b(config.disable_box_chars))?;
+    writeln!(dst, "outputInvalidCharGlyph={}", b(config.output_invalid_char_glyph))?;
+    writeln!(dst, "dontIncludeKerningPairs={}", b(config.dont_include_kerning_pairs))?;
+    writeln!(dst, "useHinting={}", b(config.use_hinting))?;
+    writeln!(dst, "renderFromOutline={}", b(config.render_from_outline))?;
+    writeln!(dst, "useClearType={}", b(config.use_clear_type))?;
+    writeln!(dst, "autoFitNumPages={}", config.auto_fit_num_pages)?;
+    writeln!(dst, "autoFitFontSizeMin={}", config.auto_fit_font_size_min)?;
+    writeln!(dst, "autoFitFontSizeMax={}", config.auto_fit_font_size_max)?;
+    writeln!(dst)?;
+    writeln!(dst, "# character alignment")?;
+    writeln!(dst, "paddingDown={}", config.padding.down)?;
+    writeln!(dst, "paddingUp={}", config.padding.up)?;
+    writeln!(dst, "paddingRight={}", config.padding.right)?;
+    writeln!(dst, "paddingLeft={}", config.padding.left)?;
+    writeln!(dst, "spacingHoriz={}", config.spacing.horizontal)?;
+    writeln!(dst, "spacingVert={}", config.spacing.vertical)?;
+    writeln!(dst, "useFixedHeight={}", b(config.use_fixed_height))?;
+    writeln!(dst, "forceZero={}", b(config.force_zero))?;
+    writeln!(dst, "widthPaddingFactor={:.2}", config.width_padding_factor)?;
+    writeln!(dst)?;
+    writeln!(dst, "# output file")?;
+    writeln!(dst, "outWidth={}", config.out_width)?;
+    writeln!(dst, "outHeight={}", config.out_height)?;
+    writeln!(dst, "outBitDepth={}", config.out_bit_depth)?;
+    writeln!(dst, "fontDescFormat={}", config.font_desc_format)?;
+    writeln!(dst, "fourChnlPacked={}", b(config.four_chnl_packed))?;
+    writeln!(dst, "textureFormat={}", config.texture_format)?;
+    writeln!(dst, "textureCompression={}", config.texture_compression)?;
+    writeln!(dst, "alphaChnl={}", u8::from(config.alpha_chnl))?;
+    writeln!(dst, "redChnl={}", u8::from(config.red_chnl))?;
+    writeln!(dst, "greenChnl={}", u8::from(config.green_chnl))?;
+    writeln!(dst, "blueChnl={}", u8::from(config.blue_chnl))?;
+    writeln!(dst, "invA={}", b(config.inv_a))?;
+    writeln!(dst, "invR={}", b(config.inv_r))?;
+    writeln!(dst, "invG={}", b(config.inv_g))?;
+    writeln!(dst, "invB={}", b(config.inv_b))?;
+    writeln!(dst)?;
+    writeln!(dst, "# outline")?;
+    writeln!(dst, "outlineThickness={}", config.outline_thickness)?;
+    writeln!(dst)?;
+    writeln!(dst, "# selected chars")?;
+    for ranges in config.chars.chunks(CHARS_PER_LINE) {
+        write!(dst, "chars=")?;
+        for (i, range) in ranges.iter().enumerate() {
+            let sep = if i == 0 { "" } else { "," };
+            if range.start() == range.end() {
+                write!(dst, "{}{}", sep, range.start())?;
+            } else {
+                write!(dst, "{}{}-{}", sep, range.start(), range.end())?;
+            }
+        }
+        writeln!(dst)?;
+    }
+    writeln!(dst)?;
+    writeln!(dst, "# imported icon images")?;
+    for icon in &config.icons {
+        writeln!(
+            dst,
+            "icon=\"{}\",{},{},{},{}",
+            icon.file, icon.id, icon.xoffset, icon.yoffset, icon.xadvance
+        )?;
+    }
+    Ok(())
+}
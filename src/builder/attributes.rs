@@ -1,3 +1,4 @@
+use crate::prelude::*;
 use crate::tagged_attributes::TaggedAttributes;
 
 pub trait Attributes<'a> {
@@ -32,6 +33,87 @@ impl<'a> Attributes<'a> for TaggedAttributes<'a> {
     }
 }
 
+/// Attributes adapter that intercepts, and withholds, the specified key.
+pub struct Intercept<'a, 'b, A> {
+    attributes: &'b mut A,
+    key: &'static [u8],
+    value: Option<Attribute<'a>>,
+}
+
+impl<'a, 'b, A: Attributes<'a>> Intercept<'a, 'b, A> {
+    pub fn new(attributes: &'b mut A, key: &'static [u8]) -> Self {
+        Self { attributes, key, value: None }
+    }
+
+    /// The intercepted attribute, if present.
+    pub fn value(&self) -> Option<Attribute<'a>> {
+        self.value
+    }
+}
+
+impl<'a, 'b, A: Attributes<'a>> Attributes<'a> for Intercept<'a, 'b, A> {
+    fn next_attribute(&mut self) -> crate::Result<Option<Attribute<'a>>> {
+        loop {
+            match self.attributes.next_attribute()? {
+                Some(attribute) if attribute.key == self.key => {
+                    if self.value.is_some() {
+                        let key = String::from_utf8_lossy(self.key).into();
+                        return Err(crate::Error::DuplicateKey { line: attribute.line, key });
+                    }
+                    self.value = Some(attribute);
+                }
+                attribute => return Ok(attribute),
+            }
+        }
+    }
+}
+
+/// A single, optional, attribute.
+#[cfg(feature = "xml")]
+impl<'a> Attributes<'a> for Option<Attribute<'a>> {
+    fn next_attribute(&mut self) -> crate::Result<Option<Attribute<'a>>> {
+        Ok(self.take())
+    }
+}
+
+/// The attribute value as a str, if present.
+pub fn value_str(attribute: Option<Attribute<'_>>) -> crate::Result<Option<&str>> {
+    match attribute {
+        Some(Attribute { key, value, line }) => match core::str::from_utf8(value) {
+            Ok(u) => Ok(Some(u)),
+            Err(e) => Err(crate::Error::Parse {
+                line,
+                entity: String::from_utf8_lossy(key).into(),
+                err: e.to_string(),
+            }),
+        },
+        None => Ok(None),
+    }
+}
+
+/// Attributes adapter that discards all but the specified keys.
+pub struct Retain<'b, A> {
+    attributes: &'b mut A,
+    keys: &'static [&'static [u8]],
+}
+
+impl<'b, A> Retain<'b, A> {
+    pub fn new(attributes: &'b mut A, keys: &'static [&'static [u8]]) -> Self {
+        Self { attributes, keys }
+    }
+}
+
+impl<'a, 'b, A: Attributes<'a>> Attributes<'a> for Retain<'b, A> {
+    fn next_attribute(&mut self) -> crate::Result<Option<Attribute<'a>>> {
+        loop {
+            match self.attributes.next_attribute()? {
+                Some(attribute) if !self.keys.contains(&attribute.key) => continue,
+                attribute => return Ok(attribute),
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,6 +125,37 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn intercept() -> crate::Result<()> {
+        let mut attributes = TaggedAttributes::from_bytes(b"a=1 b=\"2\" c=3");
+        let mut intercept = Intercept::new(&mut attributes, b"b");
+        assert_eq!(intercept.next_attribute()?, Some(Attribute::new(b"a", b"1", Some(1))));
+        assert_eq!(intercept.next_attribute()?, Some(Attribute::new(b"c", b"3", Some(1))));
+        assert_eq!(intercept.next_attribute()?, None);
+        assert_eq!(value_str(intercept.value())?, Some("2"));
+        Ok(())
+    }
+
+    #[test]
+    fn intercept_duplicate() {
+        let mut attributes = TaggedAttributes::from_bytes(b"b=1 b=2");
+        let mut intercept = Intercept::new(&mut attributes, b"b");
+        match intercept.next_attribute() {
+            Err(crate::Error::DuplicateKey { .. }) => {}
+            _ => panic!("expected error"),
+        }
+    }
+
+    #[test]
+    fn retain() -> crate::Result<()> {
+        let mut attributes = TaggedAttributes::from_bytes(b"a=1 b=2 c=3");
+        let mut retain = Retain::new(&mut attributes, &[b"a", b"c"]);
+        assert_eq!(retain.next_attribute()?, Some(Attribute::new(b"a", b"1", Some(1))));
+        assert_eq!(retain.next_attribute()?, Some(Attribute::new(b"c", b"3", Some(1))));
+        assert_eq!(retain.next_attribute()?, None);
+        Ok(())
+    }
+
     #[test]
     fn tagged_attributes_next_attribute_err() -> crate::Result<()> {
         let mut attributes = TaggedAttributes::from_bytes(b"key=");
@@ -1,22 +1,34 @@
 use crate::charset::Charset;
 use crate::font::{Char, Chnl, Common, Info, Padding, Page, Spacing};
 use crate::font::{Kerning, Packing};
-use crate::parse::Parse;
-use crate::Error;
+use crate::parse::{Adjustment, Parse};
+use crate::prelude::*;
+use crate::{Error, LoadSettings, Warning};
 
 use super::attributes::{Attribute, Attributes};
 use super::Count;
+#[cfg(feature = "xml")]
+use super::LetterSpacing;
 
 pub trait Load: Sized {
-    fn load<'b, A: Attributes<'b>>(attributes: &mut A) -> crate::Result<Self>;
+    fn load<'b, A: Attributes<'b>>(
+        attributes: &mut A,
+        settings: &LoadSettings,
+        warnings: &mut Vec<Warning>,
+    ) -> crate::Result<Self>;
 }
 
 macro_rules! implement_load {
     ($object:ty, $(($type:ty, $id:expr, $key:expr, $field:ident)),+) => {
         impl Load for $object {
-            fn load<'b, A: Attributes<'b>>(attributes: &mut A) -> crate::Result<Self> {
+            fn load<'b, A: Attributes<'b>>(
+                attributes: &mut A,
+                settings: &LoadSettings,
+                warnings: &mut Vec<Warning>,
+            ) -> crate::Result<Self> {
                 let mut block = Self::default();
                 let mut bit_mask: u32 = 0x0000_0000;
+                let mut adjustments: Vec<Adjustment> = Vec::default();
                 while let Some(Attribute { key, value, line }) = attributes.next_attribute()? {
                     match key {
                         $(
@@ -27,7 +39,7 @@ macro_rules! implement_load {
                                     return Err(Error::DuplicateKey{ line, key });
                                 }
                                 bit_mask |= bit;
-                                match <$type>::parse_bytes(&value) {
+                                match <$type>::parse_bytes_ext(&value, settings, &mut adjustments) {
                                     Ok(v) => block.$field = v,
                                     Err(err) => {
                                         let err = err.to_string();
@@ -35,6 +47,18 @@ macro_rules! implement_load {
                                         return Err(Error::Parse{ line, entity:key, err });
                                     }
                                 }
+                                for adjustment in adjustments.drain(..) {
+                                    let key = String::from_utf8_lossy($key).into();
+                                    warnings.push(match adjustment {
+                                        Adjustment::Numeric { value, adjusted } => {
+                                            Warning::NumericAdjusted { line, key, value, adjusted }
+                                        }
+                                        Adjustment::Transcoded { encoding } => {
+                                            let encoding = encoding.into();
+                                            Warning::Transcoded { line, key, encoding }
+                                        }
+                                    });
+                                }
                             },
                         )*
                         key => {
@@ -84,6 +108,9 @@ implement_load!(
 
 implement_load!(Count, (u32, 0x0, b"count", count));
 
+#[cfg(feature = "xml")]
+implement_load!(LetterSpacing, (i16, 0x0, b"letterSpacing", letter_spacing));
+
 implement_load!(
     Info,
     (String, 0x0, b"face", face),
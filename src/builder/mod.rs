@@ -2,10 +2,18 @@ pub mod attributes;
 pub mod load;
 pub mod tags;
 
+use crate::event::Event;
 use crate::font::{Char, Common, Font, Info, Kerning, Page};
-use crate::{Charset, Error, LoadSettings};
+use crate::prelude::*;
+use crate::{Charset, DuplicateCharPolicy, DuplicateKerningPolicy, Error, LoadSettings};
+use crate::{LoadReport, TextEncoding, Warning};
 
+use alloc::collections::btree_map::Entry;
+use alloc::collections::BTreeMap;
+
+#[cfg(any(feature = "xml", feature = "plist"))]
 use attributes::Attributes;
+#[cfg(any(feature = "xml", feature = "plist"))]
 use load::Load;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -13,6 +21,13 @@ pub struct Count {
     pub count: u32,
 }
 
+/// The Phaser XML `<info>` `letterSpacing` attribute.
+#[cfg(feature = "xml")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LetterSpacing {
+    pub letter_spacing: i16,
+}
+
 #[derive(Debug, Default)]
 pub struct FontProto {
     pub info: Option<Info>,
@@ -46,24 +61,23 @@ impl FontProto {
     }
 
     pub fn build(self, settings: &LoadSettings) -> crate::Result<Font> {
-        let font = self.build_unchecked()?;
-        if !settings.ignore_counts {
-            {
-                let specified = font.common.pages;
-                let realized = font.pages.len();
-                if specified as usize != realized {
-                    return Err(Error::InvalidPageCount { specified, realized });
-                }
-            }
-        }
+        self.build_lines(&[], &[], settings)
+    }
+
+    /// As `build`, with the source line numbers of the characters and kerning pairs, if known.
+    fn build_lines(
+        self,
+        char_lines: &[Option<usize>],
+        kerning_lines: &[Option<usize>],
+        settings: &LoadSettings,
+    ) -> crate::Result<Font> {
+        let mut font = self.build_unchecked()?;
+        font.chars = resolve_chars(font.chars, char_lines, settings.duplicate_chars)?;
+        font.kernings =
+            resolve_kernings(font.kernings, kerning_lines, settings.duplicate_kernings)?;
+        check_pages(&font.common, font.pages.len(), settings)?;
         if !settings.allow_string_control_characters {
-            if let Charset::Undefined(u) = &font.info.charset {
-                check_string("charset undefined", u)?;
-            }
-            for page in &font.pages {
-                check_string("page id", page)?;
-            }
-            check_string("info face", &font.info.face)?;
+            check_strings(&font.info.charset, &font.info.face, &font.pages)?;
         }
         Ok(font)
     }
@@ -117,17 +131,38 @@ impl FontProto {
 
 #[derive(Debug, Default)]
 pub struct FontBuilder {
+    settings: LoadSettings,
+    warnings: Vec<Warning>,
     proto: FontProto,
     pages: Vec<String>,
     chars: Vec<Char>,
+    char_lines: Vec<Option<usize>>,
     char_count: Option<u32>,
     kernings: Vec<Kerning>,
+    kerning_lines: Vec<Option<usize>>,
     kerning_count: Option<u32>,
+    letter_spacing: Option<i16>,
 }
 
 impl FontBuilder {
-    pub fn build(self, settings: &LoadSettings) -> crate::Result<Font> {
-        if !settings.ignore_counts {
+    pub fn new(settings: &LoadSettings) -> Self {
+        Self { settings: *settings, ..Default::default() }
+    }
+
+    pub fn settings(&self) -> &LoadSettings {
+        &self.settings
+    }
+
+    pub fn build_report(mut self) -> crate::Result<LoadReport> {
+        let warnings = core::mem::take(&mut self.warnings);
+        let letter_spacing = self.letter_spacing;
+        let mut report = LoadReport::new(self.build()?, TextEncoding::Utf8, warnings);
+        report.letter_spacing = letter_spacing;
+        Ok(report)
+    }
+
+    pub fn build(self) -> crate::Result<Font> {
+        if !self.settings.ignore_counts {
             if let Some(specified) = self.char_count {
                 let realized = self.chars.len();
                 if specified as usize != realized {
@@ -141,13 +176,92 @@ impl FontBuilder {
                 }
             }
         }
-        let FontBuilder { mut proto, pages, chars, kernings, .. } = self;
+        let FontBuilder {
+            settings,
+            mut proto,
+            pages,
+            chars,
+            char_lines,
+            kernings,
+            kerning_lines,
+            ..
+        } = self;
         proto.set_pages(None, pages)?;
         proto.set_chars(None, chars)?;
         proto.set_kernings(None, kernings)?;
-        proto.build(settings)
+        proto.build_lines(&char_lines, &kerning_lines, &settings)
+    }
+
+    pub fn add_event(&mut self, line: Option<usize>, event: Event) -> crate::Result<()> {
+        match event {
+            Event::Info(info) => self.proto.set_info(line, info),
+            Event::Common(common) => self.proto.set_common(line, common),
+            Event::Page { id, file } => self.add_page(Page { id, file }),
+            Event::CharCount(count) => self.set_char_count(line, count),
+            Event::Char(char) => self.add_char(line, char),
+            Event::KerningCount(count) => self.set_kerning_count(line, count),
+            Event::Kerning(kerning) => self.add_kerning(line, kerning),
+        }
+    }
+
+    pub fn extend_warnings(&mut self, warnings: Vec<Warning>) {
+        self.warnings.extend(warnings);
+    }
+
+    pub fn add_page(&mut self, page: Page) -> crate::Result<()> {
+        let Page { id, file } = page;
+        if id as usize == self.pages.len() {
+            self.pages.push(file);
+            Ok(())
+        } else {
+            Err(crate::Error::BrokenPageList)
+        }
+    }
+
+    pub fn add_char(&mut self, line: Option<usize>, char: Char) -> crate::Result<()> {
+        self.chars.push(char);
+        self.char_lines.push(line);
+        Ok(())
+    }
+
+    pub fn set_char_count(&mut self, line: Option<usize>, char_count: u32) -> crate::Result<()> {
+        if self.char_count.is_some() {
+            Err(Error::DuplicateCharCount { line })
+        } else {
+            self.char_count = Some(char_count);
+            if self.chars.len() < char_count as usize {
+                self.chars.reserve(char_count as usize - self.chars.len())
+            }
+            Ok(())
+        }
     }
 
+    pub fn set_kerning_count(
+        &mut self,
+        line: Option<usize>,
+        kerning_count: u32,
+    ) -> crate::Result<()> {
+        if self.kerning_count.is_some() {
+            Err(Error::DuplicateKerningCount { line })
+        } else {
+            self.kerning_count = Some(kerning_count);
+            if self.kernings.len() < kerning_count as usize {
+                self.kernings.reserve(kerning_count as usize - self.kernings.len())
+            }
+            Ok(())
+        }
+    }
+
+    pub fn add_kerning(&mut self, line: Option<usize>, kerning: Kerning) -> crate::Result<()> {
+        self.kernings.push(kerning);
+        self.kerning_lines.push(line);
+        Ok(())
+    }
+}
+
+/// Attribute loading, as used by the XML based formats.
+#[cfg(any(feature = "xml", feature = "plist"))]
+impl FontBuilder {
     pub fn set_info_attributes<'b, A>(
         &mut self,
         line: Option<usize>,
@@ -156,7 +270,7 @@ impl FontBuilder {
     where
         A: Attributes<'b>,
     {
-        self.proto.set_info(line, Info::load(attributes)?)
+        self.proto.set_info(line, Info::load(attributes, &self.settings, &mut self.warnings)?)
     }
 
     pub fn set_common_attributes<'b, A>(
@@ -167,38 +281,41 @@ impl FontBuilder {
     where
         A: Attributes<'b>,
     {
-        self.proto.set_common(line, Common::load(attributes)?)
+        self.proto.set_common(line, Common::load(attributes, &self.settings, &mut self.warnings)?)
     }
 
     pub fn add_page_attributes<'b, A>(&mut self, attributes: &mut A) -> crate::Result<()>
     where
         A: Attributes<'b>,
     {
-        self.add_page(Page::load(attributes)?)
-    }
-
-    pub fn add_page(&mut self, page: Page) -> crate::Result<()> {
-        let Page { id, file } = page;
-        if id as usize == self.pages.len() {
-            self.pages.push(file);
-            Ok(())
-        } else {
-            Err(crate::Error::BrokenPageList)
-        }
+        let page = Page::load(attributes, &self.settings, &mut self.warnings)?;
+        self.add_page(page)
     }
 
-    pub fn add_char_attributes<'b, A>(&mut self, attributes: &mut A) -> crate::Result<()>
+    pub fn add_char_attributes<'b, A>(
+        &mut self,
+        line: Option<usize>,
+        attributes: &mut A,
+    ) -> crate::Result<()>
     where
         A: Attributes<'b>,
     {
-        self.add_char(Char::load(attributes)?)
+        let char = Char::load(attributes, &self.settings, &mut self.warnings)?;
+        self.add_char(line, char)
     }
 
-    pub fn add_char(&mut self, char: Char) -> crate::Result<()> {
-        self.chars.push(char);
+    #[cfg(feature = "xml")]
+    pub fn set_letter_spacing_attributes<'b, A>(&mut self, attributes: &mut A) -> crate::Result<()>
+    where
+        A: Attributes<'b>,
+    {
+        let LetterSpacing { letter_spacing } =
+            LetterSpacing::load(attributes, &self.settings, &mut self.warnings)?;
+        self.letter_spacing = Some(letter_spacing);
         Ok(())
     }
 
+    #[cfg(feature = "xml")]
     pub fn set_char_count_attributes<'b, A>(
         &mut self,
         line: Option<usize>,
@@ -207,21 +324,11 @@ impl FontBuilder {
     where
         A: Attributes<'b>,
     {
-        Count::load(attributes).and_then(|Count { count }| self.set_char_count(line, count))
-    }
-
-    pub fn set_char_count(&mut self, line: Option<usize>, char_count: u32) -> crate::Result<()> {
-        if self.char_count.is_some() {
-            Err(Error::DuplicateCharCount { line })
-        } else {
-            self.char_count = Some(char_count);
-            if self.chars.len() < char_count as usize {
-                self.chars.reserve(char_count as usize - self.chars.len())
-            }
-            Ok(())
-        }
+        Count::load(attributes, &self.settings, &mut self.warnings)
+            .and_then(|Count { count }| self.set_char_count(line, count))
     }
 
+    #[cfg(feature = "xml")]
     pub fn set_kerning_count_attributes<'b, A>(
         &mut self,
         line: Option<usize>,
@@ -230,38 +337,116 @@ impl FontBuilder {
     where
         A: Attributes<'b>,
     {
-        Count::load(attributes).and_then(|Count { count }| self.set_kerning_count(line, count))
+        Count::load(attributes, &self.settings, &mut self.warnings)
+            .and_then(|Count { count }| self.set_kerning_count(line, count))
     }
 
-    pub fn set_kerning_count(
+    pub fn add_kerning_attributes<'b, A>(
         &mut self,
         line: Option<usize>,
-        kerning_count: u32,
-    ) -> crate::Result<()> {
-        if self.kerning_count.is_some() {
-            Err(Error::DuplicateKerningCount { line })
-        } else {
-            self.kerning_count = Some(kerning_count);
-            if self.kernings.len() < kerning_count as usize {
-                self.kernings.reserve(kerning_count as usize - self.kernings.len())
+        attributes: &mut A,
+    ) -> crate::Result<()>
+    where
+        A: Attributes<'b>,
+    {
+        let kerning = Kerning::load(attributes, &self.settings, &mut self.warnings)?;
+        self.add_kerning(line, kerning)
+    }
+}
+
+/// Resolve duplicate characters. Lines, where known, are indexed as the characters.
+fn resolve_chars(
+    chars: Vec<Char>,
+    lines: &[Option<usize>],
+    policy: DuplicateCharPolicy,
+) -> crate::Result<Vec<Char>> {
+    if policy == DuplicateCharPolicy::KeepAll {
+        return Ok(chars);
+    }
+    let mut index: BTreeMap<u32, usize> = BTreeMap::new();
+    let mut dst = Vec::with_capacity(chars.len());
+    for (i, char) in chars.into_iter().enumerate() {
+        match index.entry(char.id) {
+            Entry::Vacant(entry) => {
+                entry.insert(dst.len());
+                dst.push(char);
             }
-            Ok(())
+            Entry::Occupied(entry) => match policy {
+                DuplicateCharPolicy::Error => {
+                    let line = lines.get(i).copied().flatten();
+                    return Err(Error::DuplicateChar { line, id: char.id });
+                }
+                DuplicateCharPolicy::KeepAll | DuplicateCharPolicy::FirstWins => {}
+                DuplicateCharPolicy::LastWins => dst[*entry.get()] = char,
+            },
         }
     }
+    Ok(dst)
+}
 
-    pub fn add_kerning_attributes<'b, A>(&mut self, attributes: &mut A) -> crate::Result<()>
-    where
-        A: Attributes<'b>,
-    {
-        self.add_kerning(Kerning::load(attributes)?)
+/// Resolve duplicate kerning pairs. Lines, where known, are indexed as the kerning pairs.
+fn resolve_kernings(
+    kernings: Vec<Kerning>,
+    lines: &[Option<usize>],
+    policy: DuplicateKerningPolicy,
+) -> crate::Result<Vec<Kerning>> {
+    if policy == DuplicateKerningPolicy::KeepAll {
+        return Ok(kernings);
     }
+    let mut index: BTreeMap<(u32, u32), usize> = BTreeMap::new();
+    let mut dst: Vec<Kerning> = Vec::with_capacity(kernings.len());
+    for (i, kerning) in kernings.into_iter().enumerate() {
+        match index.entry((kerning.first, kerning.second)) {
+            Entry::Vacant(entry) => {
+                entry.insert(dst.len());
+                dst.push(kerning);
+            }
+            Entry::Occupied(entry) => match policy {
+                DuplicateKerningPolicy::Error => {
+                    return Err(Error::DuplicateKerningPair {
+                        line: lines.get(i).copied().flatten(),
+                        first: kerning.first,
+                        second: kerning.second,
+                    })
+                }
+                DuplicateKerningPolicy::KeepAll | DuplicateKerningPolicy::FirstWins => {}
+                DuplicateKerningPolicy::LastWins => dst[*entry.get()] = kerning,
+                DuplicateKerningPolicy::Sum => {
+                    let amount = &mut dst[*entry.get()].amount;
+                    *amount = amount.saturating_add(kerning.amount);
+                }
+            },
+        }
+    }
+    Ok(dst)
+}
 
-    pub fn add_kerning(&mut self, kerning: Kerning) -> crate::Result<()> {
-        self.kernings.push(kerning);
+/// Check the specified page count against the common block, unless ignored.
+pub fn check_pages(common: &Common, realized: usize, settings: &LoadSettings) -> crate::Result<()> {
+    let specified = common.pages;
+    if !settings.ignore_counts && specified as usize != realized {
+        Err(Error::InvalidPageCount { specified, realized })
+    } else {
         Ok(())
     }
 }
 
+/// Check the font strings for control characters.
+pub fn check_strings<S: AsRef<str>>(
+    charset: &Charset,
+    face: &str,
+    pages: &[S],
+) -> crate::Result<()> {
+    if let Charset::Undefined(u) = charset {
+        check_string("charset undefined", u)?;
+    }
+    for page in pages {
+        check_string("page id", page.as_ref())?;
+    }
+    check_string("info face", face)?;
+    Ok(())
+}
+
 fn check_string<'a>(path: &'a str, value: &'a str) -> crate::Result<&'a str> {
     for c in value.chars() {
         match c {
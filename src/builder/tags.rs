@@ -1,3 +1,4 @@
+use crate::prelude::*;
 use crate::tagged_attributes::TaggedAttributes;
 
 pub trait Tags<'a> {
@@ -1,9 +1,11 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::parse::{Parse, ParseResult};
+use crate::parse::{Adjustment, Parse, ParseResult};
+use crate::prelude::*;
+use crate::settings::LoadSettings;
 
-use std::fmt;
+use core::fmt;
 
 /// ANSI character encoding.
 pub const ANSI: u8 = 0;
@@ -235,6 +237,14 @@ impl Parse for Charset {
     fn parse(src: &str) -> ParseResult<Self> {
         Ok(src.into())
     }
+
+    fn parse_bytes_ext(
+        bytes: &[u8],
+        settings: &LoadSettings,
+        adjustments: &mut Vec<Adjustment>,
+    ) -> ParseResult<Self> {
+        String::parse_bytes_ext(bytes, settings, adjustments).map(Into::into)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,247 @@
+//! Rust source code generation.
+//!
+//! Convert a [Font] into Rust source defining a [StaticFont](crate::StaticFont) along with its
+//! `static` character and kerning pair tables. Intended for use from `build.rs`, allowing
+//! embedded targets to include fonts without runtime parsing or allocation.
+//!
+//! For a specified name `FONT`, the generated source defines:
+//! * `pub static FONT: ::bmfont_rs::StaticFont`
+//! * `pub static FONT_CHARS: [::bmfont_rs::Char; N]`, sorted by id
+//! * `pub static FONT_KERNINGS: [::bmfont_rs::Kerning; N]`, sorted by first/ second ids
+//!
+//! The font is normalized, see [Font::normalize], prior to generation.
+//!
+//! # Example
+//!
+//! `build.rs`:
+//!
+//! ```no_run
+//! use std::env;
+//! use std::fs::File;
+//! use std::path::Path;
+//!
+//! fn main() -> bmfont_rs::Result<()> {
+//!     let font = bmfont_rs::binary::from_bytes(&std::fs::read("font.bin")?)?;
+//!     let out_dir = env::var("OUT_DIR").unwrap();
+//!     let writer = File::create(Path::new(&out_dir).join("font.rs"))?;
+//!     bmfont_rs::codegen::to_writer(writer, &font, "FONT")?;
+//!     println!("cargo:rerun-if-changed=font.bin");
+//!     Ok(())
+//! }
+//! ```
+//!
+//! Target crate:
+//!
+//! ```ignore
+//! include!(concat!(env!("OUT_DIR"), "/font.rs"));
+//!
+//! fn advance(c: char) -> i16 {
+//!     FONT.char(c as u32).map(|u| u.xadvance).unwrap_or_default()
+//! }
+//! ```
+
+use crate::font::{Char, Chnl, Common, Font, Kerning};
+use crate::prelude::*;
+
+use core::fmt::{self, Write};
+
+#[cfg(feature = "std")]
+use std::io;
+
+/// Crate path prefix used in the generated source.
+const CRATE: &str = "::bmfont_rs";
+
+/// Rust strict and reserved keywords, 2018 edition onwards.
+const KEYWORDS: &[&str] = &[
+    "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+    "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+    "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub",
+    "ref", "return", "self", "static", "struct", "super", "trait", "true", "try", "type", "typeof",
+    "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+];
+
+/// Generate Rust source.
+///
+/// Generate Rust source defining a static font with the specified name, which must be a valid
+/// Rust identifier and not a Rust keyword.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let src = bmfont_rs::codegen::to_string(&font, "FONT")?;
+///     assert!(src.contains("pub static FONT: ::bmfont_rs::StaticFont"));
+///     Ok(())
+/// }
+/// ```
+pub fn to_string(font: &Font, name: &str) -> crate::Result<String> {
+    check_name(name)?;
+    let mut font = font.clone();
+    font.normalize();
+    let mut dst = String::default();
+    generate(&mut dst, &font, name).map_err(|e| crate::Error::Internal { err: e.to_string() })?;
+    Ok(dst)
+}
+
+/// Write generated Rust source.
+///
+/// As [to_string], writing the generated source to the specified writer.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+#[cfg(feature = "std")]
+pub fn to_writer<W: io::Write>(mut writer: W, font: &Font, name: &str) -> crate::Result<()> {
+    let src = to_string(font, name)?;
+    writer.write_all(src.as_bytes())?;
+    Ok(())
+}
+
+fn check_name(name: &str) -> crate::Result<()> {
+    let mut chars = name.chars();
+    let valid = match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            name != "_"
+                && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+                && !KEYWORDS.contains(&name)
+        }
+        _ => false,
+    };
+    if valid {
+        Ok(())
+    } else {
+        Err(crate::Error::UnsupportedValueEncoding {
+            path: "codegen name".to_owned(),
+            value: name.to_owned(),
+        })
+    }
+}
+
+fn generate(dst: &mut String, font: &Font, name: &str) -> fmt::Result {
+    writeln!(dst, "// Generated by bmfont_rs, do not edit.")?;
+    writeln!(dst)?;
+    writeln!(dst, "pub static {}: {}::StaticFont = {}::StaticFont::new(", name, CRATE, CRATE)?;
+    writeln!(dst, "    {:?},", font.info.face)?;
+    writeln!(dst, "    {},", font.info.size)?;
+    common(dst, &font.common)?;
+    write!(dst, "    &[")?;
+    for (i, page) in font.pages.iter().enumerate() {
+        write!(dst, "{}{:?}", if i == 0 { "" } else { ", " }, page)?;
+    }
+    writeln!(dst, "],")?;
+    writeln!(dst, "    &{}_CHARS,", name)?;
+    writeln!(dst, "    &{}_KERNINGS,", name)?;
+    writeln!(dst, ");")?;
+    writeln!(dst)?;
+    writeln!(dst, "pub static {}_CHARS: [{}::Char; {}] = [", name, CRATE, font.chars.len())?;
+    font.chars.iter().try_for_each(|u| char(dst, u))?;
+    writeln!(dst, "];")?;
+    writeln!(dst)?;
+    writeln!(
+        dst,
+        "pub static {}_KERNINGS: [{}::Kerning; {}] = [",
+        name,
+        CRATE,
+        font.kernings.len()
+    )?;
+    font.kernings.iter().try_for_each(|u| kerning(dst, u))?;
+    writeln!(dst, "];")
+}
+
+fn common(dst: &mut String, common: &Common) -> fmt::Result {
+    writeln!(
+        dst,
+        "    {}::Common::new({}, {}, {}, {}, {}, {}, {}::Packing::{:?}, {}::Packing::{:?}, \
+         {}::Packing::{:?}, {}::Packing::{:?}),",
+        CRATE,
+        common.line_height,
+        common.base,
+        common.scale_w,
+        common.scale_h,
+        common.pages,
+        common.packed,
+        CRATE,
+        common.alpha_chnl,
+        CRATE,
+        common.red_chnl,
+        CRATE,
+        common.green_chnl,
+        CRATE,
+        common.blue_chnl
+    )
+}
+
+fn char(dst: &mut String, char: &Char) -> fmt::Result {
+    writeln!(
+        dst,
+        "    {}::Char::new({}, {}, {}, {}, {}, {}, {}, {}, {}, {}),",
+        CRATE,
+        char.id,
+        char.x,
+        char.y,
+        char.width,
+        char.height,
+        char.xoffset,
+        char.yoffset,
+        char.xadvance,
+        char.page,
+        ChnlSrc(char.chnl)
+    )
+}
+
+fn kerning(dst: &mut String, kerning: &Kerning) -> fmt::Result {
+    writeln!(
+        dst,
+        "    {}::Kerning::new({}, {}, {}),",
+        CRATE, kerning.first, kerning.second, kerning.amount
+    )
+}
+
+struct ChnlSrc(Chnl);
+
+impl fmt::Display for ChnlSrc {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let constant = match self.0 {
+            Chnl::ALL => "ALL",
+            Chnl::ALPHA => "ALPHA",
+            Chnl::RED => "RED",
+            Chnl::GREEN => "GREEN",
+            Chnl::BLUE => "BLUE",
+            chnl => return write!(f, "{}::Chnl::from_bits_truncate({})", CRATE, u8::from(chnl)),
+        };
+        write!(f, "{}::Chnl::{}", CRATE, constant)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn name_ok() {
+        for name in ["FONT", "_FONT", "font_2", "F"] {
+            assert!(check_name(name).is_ok(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn name_err() {
+        for name in ["", "_", "2FONT", "FONT-2", "FONT 2", "ΦONT"] {
+            assert!(check_name(name).is_err(), "{}", name);
+        }
+    }
+
+    #[test]
+    fn chnl_src() {
+        assert_eq!(ChnlSrc(Chnl::ALL).to_string(), "::bmfont_rs::Chnl::ALL");
+        assert_eq!(
+            ChnlSrc(Chnl::from_bits_truncate(12)).to_string(),
+            "::bmfont_rs::Chnl::from_bits_truncate(12)"
+        );
+    }
+}
@@ -0,0 +1,12 @@
+//! Checked value conversion, as used by the font model conversions and edits.
+use crate::prelude::*;
+
+use core::convert::TryFrom;
+
+/// Integer conversion.
+pub fn int<T: TryFrom<i64>>(path: &str, v: i64) -> crate::Result<T> {
+    T::try_from(v).map_err(|_| crate::Error::UnsupportedValueEncoding {
+        path: path.to_owned(),
+        value: v.to_string(),
+    })
+}
@@ -0,0 +1,423 @@
+use crate::charset::Charset;
+use crate::font::*;
+use crate::prelude::*;
+
+use alloc::collections::BTreeMap;
+use core::fmt;
+
+/// Structural font difference.
+///
+/// Describes the changes required to transform one [Font] into another, as generated by
+/// [Font::diff].
+///
+/// Characters are matched by id and kerning pairs by their first/ second character ids,
+/// independent of their order. Pages are matched by id. All lists are ordered by id.
+///
+/// The [Display](fmt::Display) implementation renders a human readable, line oriented,
+/// description of the changes.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FontDiff {
+    /// Changed info fields.
+    pub info: Vec<FieldChange>,
+    /// Changed common fields.
+    pub common: Vec<FieldChange>,
+    /// Page changes.
+    pub pages: Vec<PageChange>,
+    /// Added characters.
+    pub added_chars: Vec<Char>,
+    /// Removed characters.
+    pub removed_chars: Vec<Char>,
+    /// Modified characters.
+    pub modified_chars: Vec<CharChange>,
+    /// Added kerning pairs.
+    pub added_kernings: Vec<Kerning>,
+    /// Removed kerning pairs.
+    pub removed_kernings: Vec<Kerning>,
+    /// Modified kerning pairs.
+    pub modified_kernings: Vec<KerningChange>,
+}
+
+impl FontDiff {
+    /// Returns true if there are no changes.
+    pub fn is_empty(&self) -> bool {
+        self.info.is_empty()
+            && self.common.is_empty()
+            && self.pages.is_empty()
+            && self.added_chars.is_empty()
+            && self.removed_chars.is_empty()
+            && self.modified_chars.is_empty()
+            && self.added_kernings.is_empty()
+            && self.removed_kernings.is_empty()
+            && self.modified_kernings.is_empty()
+    }
+}
+
+impl fmt::Display for FontDiff {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for change in &self.info {
+            writeln!(f, "~ info {}", change)?;
+        }
+        for change in &self.common {
+            writeln!(f, "~ common {}", change)?;
+        }
+        for change in &self.pages {
+            writeln!(f, "{}", change)?;
+        }
+        for char in &self.removed_chars {
+            writeln!(f, "- char id={}", char.id)?;
+        }
+        for char in &self.added_chars {
+            writeln!(f, "+ char id={}", char.id)?;
+        }
+        for change in &self.modified_chars {
+            writeln!(f, "{}", change)?;
+        }
+        for kerning in &self.removed_kernings {
+            writeln!(
+                f,
+                "- kerning first={} second={} amount={}",
+                kerning.first, kerning.second, kerning.amount
+            )?;
+        }
+        for kerning in &self.added_kernings {
+            writeln!(
+                f,
+                "+ kerning first={} second={} amount={}",
+                kerning.first, kerning.second, kerning.amount
+            )?;
+        }
+        for change in &self.modified_kernings {
+            writeln!(f, "{}", change)?;
+        }
+        Ok(())
+    }
+}
+
+/// Field change.
+///
+/// Field values are rendered as strings in their BMFont text format representation.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FieldChange {
+    /// The field name, as used by the BMFont text format.
+    pub field: &'static str,
+    /// The old value.
+    pub old: String,
+    /// The new value.
+    pub new: String,
+}
+
+impl FieldChange {
+    /// Construct a new FieldChange.
+    #[inline(always)]
+    pub fn new(field: &'static str, old: String, new: String) -> Self {
+        Self { field, old, new }
+    }
+}
+
+impl fmt::Display for FieldChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {} -> {}", self.field, self.old, self.new)
+    }
+}
+
+/// Page change.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PageChange {
+    /// Page added.
+    Added {
+        /// Page id.
+        id: u32,
+        /// Page file.
+        file: String,
+    },
+    /// Page removed.
+    Removed {
+        /// Page id.
+        id: u32,
+        /// Page file.
+        file: String,
+    },
+    /// Page renamed.
+    Renamed {
+        /// Page id.
+        id: u32,
+        /// Old page file.
+        old: String,
+        /// New page file.
+        new: String,
+    },
+}
+
+impl fmt::Display for PageChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            PageChange::Added { id, file } => write!(f, "+ page id={} file={:?}", id, file),
+            PageChange::Removed { id, file } => write!(f, "- page id={} file={:?}", id, file),
+            PageChange::Renamed { id, old, new } => {
+                write!(f, "~ page id={} file: {:?} -> {:?}", id, old, new)
+            }
+        }
+    }
+}
+
+/// Character change.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CharChange {
+    /// Character id.
+    pub id: u32,
+    /// Changed fields.
+    pub fields: Vec<FieldChange>,
+}
+
+impl fmt::Display for CharChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "~ char id={}", self.id)?;
+        for (i, change) in self.fields.iter().enumerate() {
+            write!(f, "{}{}", if i == 0 { ": " } else { ", " }, change)?;
+        }
+        Ok(())
+    }
+}
+
+/// Kerning pair change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KerningChange {
+    /// The first character id.
+    pub first: u32,
+    /// The second character id.
+    pub second: u32,
+    /// The old amount.
+    pub old: i16,
+    /// The new amount.
+    pub new: i16,
+}
+
+impl fmt::Display for KerningChange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "~ kerning first={} second={}: amount: {} -> {}",
+            self.first, self.second, self.old, self.new
+        )
+    }
+}
+
+impl Font {
+    /// Structural difference.
+    ///
+    /// Returns the changes required to transform self into other.
+    /// Characters and kerning pairs are matched by id, independent of their order.
+    /// In the case of duplicate ids, the first entry is used, as with [normalize](Self::normalize).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let old = bmfont_rs::Font::default();
+    /// let mut new = old.clone();
+    /// new.common.line_height = 32;
+    /// let diff = old.diff(&new);
+    /// assert_eq!(diff.to_string(), "~ common lineHeight: 0 -> 32\n");
+    /// ```
+    pub fn diff(&self, other: &Font) -> FontDiff {
+        let mut diff = FontDiff::default();
+        diff_info(&mut diff.info, &self.info, &other.info);
+        diff_common(&mut diff.common, &self.common, &other.common);
+        diff_pages(&mut diff.pages, &self.pages, &other.pages);
+        diff_chars(&mut diff, &self.chars, &other.chars);
+        diff_kernings(&mut diff, &self.kernings, &other.kernings);
+        diff
+    }
+}
+
+macro_rules! diff_fields {
+    ($dst:expr, $old:expr, $new:expr, $(($key:expr, $field:ident)),+) => {
+        $(
+            if $old.$field != $new.$field {
+                $dst.push(FieldChange::new($key, $old.$field.value(), $new.$field.value()));
+            }
+        )+
+    };
+}
+
+pub(crate) fn diff_info(dst: &mut Vec<FieldChange>, old: &Info, new: &Info) {
+    diff_fields!(
+        dst,
+        old,
+        new,
+        ("face", face),
+        ("size", size),
+        ("bold", bold),
+        ("italic", italic),
+        ("charset", charset),
+        ("unicode", unicode),
+        ("stretchH", stretch_h),
+        ("smooth", smooth),
+        ("aa", aa),
+        ("padding", padding),
+        ("spacing", spacing),
+        ("outline", outline)
+    );
+}
+
+pub(crate) fn diff_common(dst: &mut Vec<FieldChange>, old: &Common, new: &Common) {
+    diff_fields!(
+        dst,
+        old,
+        new,
+        ("lineHeight", line_height),
+        ("base", base),
+        ("scaleW", scale_w),
+        ("scaleH", scale_h),
+        ("pages", pages),
+        ("packed", packed),
+        ("alphaChnl", alpha_chnl),
+        ("redChnl", red_chnl),
+        ("greenChnl", green_chnl),
+        ("blueChnl", blue_chnl)
+    );
+}
+
+fn diff_char(dst: &mut Vec<FieldChange>, old: &Char, new: &Char) {
+    diff_fields!(
+        dst,
+        old,
+        new,
+        ("x", x),
+        ("y", y),
+        ("width", width),
+        ("height", height),
+        ("xoffset", xoffset),
+        ("yoffset", yoffset),
+        ("xadvance", xadvance),
+        ("page", page),
+        ("chnl", chnl)
+    );
+}
+
+fn diff_pages(dst: &mut Vec<PageChange>, old: &[String], new: &[String]) {
+    for id in 0..old.len().max(new.len()) {
+        match (old.get(id), new.get(id)) {
+            (Some(old), Some(new)) if old != new => dst.push(PageChange::Renamed {
+                id: id as u32,
+                old: old.to_owned(),
+                new: new.to_owned(),
+            }),
+            (Some(file), None) => {
+                dst.push(PageChange::Removed { id: id as u32, file: file.to_owned() })
+            }
+            (None, Some(file)) => {
+                dst.push(PageChange::Added { id: id as u32, file: file.to_owned() })
+            }
+            _ => {}
+        }
+    }
+}
+
+fn diff_chars(diff: &mut FontDiff, old: &[Char], new: &[Char]) {
+    let old: BTreeMap<u32, &Char> = old.iter().rev().map(|u| (u.id, u)).collect();
+    let new: BTreeMap<u32, &Char> = new.iter().rev().map(|u| (u.id, u)).collect();
+    for (id, old_char) in &old {
+        match new.get(id) {
+            Some(new_char) => {
+                let mut fields = Vec::default();
+                diff_char(&mut fields, old_char, new_char);
+                if !fields.is_empty() {
+                    diff.modified_chars.push(CharChange { id: *id, fields });
+                }
+            }
+            None => diff.removed_chars.push(**old_char),
+        }
+    }
+    for (id, new_char) in &new {
+        if !old.contains_key(id) {
+            diff.added_chars.push(**new_char);
+        }
+    }
+}
+
+fn diff_kernings(diff: &mut FontDiff, old: &[Kerning], new: &[Kerning]) {
+    let old: BTreeMap<(u32, u32), &Kerning> =
+        old.iter().rev().map(|u| ((u.first, u.second), u)).collect();
+    let new: BTreeMap<(u32, u32), &Kerning> =
+        new.iter().rev().map(|u| ((u.first, u.second), u)).collect();
+    for (key, old_kerning) in &old {
+        match new.get(key) {
+            Some(new_kerning) if old_kerning.amount != new_kerning.amount => {
+                diff.modified_kernings.push(KerningChange {
+                    first: key.0,
+                    second: key.1,
+                    old: old_kerning.amount,
+                    new: new_kerning.amount,
+                })
+            }
+            Some(_) => {}
+            None => diff.removed_kernings.push(**old_kerning),
+        }
+    }
+    for (key, new_kerning) in &new {
+        if !old.contains_key(key) {
+            diff.added_kernings.push(**new_kerning);
+        }
+    }
+}
+
+/// Field value rendering, in BMFont text format representation.
+trait Value {
+    fn value(&self) -> String;
+}
+
+macro_rules! implement_value {
+    ($($type:ty),+) => {
+        $(
+            impl Value for $type {
+                fn value(&self) -> String {
+                    self.to_string()
+                }
+            }
+        )+
+    };
+}
+
+implement_value!(u8, u16, i16);
+
+impl Value for bool {
+    fn value(&self) -> String {
+        (*self as u8).to_string()
+    }
+}
+
+impl Value for String {
+    fn value(&self) -> String {
+        format!("{:?}", self)
+    }
+}
+
+impl Value for Charset {
+    fn value(&self) -> String {
+        format!("{:?}", self.to_string())
+    }
+}
+
+impl Value for Padding {
+    fn value(&self) -> String {
+        format!("{},{},{},{}", self.up, self.right, self.down, self.left)
+    }
+}
+
+impl Value for Spacing {
+    fn value(&self) -> String {
+        format!("{},{}", self.horizontal, self.vertical)
+    }
+}
+
+impl Value for Packing {
+    fn value(&self) -> String {
+        (*self as u8).to_string()
+    }
+}
+
+impl Value for Chnl {
+    fn value(&self) -> String {
+        u8::from(*self).to_string()
+    }
+}
@@ -0,0 +1,222 @@
+use crate::convert::int;
+use crate::font::{Char, Font, Kerning};
+use crate::prelude::*;
+
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::convert::TryFrom;
+
+impl Font {
+    /// Subset.
+    ///
+    /// Retain the specified characters, in their original order, and the kerning pairs between
+    /// them. Pages are retained, including those no longer referenced.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeSet;
+    ///
+    /// let mut font = bmfont_rs::Font::default();
+    /// font.chars.push(bmfont_rs::Char { id: 65, ..Default::default() });
+    /// font.chars.push(bmfont_rs::Char { id: 66, ..Default::default() });
+    /// font.kernings.push(bmfont_rs::Kerning::new(65, 66, -1));
+    /// font.subset(&[65].iter().copied().collect::<BTreeSet<_>>());
+    /// assert_eq!(font.chars.len(), 1);
+    /// assert!(font.kernings.is_empty());
+    /// ```
+    pub fn subset(&mut self, ids: &BTreeSet<u32>) {
+        self.chars.retain(|u| ids.contains(&u.id));
+        self.kernings.retain(|u| ids.contains(&u.first) && ids.contains(&u.second));
+    }
+
+    /// Merge.
+    ///
+    /// Append the pages, characters and kerning pairs of other. Pages with identical file names
+    /// are shared, other pages are appended and other's characters renumbered to match.
+    /// Characters and kerning pairs already present take precedence over those of other. The info
+    /// block and the common metrics of self are retained, the common `pages` count is updated.
+    ///
+    /// # Errors
+    ///
+    /// * [Error](crate::Error) if the fonts differ in texture size or packing, a merged character
+    ///   of other references a non-existent page, or the merged page count exceeds 256. The font
+    ///   is left unmodified.
+    pub fn merge(&mut self, other: &Font) -> crate::Result<()> {
+        let (a, b) = (&self.common, &other.common);
+        if (a.scale_w, a.scale_h, a.packed) != (b.scale_w, b.scale_h, b.packed) {
+            return Err(crate::Error::IncongruentFonts {
+                err: format!(
+                    "scale {}x{} packed {}, scale {}x{} packed {}",
+                    a.scale_w, a.scale_h, a.packed, b.scale_w, b.scale_h, b.packed
+                ),
+            });
+        }
+        let mut pages = self.pages.clone();
+        let mut page_ids = Vec::with_capacity(other.pages.len());
+        for page in &other.pages {
+            let id = match pages.iter().position(|u| u == page) {
+                Some(id) => id,
+                None => {
+                    pages.push(page.clone());
+                    pages.len() - 1
+                }
+            };
+            page_ids.push(int::<u8>("char page", id as i64)?);
+        }
+        let mut chars = self.chars.clone();
+        let mut ids: BTreeSet<u32> = chars.iter().map(|u| u.id).collect();
+        for char in &other.chars {
+            if ids.insert(char.id) {
+                let page = page_ids.get(char.page as usize).copied().ok_or(
+                    crate::Error::InvalidCharPage { char_id: char.id, page_id: char.page as u32 },
+                )?;
+                chars.push(Char { page, ..*char });
+            }
+        }
+        let mut kernings = self.kernings.clone();
+        let mut pairs: BTreeSet<(u32, u32)> =
+            kernings.iter().map(|u| (u.first, u.second)).collect();
+        for kerning in &other.kernings {
+            if pairs.insert((kerning.first, kerning.second)) {
+                kernings.push(*kerning);
+            }
+        }
+        self.common.pages = int("common pages", pages.len() as i64)?;
+        self.pages = pages;
+        self.chars = chars;
+        self.kernings = kernings;
+        Ok(())
+    }
+
+    /// Scale.
+    ///
+    /// Scale the font metrics by the specified factor, rounding to the nearest integer: the info
+    /// size, padding, spacing and outline, the common line height, base and texture size,
+    /// character rectangles, offsets and advances, and kerning amounts. Character rectangle edges
+    /// are scaled, so that adjacent rectangles remain adjacent.
+    ///
+    /// The page textures are not modified and should be resampled by the same factor.
+    ///
+    /// # Errors
+    ///
+    /// * [Error](crate::Error) if the factor is not a positive finite number or a scaled value is
+    ///   out of range. The font is left unmodified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// fn main() -> bmfont_rs::Result<()> {
+    ///     let mut font = bmfont_rs::Font::default();
+    ///     font.common.line_height = 32;
+    ///     font.scale(0.5)?;
+    ///     assert_eq!(font.common.line_height, 16);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn scale(&mut self, factor: f64) -> crate::Result<()> {
+        if !(factor.is_finite() && factor > 0.0) {
+            return Err(crate::Error::UnsupportedValueEncoding {
+                path: "scale factor".to_owned(),
+                value: factor.to_string(),
+            });
+        }
+        let mut font = self.clone();
+        let info = &mut font.info;
+        info.size = scaled("info size", info.size as i64, factor)?;
+        info.padding.up = scaled("info padding", info.padding.up as i64, factor)?;
+        info.padding.right = scaled("info padding", info.padding.right as i64, factor)?;
+        info.padding.down = scaled("info padding", info.padding.down as i64, factor)?;
+        info.padding.left = scaled("info padding", info.padding.left as i64, factor)?;
+        info.spacing.horizontal = scaled("info spacing", info.spacing.horizontal as i64, factor)?;
+        info.spacing.vertical = scaled("info spacing", info.spacing.vertical as i64, factor)?;
+        info.outline = scaled("info outline", info.outline as i64, factor)?;
+        let common = &mut font.common;
+        common.line_height = scaled("common lineHeight", common.line_height as i64, factor)?;
+        common.base = scaled("common base", common.base as i64, factor)?;
+        common.scale_w = scaled("common scaleW", common.scale_w as i64, factor)?;
+        common.scale_h = scaled("common scaleH", common.scale_h as i64, factor)?;
+        for char in &mut font.chars {
+            let path = format!("char {}", char.id);
+            let (x, y) = (char.x as i64, char.y as i64);
+            char.x = scaled(&path, x, factor)?;
+            char.y = scaled(&path, y, factor)?;
+            char.width =
+                int(&path, scaled::<i64>(&path, x + char.width as i64, factor)? - char.x as i64)?;
+            char.height =
+                int(&path, scaled::<i64>(&path, y + char.height as i64, factor)? - char.y as i64)?;
+            char.xoffset = scaled(&path, char.xoffset as i64, factor)?;
+            char.yoffset = scaled(&path, char.yoffset as i64, factor)?;
+            char.xadvance = scaled(&path, char.xadvance as i64, factor)?;
+        }
+        for kerning in &mut font.kernings {
+            let path = format!("kerning {} {}", kerning.first, kerning.second);
+            kerning.amount = scaled(&path, kerning.amount as i64, factor)?;
+        }
+        *self = font;
+        Ok(())
+    }
+
+    /// Remap character ids.
+    ///
+    /// Replace character ids, in both characters and kerning pairs, in accordance with the
+    /// specified map. Ids not present in the map are unchanged.
+    ///
+    /// # Errors
+    ///
+    /// * [Error::DuplicateChar](crate::Error::DuplicateChar) or
+    ///   [Error::DuplicateKerningPair](crate::Error::DuplicateKerningPair) if remapping results in
+    ///   duplicate characters or kerning pairs. The font is left unmodified.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use std::collections::BTreeMap;
+    ///
+    /// fn main() -> bmfont_rs::Result<()> {
+    ///     let mut font = bmfont_rs::Font::default();
+    ///     font.chars.push(bmfont_rs::Char { id: 65, ..Default::default() });
+    ///     font.remap(&[(65, 97)].iter().copied().collect::<BTreeMap<_, _>>())?;
+    ///     assert_eq!(font.chars[0].id, 97);
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn remap(&mut self, map: &BTreeMap<u32, u32>) -> crate::Result<()> {
+        let id = |id: u32| map.get(&id).copied().unwrap_or(id);
+        let chars: Vec<Char> = self.chars.iter().map(|u| Char { id: id(u.id), ..*u }).collect();
+        let kernings: Vec<Kerning> = self
+            .kernings
+            .iter()
+            .map(|u| Kerning::new(id(u.first), id(u.second), u.amount))
+            .collect();
+        let mut ids = BTreeSet::default();
+        if let Some(char) = chars.iter().find(|u| !ids.insert(u.id)) {
+            return Err(crate::Error::DuplicateChar { line: None, id: char.id });
+        }
+        let mut pairs = BTreeSet::default();
+        if let Some(kerning) = kernings.iter().find(|u| !pairs.insert((u.first, u.second))) {
+            return Err(crate::Error::DuplicateKerningPair {
+                line: None,
+                first: kerning.first,
+                second: kerning.second,
+            });
+        }
+        self.chars = chars;
+        self.kernings = kernings;
+        Ok(())
+    }
+}
+
+/// Scaled integer conversion, rounding to the nearest integer, half way cases away from zero.
+fn scaled<T: TryFrom<i64>>(path: &str, v: i64, factor: f64) -> crate::Result<T> {
+    let f = v as f64 * factor;
+    let t = f as i64;
+    let frac = f - t as f64;
+    let t = if frac >= 0.5 {
+        t.saturating_add(1)
+    } else if frac <= -0.5 {
+        t.saturating_sub(1)
+    } else {
+        t
+    };
+    int(path, t)
+}
@@ -0,0 +1,192 @@
+use crate::prelude::*;
+use alloc::borrow::Cow;
+
+/// Text encoding.
+///
+/// The encoding of a text format font, as detected by
+/// [text::detect_encoding](crate::text::detect_encoding) or reported by
+/// [LoadReport](crate::LoadReport).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextEncoding {
+    /// UTF-8, or ASCII.
+    Utf8,
+    /// UTF-8 with a byte order mark.
+    Utf8Bom,
+    /// UTF-16 little endian, with or without a byte order mark.
+    Utf16Le,
+    /// UTF-16 big endian, with or without a byte order mark.
+    Utf16Be,
+    /// Windows-1252.
+    Windows1252,
+    /// ISO-8859-1.
+    Latin1,
+}
+
+impl Default for TextEncoding {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::Utf8
+    }
+}
+
+impl From<LegacyEncoding> for TextEncoding {
+    fn from(encoding: LegacyEncoding) -> Self {
+        match encoding {
+            LegacyEncoding::Windows1252 => Self::Windows1252,
+            LegacyEncoding::Latin1 => Self::Latin1,
+        }
+    }
+}
+
+/// Legacy single byte encoding.
+///
+/// Used by [LoadSettings::fallback_encoding](crate::LoadSettings::fallback_encoding) to decode
+/// string values that are not valid UTF-8, e.g. face names written by BMFont in the Windows
+/// system code page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LegacyEncoding {
+    /// Windows-1252.
+    Windows1252,
+    /// ISO-8859-1.
+    Latin1,
+}
+
+impl LegacyEncoding {
+    /// Decode the specified bytes.
+    pub(crate) fn decode(self, bytes: &[u8]) -> String {
+        match self {
+            LegacyEncoding::Windows1252 => bytes.iter().map(|&u| windows_1252(u)).collect(),
+            LegacyEncoding::Latin1 => bytes.iter().map(|&u| u as char).collect(),
+        }
+    }
+}
+
+/// Windows-1252 code points for bytes 0x80..=0x9F. Undefined bytes map to their C1 control
+/// code, as per the WHATWG encoding standard.
+const WINDOWS_1252: [char; 32] = [
+    '\u{20AC}', '\u{0081}', '\u{201A}', '\u{0192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{02C6}', '\u{2030}', '\u{0160}', '\u{2039}', '\u{0152}', '\u{008D}', '\u{017D}', '\u{008F}',
+    '\u{0090}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{02DC}', '\u{2122}', '\u{0161}', '\u{203A}', '\u{0153}', '\u{009D}', '\u{017E}', '\u{0178}',
+];
+
+#[inline(always)]
+fn windows_1252(u: u8) -> char {
+    match u {
+        0x80..=0x9F => WINDOWS_1252[(u - 0x80) as usize],
+        u => u as char,
+    }
+}
+
+const BOM_UTF8: &[u8] = b"\xEF\xBB\xBF";
+const BOM_UTF16_LE: &[u8] = b"\xFF\xFE";
+const BOM_UTF16_BE: &[u8] = b"\xFE\xFF";
+
+/// Detect the encoding from the byte order mark, or in its absence, the NUL byte pattern of the
+/// leading ASCII character.
+pub fn detect(bytes: &[u8]) -> TextEncoding {
+    if bytes.starts_with(BOM_UTF8) {
+        TextEncoding::Utf8Bom
+    } else if bytes.starts_with(BOM_UTF16_LE) {
+        TextEncoding::Utf16Le
+    } else if bytes.starts_with(BOM_UTF16_BE) {
+        TextEncoding::Utf16Be
+    } else if bytes.len() >= 2 && bytes[0] != 0 && bytes[1] == 0 {
+        TextEncoding::Utf16Le
+    } else if bytes.len() >= 2 && bytes[0] == 0 && bytes[1] != 0 {
+        TextEncoding::Utf16Be
+    } else {
+        TextEncoding::Utf8
+    }
+}
+
+/// Transcode the specified bytes, of the specified detected encoding, to UTF-8 sans byte order
+/// mark.
+pub fn transcode(bytes: &[u8], encoding: TextEncoding) -> crate::Result<Cow<'_, [u8]>> {
+    match encoding {
+        TextEncoding::Utf8Bom => Ok(Cow::Borrowed(&bytes[BOM_UTF8.len()..])),
+        TextEncoding::Utf16Le => utf16(bytes, BOM_UTF16_LE, u16::from_le_bytes).map(Cow::Owned),
+        TextEncoding::Utf16Be => utf16(bytes, BOM_UTF16_BE, u16::from_be_bytes).map(Cow::Owned),
+        _ => Ok(Cow::Borrowed(bytes)),
+    }
+}
+
+fn utf16(bytes: &[u8], bom: &[u8], from_bytes: fn([u8; 2]) -> u16) -> crate::Result<Vec<u8>> {
+    let bytes = bytes.strip_prefix(bom).unwrap_or(bytes);
+    if bytes.len() % 2 != 0 {
+        return Err(utf16_error("odd byte length".to_owned()));
+    }
+    let units = bytes.chunks_exact(2).map(|u| from_bytes([u[0], u[1]]));
+    let mut string = String::with_capacity(bytes.len() / 2);
+    for c in char::decode_utf16(units) {
+        string.push(c.map_err(|e| utf16_error(e.to_string()))?);
+    }
+    Ok(string.into_bytes())
+}
+
+fn utf16_error(err: String) -> crate::Error {
+    crate::Error::Parse { line: None, entity: "UTF16".to_owned(), err }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16_le(src: &str) -> Vec<u8> {
+        src.encode_utf16().flat_map(u16::to_le_bytes).collect()
+    }
+
+    fn utf16_be(src: &str) -> Vec<u8> {
+        src.encode_utf16().flat_map(u16::to_be_bytes).collect()
+    }
+
+    #[test]
+    fn detect_utf8() {
+        assert_eq!(detect(b"info"), TextEncoding::Utf8);
+        assert_eq!(detect(b""), TextEncoding::Utf8);
+    }
+
+    #[test]
+    fn detect_utf8_bom() {
+        assert_eq!(detect(b"\xEF\xBB\xBFinfo"), TextEncoding::Utf8Bom);
+    }
+
+    #[test]
+    fn detect_utf16() {
+        assert_eq!(detect(&utf16_le("\u{FEFF}info")), TextEncoding::Utf16Le);
+        assert_eq!(detect(&utf16_be("\u{FEFF}info")), TextEncoding::Utf16Be);
+        assert_eq!(detect(&utf16_le("info")), TextEncoding::Utf16Le);
+        assert_eq!(detect(&utf16_be("info")), TextEncoding::Utf16Be);
+    }
+
+    #[test]
+    fn transcode_utf16() -> crate::Result<()> {
+        for src in [utf16_le("\u{FEFF}info ☺"), utf16_le("info ☺")] {
+            assert_eq!(transcode(&src, TextEncoding::Utf16Le)?.as_ref(), "info ☺".as_bytes());
+        }
+        for src in [utf16_be("\u{FEFF}info ☺"), utf16_be("info ☺")] {
+            assert_eq!(transcode(&src, TextEncoding::Utf16Be)?.as_ref(), "info ☺".as_bytes());
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn transcode_utf16_odd() {
+        assert!(transcode(b"i\x00n", TextEncoding::Utf16Le).is_err());
+    }
+
+    #[test]
+    fn transcode_utf16_surrogate() {
+        assert!(transcode(b"\x00\xD8", TextEncoding::Utf16Le).is_err());
+    }
+
+    #[test]
+    fn decode_windows_1252() {
+        assert_eq!(LegacyEncoding::Windows1252.decode(b"Caf\xE9 \x80\x99\x81"), "Café €™\u{81}");
+    }
+
+    #[test]
+    fn decode_latin1() {
+        assert_eq!(LegacyEncoding::Latin1.decode(b"Caf\xE9 \x80"), "Café \u{80}");
+    }
+}
@@ -1,10 +1,12 @@
-use std::fmt;
+use core::fmt;
+#[cfg(feature = "std")]
 use std::io;
 
+use crate::prelude::*;
 use crate::Charset;
 
 /// Error Result.
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 /// Crate errors.
 ///
@@ -44,6 +46,11 @@ pub enum Error {
         /// Line where the error occurred.
         line: Option<usize>,
     },
+    /// Duplicate atlas frame name (encode only).
+    DuplicateFrameName {
+        /// Duplicate frame name.
+        name: String,
+    },
     /// Duplicate info block (decode only).
     DuplicateInfoBlock {
         /// Line where the error occurred.
@@ -84,6 +91,11 @@ pub enum Error {
         /// Duplicate tag.
         tag: String,
     },
+    /// The fonts cannot be combined as they differ in texture size or packing.
+    IncongruentFonts {
+        /// The difference.
+        err: String,
+    },
     /// Page name lengths are not all of the same size.
     IncongruentPageNameLen {
         /// Line where the error occurred.
@@ -141,6 +153,11 @@ pub enum Error {
         /// Invalid key.
         key: String,
     },
+    /// The specified page does not exist.
+    InvalidPage {
+        /// Page id.
+        id: u32,
+    },
     /// The specified page count does not match the number of realized pages
     /// (decode only).
     InvalidPageCount {
@@ -156,6 +173,11 @@ pub enum Error {
         /// Invalid tag.
         tag: String,
     },
+    /// The entity was written out of order (streaming encode only).
+    InvalidWriteOrder {
+        /// Entity.
+        entity: String,
+    },
     /// The common block is missing.
     NoCommonBlock,
     /// The info block is missing.
@@ -169,6 +191,15 @@ pub enum Error {
         /// The parse error.
         err: String,
     },
+    /// The page file path violates the path resolution policy.
+    UnsafePagePath {
+        /// Page id.
+        id: u32,
+        /// Page file.
+        file: String,
+        /// The policy violation.
+        err: String,
+    },
     /// The value string contains potentially unsafe control characters.
     UnsafeValueString {
         /// Path/ location.
@@ -203,6 +234,7 @@ pub enum Error {
         err: String,
     },
     /// Io error.
+    #[cfg(feature = "std")]
     Io {
         /// IO error
         err: io::Error,
@@ -224,6 +256,9 @@ impl fmt::Display for Error {
             Error::DuplicateCommonBlock { line } => {
                 write!(f, "{}duplicate common block", format_line(line))
             }
+            Error::DuplicateFrameName { name } => {
+                write!(f, "duplicate frame name: '{}'", name)
+            }
             Error::DuplicateInfoBlock { line } => {
                 write!(f, "{}duplicate info block", format_line(line))
             }
@@ -242,6 +277,9 @@ impl fmt::Display for Error {
             Error::DuplicateTag { line, tag } => {
                 write!(f, "{}duplicate tag: '{}'", format_line(line), tag)
             }
+            Error::IncongruentFonts { err } => {
+                write!(f, "incongruent fonts: {}", err)
+            }
             Error::IncongruentPageNameLen { line } => {
                 write!(f, "{}incongruent page file length", format_line(line))
             }
@@ -269,12 +307,18 @@ impl fmt::Display for Error {
             Error::InvalidKey { line, key } => {
                 write!(f, "{}invalid key: '{}'", format_line(line), key)
             }
+            Error::InvalidPage { id } => {
+                write!(f, "invalid page id: {}", id)
+            }
             Error::InvalidPageCount { specified, realized } => {
                 write!(f, "invalid page count: specified: {}, realized: {}", specified, realized)
             }
             Error::InvalidTag { line, tag } => {
                 write!(f, "{}invalid tag: '{}'", format_line(line), tag)
             }
+            Error::InvalidWriteOrder { entity } => {
+                write!(f, "invalid write order: '{}'", entity)
+            }
             Error::NoCommonBlock => {
                 write!(f, "no common block")
             }
@@ -284,6 +328,9 @@ impl fmt::Display for Error {
             Error::Parse { line, entity, err } => {
                 write!(f, "{}parse error: {}: {}", format_line(line), entity, err)
             }
+            Error::UnsafePagePath { id, file, err } => {
+                write!(f, "unsafe page path: id: {}, file: '{}': {}", id, file, err)
+            }
             Error::UnsafeValueString { path, value } => {
                 write!(f, "{}: unsafe value string: '{}'", path, value)
             }
@@ -299,6 +346,7 @@ impl fmt::Display for Error {
             Error::Internal { err } => {
                 write!(f, "internal error: {}", err)
             }
+            #[cfg(feature = "std")]
             Error::Io { err } => {
                 write!(f, "io: {}", err)
             }
@@ -306,14 +354,17 @@ impl fmt::Display for Error {
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
+#[cfg(feature = "std")]
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Self {
         Self::Io { err }
     }
 }
 
+#[cfg(feature = "std")]
 impl From<Error> for io::Error {
     fn from(err: Error) -> Self {
         match err {
@@ -0,0 +1,32 @@
+use crate::font::{Char, Common, Info, Kerning};
+use crate::prelude::*;
+
+/// Font parse event.
+///
+/// Typed font descriptor entries, as yielded in source order by the pull parsers:
+/// [text::Parser](crate::text::Parser) and [binary::Parser](crate::binary::Parser).
+///
+/// The list of variants may change over time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Event {
+    /// Info block.
+    Info(Info),
+    /// Common block.
+    Common(Common),
+    /// Page.
+    Page {
+        /// Page id.
+        id: u16,
+        /// Page file name.
+        file: String,
+    },
+    /// Declared character count.
+    CharCount(u32),
+    /// Character.
+    Char(Char),
+    /// Declared kerning pair count.
+    KerningCount(u32),
+    /// Kerning pair.
+    Kerning(Kerning),
+}
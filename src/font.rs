@@ -1,10 +1,12 @@
-use std::collections::HashSet;
-use std::convert::{TryFrom, TryInto};
+use alloc::collections::BTreeSet;
+use core::convert::{TryFrom, TryInto};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-use crate::parse::{Parse, ParseError, ParseResult};
+use crate::parse::{Adjustment, Parse, ParseError, ParseResult};
+use crate::prelude::*;
+use crate::settings::LoadSettings;
 
 use super::charset::Charset;
 
@@ -61,37 +63,102 @@ impl Font {
     /// Validate references. Ensure that all page/ character references exist. In other words, that
     /// we don't have references to a non-existent page/ character.
     pub fn validate_references(&self) -> crate::Result<()> {
-        self.validate_char_references()?;
-        self.validate_kerning_references()?;
-        Ok(())
+        let mut result = Ok(());
+        self.visit_references(|err| {
+            result = Err(err);
+            false
+        });
+        result
+    }
+
+    /// Validation errors.
+    ///
+    /// As [validate_references](Self::validate_references), collecting all errors rather than
+    /// returning the first. Additionally checks the common `pages` count against the page list.
+    ///
+    /// Returns, in order:
+    /// * [InvalidPageCount](crate::Error::InvalidPageCount), if the page count does not match.
+    /// * [InvalidCharPage](crate::Error::InvalidCharPage), for each character referencing a
+    ///   non-existent page.
+    /// * [InvalidKerningChar](crate::Error::InvalidKerningChar), for each kerning pair character
+    ///   that does not exist.
+    pub fn validation_errors(&self) -> Vec<crate::Error> {
+        let mut vec = Vec::default();
+        if self.common.pages as usize != self.pages.len() {
+            vec.push(crate::Error::InvalidPageCount {
+                specified: self.common.pages,
+                realized: self.pages.len(),
+            });
+        }
+        self.visit_references(|err| {
+            vec.push(err);
+            true
+        });
+        vec
     }
 
-    fn validate_char_references(&self) -> crate::Result<()> {
+    /// Normalize into canonical form.
+    ///
+    /// Sort characters by id and kerning pairs by first/ second character ids. Duplicate
+    /// characters and kerning pairs are removed, the first occurrence is retained.
+    ///
+    /// Fonts that are semantically equal, see [semantically_eq](Self::semantically_eq), are
+    /// equal once normalized and store to identical output.
+    pub fn normalize(&mut self) {
+        self.chars.sort_by_key(|u| u.id);
+        self.chars.dedup_by_key(|u| u.id);
+        self.kernings.sort_by_key(|u| (u.first, u.second));
+        self.kernings.dedup_by_key(|u| (u.first, u.second));
+    }
+
+    /// Semantic equality.
+    ///
+    /// Returns true if both fonts are equal when normalized, that is ignoring character and
+    /// kerning pair order. See [normalize](Self::normalize).
+    pub fn semantically_eq(&self, other: &Font) -> bool {
+        self.info == other.info
+            && self.common == other.common
+            && self.pages == other.pages
+            && canonical_chars(&self.chars) == canonical_chars(&other.chars)
+            && canonical_kernings(&self.kernings) == canonical_kernings(&other.kernings)
+    }
+
+    /// Visit reference errors, stopping once the visitor returns false.
+    fn visit_references<F: FnMut(crate::Error) -> bool>(&self, mut f: F) {
         for char in &self.chars {
             if self.pages.len() <= char.page as usize {
-                return Err(crate::Error::InvalidCharPage {
-                    char_id: char.id,
-                    page_id: char.page as u32,
-                });
+                let err =
+                    crate::Error::InvalidCharPage { char_id: char.id, page_id: char.page as u32 };
+                if !f(err) {
+                    return;
+                }
             }
         }
-        Ok(())
-    }
-
-    fn validate_kerning_references(&self) -> crate::Result<()> {
-        let set: HashSet<u32> = self.chars.iter().map(|u| u.id).collect();
+        let set: BTreeSet<u32> = self.chars.iter().map(|u| u.id).collect();
         for kerning in &self.kernings {
-            if !set.contains(&kerning.first) {
-                return Err(crate::Error::InvalidKerningChar { id: kerning.first });
-            }
-            if !set.contains(&kerning.second) {
-                return Err(crate::Error::InvalidKerningChar { id: kerning.second });
+            for id in [kerning.first, kerning.second] {
+                if !set.contains(&id) && !f(crate::Error::InvalidKerningChar { id }) {
+                    return;
+                }
             }
         }
-        Ok(())
     }
 }
 
+fn canonical_chars(chars: &[Char]) -> Vec<&Char> {
+    let mut vec: Vec<&Char> = chars.iter().collect();
+    vec.sort_by_key(|u| u.id);
+    vec.dedup_by_key(|u| u.id);
+    vec
+}
+
+fn canonical_kernings(kernings: &[Kerning]) -> Vec<&Kerning> {
+    let mut vec: Vec<&Kerning> = kernings.iter().collect();
+    vec.sort_by_key(|u| (u.first, u.second));
+    vec.dedup_by_key(|u| (u.first, u.second));
+    vec
+}
+
 /// Character description.
 ///
 /// This block describes a character in the font.
@@ -129,7 +196,7 @@ impl Char {
     /// N.B. The supplied arguments are not validated.
     #[allow(clippy::too_many_arguments)]
     #[inline(always)]
-    pub fn new(
+    pub const fn new(
         id: u32,
         x: u16,
         y: u16,
@@ -185,7 +252,7 @@ impl Common {
     /// N.B. The supplied arguments are not validated.
     #[allow(clippy::too_many_arguments)]
     #[inline(always)]
-    pub fn new(
+    pub const fn new(
         line_height: u16,
         base: u16,
         scale_w: u16,
@@ -369,7 +436,7 @@ impl Padding {
     ///
     /// N.B. The supplied arguments are not validated.
     #[inline(always)]
-    pub fn new(up: u8, right: u8, down: u8, left: u8) -> Self {
+    pub const fn new(up: u8, right: u8, down: u8, left: u8) -> Self {
         Self { up, right, down, left }
     }
 }
@@ -382,6 +449,14 @@ impl Parse for Padding {
     fn parse_bytes(bytes: &[u8]) -> ParseResult<Self> {
         <[u8; 4]>::parse_bytes(bytes).map(Into::into)
     }
+
+    fn parse_ext(
+        src: &str,
+        settings: &LoadSettings,
+        adjustments: &mut Vec<Adjustment>,
+    ) -> ParseResult<Self> {
+        <[u8; 4]>::parse_ext(src, settings, adjustments).map(Into::into)
+    }
 }
 
 impl From<[u8; 4]> for Padding {
@@ -419,7 +494,7 @@ impl Spacing {
     ///
     /// N.B. The supplied arguments are not validated.
     #[inline(always)]
-    pub fn new(horizontal: u8, vertical: u8) -> Self {
+    pub const fn new(horizontal: u8, vertical: u8) -> Self {
         Self { horizontal, vertical }
     }
 }
@@ -432,6 +507,14 @@ impl Parse for Spacing {
     fn parse_bytes(bytes: &[u8]) -> ParseResult<Self> {
         <[u8; 2]>::parse_bytes(bytes).map(Into::into)
     }
+
+    fn parse_ext(
+        src: &str,
+        settings: &LoadSettings,
+        adjustments: &mut Vec<Adjustment>,
+    ) -> ParseResult<Self> {
+        <[u8; 2]>::parse_ext(src, settings, adjustments).map(Into::into)
+    }
 }
 
 impl From<[u8; 2]> for Spacing {
@@ -469,7 +552,7 @@ impl Kerning {
     ///
     /// N.B. The supplied arguments are not validated.
     #[inline(always)]
-    pub fn new(first: u32, second: u32, amount: i16) -> Self {
+    pub const fn new(first: u32, second: u32, amount: i16) -> Self {
         Self { first, second, amount }
     }
 }
@@ -603,6 +686,13 @@ impl Chnl {
     /// Character image data is stored in the blue channel.    
     pub const BLUE: Chnl = Chnl(1);
 
+    /// Construct a new Chnl from the specified bits, bits outside of the four channel bits are
+    /// discarded.
+    #[inline(always)]
+    pub const fn from_bits_truncate(bits: u8) -> Self {
+        Self(bits & 0x0F)
+    }
+
     /// The alpha channel bit.
     #[inline(always)]
     pub fn alpha(self) -> bool {
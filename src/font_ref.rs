@@ -0,0 +1,296 @@
+use crate::binary::packed;
+use crate::charset::Charset;
+use crate::font::{Char, Common, Font, Info, Kerning, Padding, Spacing};
+use crate::prelude::*;
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+/// Borrowed bitmap font descriptor.
+///
+/// A zero-copy view of a font descriptor, as loaded by
+/// [binary::from_bytes_ref](crate::binary::from_bytes_ref) or
+/// [text::from_bytes_ref](crate::text::from_bytes_ref). The face and page strings borrow from the
+/// source. When loaded from binary data, the characters and kerning pairs are lazily decoded
+/// views over the source block bytes. When loaded from text data, the characters and kerning pairs
+/// are decoded and allocated on load.
+///
+/// Load settings are validated as per the owned load methods, with the exception of the duplicate
+/// character and kerning pair policies. Duplicates are exposed as is, in source order. Lazily
+/// decoded characters and kerning pairs are validated on access.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let buf = fs::read("font.bin")?;
+///     let font = bmfont_rs::binary::from_bytes_ref(&buf)?;
+///     println!("{}: {} chars", font.info.face, font.chars.len());
+///     for char in font.chars.iter() {
+///         let char = char?;
+///         if char.id == 'A' as u32 {
+///             println!("{:?}", char);
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FontRef<'a> {
+    /// Info block.
+    pub info: InfoRef<'a>,
+    /// Common block.
+    pub common: Common,
+    /// Page file names, the index corresponds to the page id.
+    pub pages: Vec<&'a str>,
+    /// Characters.
+    pub chars: CharsRef<'a>,
+    /// Kerning pairs.
+    pub kernings: KerningsRef<'a>,
+}
+
+impl<'a> FontRef<'a> {
+    /// Convert to an owned [Font].
+    ///
+    /// # Errors
+    ///
+    /// * [Error](crate::Error) if a character or kerning pair cannot be decoded.
+    pub fn to_owned(&self) -> crate::Result<Font> {
+        Ok(Font::new(
+            self.info.to_owned(),
+            self.common,
+            self.pages.iter().map(|&u| u.to_owned()).collect(),
+            self.chars.iter().collect::<crate::Result<_>>()?,
+            self.kernings.iter().collect::<crate::Result<_>>()?,
+        ))
+    }
+}
+
+/// Borrowed font information.
+///
+/// As [Info], with the face borrowed from the source.
+#[allow(missing_docs)]
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InfoRef<'a> {
+    pub face: &'a str,
+    pub size: i16,
+    pub bold: bool,
+    pub italic: bool,
+    pub charset: Charset,
+    pub unicode: bool,
+    pub stretch_h: u16,
+    pub smooth: bool,
+    pub aa: u8,
+    pub padding: Padding,
+    pub spacing: Spacing,
+    pub outline: u8,
+}
+
+impl<'a> InfoRef<'a> {
+    /// Construct a new InfoRef from the specified [Info] fields and borrowed face.
+    pub(crate) fn from_info(face: &'a str, info: Info) -> Self {
+        let Info {
+            face: _,
+            size,
+            bold,
+            italic,
+            charset,
+            unicode,
+            stretch_h,
+            smooth,
+            aa,
+            padding,
+            spacing,
+            outline,
+        } = info;
+        Self {
+            face,
+            size,
+            bold,
+            italic,
+            charset,
+            unicode,
+            stretch_h,
+            smooth,
+            aa,
+            padding,
+            spacing,
+            outline,
+        }
+    }
+
+    /// Convert to an owned [Info].
+    pub fn to_owned(&self) -> Info {
+        Info {
+            face: self.face.to_owned(),
+            size: self.size,
+            bold: self.bold,
+            italic: self.italic,
+            charset: self.charset.clone(),
+            unicode: self.unicode,
+            stretch_h: self.stretch_h,
+            smooth: self.smooth,
+            aa: self.aa,
+            padding: self.padding,
+            spacing: self.spacing,
+            outline: self.outline,
+        }
+    }
+}
+
+macro_rules! implement_entries_ref {
+    ($name:ident, $iter:ident, $type:ty, $len:expr, $unpack:expr, $doc:expr) => {
+        #[doc = concat!("Borrowed ", $doc, ".")]
+        ///
+        /// Either a lazily decoded view over binary block bytes, or a list of decoded entries.
+        /// Entries are decoded and validated on access, the view does not cache.
+        #[derive(Clone)]
+        pub struct $name<'a> {
+            repr: Repr<'a, $type>,
+        }
+
+        impl<'a> $name<'a> {
+            /// Construct from packed block bytes, the length a multiple of the entry length.
+            pub(crate) fn packed(bytes: &'a [u8]) -> Self {
+                debug_assert!(bytes.len() % $len == 0);
+                Self { repr: Repr::Packed(bytes) }
+            }
+
+            /// Construct from decoded entries.
+            pub(crate) fn decoded(vec: Vec<$type>) -> Self {
+                Self { repr: Repr::Decoded(vec) }
+            }
+
+            /// Returns the number of entries.
+            pub fn len(&self) -> usize {
+                match &self.repr {
+                    Repr::Packed(bytes) => bytes.len() / $len,
+                    Repr::Decoded(vec) => vec.len(),
+                }
+            }
+
+            /// Returns true if there are no entries.
+            pub fn is_empty(&self) -> bool {
+                self.len() == 0
+            }
+
+            /// Returns the entry at the specified index, or None if out of bounds.
+            ///
+            /// # Errors
+            ///
+            /// * [Error](crate::Error) if the entry cannot be decoded.
+            pub fn get(&self, index: usize) -> Option<crate::Result<$type>> {
+                match &self.repr {
+                    Repr::Packed(bytes) => {
+                        let chunk = bytes.get(index * $len..(index + 1) * $len)?;
+                        Some($unpack(chunk))
+                    }
+                    Repr::Decoded(vec) => vec.get(index).copied().map(Ok),
+                }
+            }
+
+            /// Returns an iterator over the entries.
+            pub fn iter(&self) -> $iter<'_, 'a> {
+                $iter { entries: self, index: 0, end: self.len() }
+            }
+        }
+
+        impl Default for $name<'_> {
+            fn default() -> Self {
+                Self::decoded(Vec::default())
+            }
+        }
+
+        impl fmt::Debug for $name<'_> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.debug_list().entries(self.iter()).finish()
+            }
+        }
+
+        impl PartialEq for $name<'_> {
+            fn eq(&self, other: &Self) -> bool {
+                // Entries that cannot be decoded compare equal.
+                self.len() == other.len()
+                    && self.iter().map(Result::ok).eq(other.iter().map(Result::ok))
+            }
+        }
+
+        impl Eq for $name<'_> {}
+
+        impl<'b, 'a> IntoIterator for &'b $name<'a> {
+            type Item = crate::Result<$type>;
+            type IntoIter = $iter<'b, 'a>;
+
+            fn into_iter(self) -> Self::IntoIter {
+                self.iter()
+            }
+        }
+
+        #[doc = concat!("Iterator over borrowed ", $doc, ".")]
+        #[derive(Clone, Debug)]
+        pub struct $iter<'b, 'a> {
+            entries: &'b $name<'a>,
+            index: usize,
+            end: usize,
+        }
+
+        impl Iterator for $iter<'_, '_> {
+            type Item = crate::Result<$type>;
+
+            fn next(&mut self) -> Option<Self::Item> {
+                if self.index < self.end {
+                    self.index += 1;
+                    self.entries.get(self.index - 1)
+                } else {
+                    None
+                }
+            }
+
+            fn size_hint(&self) -> (usize, Option<usize>) {
+                let len = self.end - self.index;
+                (len, Some(len))
+            }
+        }
+
+        impl DoubleEndedIterator for $iter<'_, '_> {
+            fn next_back(&mut self) -> Option<Self::Item> {
+                if self.index < self.end {
+                    self.end -= 1;
+                    self.entries.get(self.end)
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl ExactSizeIterator for $iter<'_, '_> {}
+
+        impl FusedIterator for $iter<'_, '_> {}
+    };
+}
+
+#[derive(Clone)]
+enum Repr<'a, T> {
+    Packed(&'a [u8]),
+    Decoded(Vec<T>),
+}
+
+implement_entries_ref!(
+    CharsRef,
+    CharsIter,
+    Char,
+    packed::CHAR_LEN,
+    packed::unpack_char,
+    "characters"
+);
+
+implement_entries_ref!(
+    KerningsRef,
+    KerningsIter,
+    Kerning,
+    packed::KERNING_LEN,
+    packed::unpack_kerning,
+    "kerning pairs"
+);
@@ -0,0 +1,412 @@
+use crate::builder::FontProto;
+use crate::charset::Charset;
+use crate::font::{Char, Chnl, Common, Font, Info, Kerning};
+use crate::parse::{ParseError, ParseResult};
+use crate::prelude::*;
+use crate::LoadSettings;
+
+use alloc::collections::BTreeMap;
+use core::convert::TryFrom;
+
+#[cfg(feature = "std")]
+use std::io;
+
+use super::RES;
+
+/// Load Godot format font.
+///
+/// Load a font from the specified Godot 4 `FontFile` text resource (`.tres`) [str].
+///
+/// Only the first cache entry is imported, at the `fixed_size` font size if specified. Page
+/// files are resolved from the external resources referenced by the cache textures, with any
+/// `res://` prefix removed. The remaining info and common fields, which have no Godot
+/// equivalent, take their default values. Characters are assigned to all channels.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let src = fs::read_to_string("font.tres")?;
+///     let font = bmfont_rs::godot::from_str(&src)?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+pub fn from_str(src: &str) -> crate::Result<Font> {
+    from_str_ext(src, &Default::default())
+}
+
+/// Load Godot format font with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+pub fn from_str_ext(src: &str, settings: &LoadSettings) -> crate::Result<Font> {
+    let resource = Resource::parse(src)?;
+    let proto: FontProto = resource.build()?.into();
+    proto.build(settings)
+}
+
+/// Load Godot format font.
+///
+/// Load a font from the specified Godot 4 `FontFile` text resource (`.tres`) byte slice.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+pub fn from_bytes(bytes: &[u8]) -> crate::Result<Font> {
+    from_bytes_ext(bytes, &Default::default())
+}
+
+/// Load Godot format font with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+pub fn from_bytes_ext(bytes: &[u8], settings: &LoadSettings) -> crate::Result<Font> {
+    from_str_ext(
+        core::str::from_utf8(bytes).map_err(|e| crate::Error::Parse {
+            line: None,
+            entity: "godot".to_owned(),
+            err: e.to_string(),
+        })?,
+        settings,
+    )
+}
+
+/// Read Godot format font.
+///
+/// Read a font from the specified Godot 4 `FontFile` text resource (`.tres`) reader.
+/// This method buffers data internally, a buffered reader is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+#[cfg(feature = "std")]
+pub fn from_reader<R: io::Read>(reader: R) -> crate::Result<Font> {
+    from_reader_ext(reader, &Default::default())
+}
+
+/// Read Godot format font with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+#[cfg(feature = "std")]
+pub fn from_reader_ext<R: io::Read>(mut reader: R, settings: &LoadSettings) -> crate::Result<Font> {
+    let mut vec = Vec::default();
+    reader.read_to_end(&mut vec)?;
+    from_bytes_ext(&vec, settings)
+}
+
+#[derive(Debug, Default)]
+struct Resource {
+    ext_resources: BTreeMap<String, String>,
+    font_name: String,
+    fixed_size: Option<u16>,
+    caches: BTreeMap<u16, Cache>,
+}
+
+#[derive(Debug, Default)]
+struct Cache {
+    ascent: f64,
+    descent: f64,
+    textures: BTreeMap<u32, String>,
+    glyphs: BTreeMap<u32, Glyph>,
+    kernings: Vec<Kerning>,
+}
+
+#[derive(Debug, Default)]
+struct Glyph {
+    advance: [f64; 2],
+    offset: [f64; 2],
+    uv_rect: [f64; 4],
+    texture_idx: u8,
+}
+
+impl Resource {
+    fn parse(src: &str) -> crate::Result<Self> {
+        let mut resource = Resource::default();
+        let mut in_resource = false;
+        for (i, line) in src.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with(';') {
+                continue;
+            }
+            let err = |entity: &str, e: ParseError| crate::Error::Parse {
+                line: Some(i + 1),
+                entity: entity.to_owned(),
+                err: e.to_string(),
+            };
+            if let Some(section) = line.strip_prefix('[') {
+                let section = section.strip_suffix(']').ok_or_else(|| {
+                    err("section", ParseError::Other(format!("unterminated: '{}'", line)))
+                })?;
+                let (tag, attributes) = section.split_once(' ').unwrap_or((section, ""));
+                let attributes = parse_attributes(attributes).map_err(|e| err(tag, e))?;
+                in_resource = tag == "resource";
+                match tag {
+                    "gd_resource"
+                        if attributes.get("type").map(String::as_str) != Some("FontFile") =>
+                    {
+                        let e = ParseError::Other("expected type FontFile".to_owned());
+                        return Err(err(tag, e));
+                    }
+                    "ext_resource" => {
+                        if let (Some(id), Some(path)) =
+                            (attributes.get("id"), attributes.get("path"))
+                        {
+                            resource.ext_resources.insert(id.clone(), path.clone());
+                        }
+                    }
+                    _ => {}
+                }
+            } else if in_resource {
+                let (key, value) = line.split_once('=').ok_or_else(|| {
+                    err("property", ParseError::Other(format!("expected key = value: '{}'", line)))
+                })?;
+                let key = key.trim();
+                resource.set(key, value.trim()).map_err(|e| err(key, e))?;
+            }
+        }
+        Ok(resource)
+    }
+
+    fn set(&mut self, key: &str, value: &str) -> ParseResult<()> {
+        if key == "font_name" {
+            self.font_name = unquote(value)?;
+            return Ok(());
+        }
+        if key == "fixed_size" {
+            self.fixed_size = Some(int(number(value)?)?);
+            return Ok(());
+        }
+        let mut parts = key.split('/');
+        if parts.next() != Some("cache") || parts.next() != Some("0") {
+            return Ok(());
+        }
+        let size: u16 = match parts.next() {
+            Some(size) => int(number(size)?)?,
+            None => return Ok(()),
+        };
+        let cache = self.caches.entry(size).or_default();
+        let parts: Vec<&str> = parts.collect();
+        match parts.as_slice() {
+            ["kerning_overrides", first, second] => {
+                let [amount, _] = args(value, "Vector2")?;
+                cache.kernings.push(Kerning::new(
+                    int(number(first)?)?,
+                    int(number(second)?)?,
+                    int(amount)?,
+                ));
+            }
+            [_, "ascent"] => cache.ascent = number(value)?,
+            [_, "descent"] => cache.descent = number(value)?,
+            [_, "textures", id, "image"] => {
+                let ext = value
+                    .strip_prefix("ExtResource(")
+                    .and_then(|u| u.strip_suffix(')'))
+                    .ok_or_else(|| ParseError::Other(format!("expected ExtResource: '{}'", value)))?
+                    .trim();
+                let ext = if ext.starts_with('"') { unquote(ext)? } else { ext.to_owned() };
+                cache.textures.insert(int(number(id)?)?, ext);
+            }
+            [_, "glyphs", id, field] => {
+                let glyph = cache.glyphs.entry(int(number(id)?)?).or_default();
+                match *field {
+                    "advance" => glyph.advance = args(value, "Vector2")?,
+                    "offset" => glyph.offset = args(value, "Vector2")?,
+                    "uv_rect" => glyph.uv_rect = args(value, "Rect2")?,
+                    "texture_idx" => glyph.texture_idx = int(number(value)?)?,
+                    _ => {}
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn build(mut self) -> crate::Result<Font> {
+        let size = match self.fixed_size {
+            Some(size) => size,
+            None => self.caches.keys().next().copied().unwrap_or_default(),
+        };
+        let cache = self.caches.remove(&size).unwrap_or_default();
+        let err = |entity: &str, e: ParseError| crate::Error::Parse {
+            line: None,
+            entity: entity.to_owned(),
+            err: e.to_string(),
+        };
+        let ascent: i16 = int(cache.ascent).map_err(|e| err("ascent", e))?;
+        let line_height: u16 = int(cache.ascent + cache.descent).map_err(|e| err("descent", e))?;
+        let mut pages = Vec::with_capacity(cache.textures.len());
+        for (id, ext) in cache.textures {
+            if id as usize != pages.len() {
+                return Err(crate::Error::BrokenPageList);
+            }
+            let path = self.ext_resources.get(&ext).ok_or_else(|| {
+                err("textures", ParseError::Other(format!("undefined ExtResource: '{}'", ext)))
+            })?;
+            pages.push(path.strip_prefix(RES).unwrap_or(path).to_owned());
+        }
+        let mut chars = Vec::with_capacity(cache.glyphs.len());
+        for (id, glyph) in cache.glyphs {
+            let char = glyph.char(id, ascent).map_err(|e| err("glyphs", e))?;
+            chars.push(char);
+        }
+        let info = Info {
+            face: self.font_name,
+            size: int(size as f64).map_err(|e| err("fixed_size", e))?,
+            charset: Charset::Null,
+            unicode: true,
+            ..Default::default()
+        };
+        let common = Common {
+            line_height,
+            base: int(cache.ascent).map_err(|e| err("ascent", e))?,
+            pages: int(pages.len() as f64).map_err(|e| err("textures", e))?,
+            ..Default::default()
+        };
+        Ok(Font::new(info, common, pages, chars, cache.kernings))
+    }
+}
+
+impl Glyph {
+    fn char(&self, id: u32, ascent: i16) -> ParseResult<Char> {
+        let [x, y, width, height] = self.uv_rect;
+        Ok(Char {
+            id,
+            x: int(x)?,
+            y: int(y)?,
+            width: int(width)?,
+            height: int(height)?,
+            xoffset: int(self.offset[0])?,
+            yoffset: int(self.offset[1] + ascent as f64)?,
+            xadvance: int(self.advance[0])?,
+            page: self.texture_idx,
+            chnl: Chnl::ALL,
+        })
+    }
+}
+
+fn number(src: &str) -> ParseResult<f64> {
+    src.trim().parse().map_err(|_| ParseError::Other(format!("number: '{}'", src)))
+}
+
+/// Integral number to integer, without loss.
+fn int<T: TryFrom<i64>>(v: f64) -> ParseResult<T> {
+    let i = v as i64;
+    if i as f64 != v {
+        return Err(ParseError::Other(format!("integer: '{}'", v)));
+    }
+    T::try_from(i).map_err(|_| ParseError::Other(format!("integer out of range: '{}'", v)))
+}
+
+/// Parse a constructor expression, e.g. `Vector2(1, 2)`, into its arguments.
+fn args<const N: usize>(src: &str, constructor: &str) -> ParseResult<[f64; N]> {
+    let err = || ParseError::Other(format!("expected {}: '{}'", constructor, src));
+    let inner = src
+        .strip_prefix(constructor)
+        .map(|u| u.strip_prefix('i').unwrap_or(u))
+        .and_then(|u| u.strip_prefix('('))
+        .and_then(|u| u.strip_suffix(')'))
+        .ok_or_else(err)?;
+    let mut arr = [0.0; N];
+    let mut args = inner.split(',');
+    for v in arr.iter_mut() {
+        *v = number(args.next().ok_or_else(err)?)?;
+    }
+    match args.next() {
+        Some(_) => Err(err()),
+        None => Ok(arr),
+    }
+}
+
+/// Parse section attributes, e.g. `type="Image" path="res://font_0.png" id="1"`.
+fn parse_attributes(src: &str) -> ParseResult<BTreeMap<String, String>> {
+    let mut attributes = BTreeMap::default();
+    let mut rest = src.trim_start();
+    while !rest.is_empty() {
+        let (key, tail) = rest
+            .split_once('=')
+            .ok_or_else(|| ParseError::Other(format!("expected key=value: '{}'", rest)))?;
+        let (value, tail) = if tail.starts_with('"') {
+            let end = quoted_len(tail)?;
+            (unquote(&tail[..end])?, &tail[end..])
+        } else {
+            let end = tail.find(' ').unwrap_or(tail.len());
+            (tail[..end].to_owned(), &tail[end..])
+        };
+        attributes.insert(key.trim().to_owned(), value);
+        rest = tail.trim_start();
+    }
+    Ok(attributes)
+}
+
+/// The byte length of the leading quoted string, including quotes.
+fn quoted_len(src: &str) -> ParseResult<usize> {
+    let mut escaped = false;
+    for (i, c) in src.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return Ok(i + 1),
+            _ => {}
+        }
+    }
+    Err(ParseError::Other(format!("unterminated string: '{}'", src)))
+}
+
+fn unquote(src: &str) -> ParseResult<String> {
+    let inner = src
+        .strip_prefix('"')
+        .and_then(|u| u.strip_suffix('"'))
+        .ok_or_else(|| ParseError::Other(format!("expected string: '{}'", src)))?;
+    let mut string = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c == '\\' {
+            match chars.next() {
+                Some('n') => string.push('\n'),
+                Some('t') => string.push('\t'),
+                Some(c) => string.push(c),
+                None => return Err(ParseError::Other(format!("invalid escape: '{}'", src))),
+            }
+        } else {
+            string.push(c);
+        }
+    }
+    Ok(string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn args_vector2() {
+        assert_eq!(args::<2>("Vector2(1, -2.0)", "Vector2"), Ok([1.0, -2.0]));
+        assert_eq!(args::<2>("Vector2i(3, 4)", "Vector2"), Ok([3.0, 4.0]));
+        assert!(args::<2>("Vector2(1)", "Vector2").is_err());
+        assert!(args::<2>("Vector2(1, 2, 3)", "Vector2").is_err());
+        assert!(args::<4>("Vector2(1, 2)", "Rect2").is_err());
+    }
+
+    #[test]
+    fn int_integral() {
+        assert_eq!(int::<i16>(-20.0), Ok(-20));
+        assert!(int::<i16>(0.5).is_err());
+        assert!(int::<u8>(-1.0).is_err());
+    }
+
+    #[test]
+    fn attributes() {
+        let attributes = parse_attributes(r#"type="Image" path="res://a \"b\".png" id=1"#).unwrap();
+        assert_eq!(attributes["type"], "Image");
+        assert_eq!(attributes["path"], "res://a \"b\".png");
+        assert_eq!(attributes["id"], "1");
+    }
+}
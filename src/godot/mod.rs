@@ -0,0 +1,22 @@
+//! Godot format operations.
+//!
+//! Convert to and from Godot 4 `FontFile` text resources (`.tres`).
+//!
+//! Characters map onto glyphs, kerning pairs onto kerning overrides and pages onto external
+//! `Image` resources. The common `base` maps onto the ascent, with the `lineHeight` remainder
+//! as the descent. Other info and common fields, as well as character channels, have no Godot
+//! equivalent and are not retained.
+
+mod load;
+mod store;
+
+pub use load::{from_bytes, from_bytes_ext, from_str, from_str_ext};
+pub use store::{to_string, to_vec};
+
+#[cfg(feature = "std")]
+pub use load::{from_reader, from_reader_ext};
+#[cfg(feature = "std")]
+pub use store::to_writer;
+
+/// Godot resource path prefix.
+const RES: &str = "res://";
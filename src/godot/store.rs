@@ -0,0 +1,154 @@
+use crate::font::Font;
+use crate::prelude::*;
+
+use core::fmt::{self, Write};
+
+#[cfg(feature = "std")]
+use std::io;
+
+use super::RES;
+
+/// Store Godot format font.
+///
+/// Store a font into a [String] as a Godot 4 `FontFile` text resource (`.tres`).
+///
+/// Pages are referenced as external `Image` resources, `res://` relative.
+/// Packed fonts, those with character data in individual texture channels, are not supported.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let string = bmfont_rs::godot::to_string(&font)?;
+///     assert!(string.starts_with("[gd_resource type=\"FontFile\""));
+///     Ok(())
+/// }
+/// ```
+pub fn to_string(font: &Font) -> crate::Result<String> {
+    if font.common.packed {
+        return Err(crate::Error::UnsupportedEncoding {
+            line: None,
+            entity: "godot".to_owned(),
+            err: "packed fonts are not supported".to_owned(),
+        });
+    }
+    let mut dst = String::default();
+    store(&mut dst, font).map_err(|e| crate::Error::Internal { err: e.to_string() })?;
+    Ok(dst)
+}
+
+/// Store Godot format font.
+///
+/// Store a font into a [Vec] as a Godot 4 `FontFile` text resource (`.tres`).
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+pub fn to_vec(font: &Font) -> crate::Result<Vec<u8>> {
+    to_string(font).map(String::into_bytes)
+}
+
+/// Write Godot format font.
+///
+/// Write a font to the specified writer as a Godot 4 `FontFile` text resource (`.tres`).
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let mut writer = File::create("font.tres")?;
+///     bmfont_rs::godot::to_writer(&mut writer, &font)?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn to_writer<W: io::Write>(mut writer: W, font: &Font) -> crate::Result<()> {
+    let string = to_string(font)?;
+    writer.write_all(string.as_bytes())?;
+    Ok(())
+}
+
+fn store(dst: &mut String, font: &Font) -> fmt::Result {
+    let size = font.info.size.unsigned_abs();
+    let base = font.common.base;
+    let prefix = format!("cache/0/{}/0", size);
+    writeln!(dst, "[gd_resource type=\"FontFile\" load_steps={} format=3]", font.pages.len() + 1)?;
+    writeln!(dst)?;
+    for (id, page) in font.pages.iter().enumerate() {
+        writeln!(
+            dst,
+            "[ext_resource type=\"Image\" path=\"{}{}\" id=\"{}\"]",
+            RES,
+            Escape(page),
+            id + 1
+        )?;
+    }
+    if !font.pages.is_empty() {
+        writeln!(dst)?;
+    }
+    writeln!(dst, "[resource]")?;
+    writeln!(dst, "font_name = \"{}\"", Escape(&font.info.face))?;
+    writeln!(dst, "fixed_size = {}", size)?;
+    writeln!(dst, "{}/ascent = {}.0", prefix, base)?;
+    writeln!(dst, "{}/descent = {}.0", prefix, font.common.line_height as i32 - base as i32)?;
+    writeln!(dst, "{}/scale = 1.0", prefix)?;
+    for id in 0..font.pages.len() {
+        writeln!(dst, "{}/textures/{}/image = ExtResource(\"{}\")", prefix, id, id + 1)?;
+    }
+    for char in &font.chars {
+        let glyph = format!("{}/glyphs/{}", prefix, char.id);
+        writeln!(dst, "{}/advance = Vector2({}, 0)", glyph, char.xadvance)?;
+        writeln!(
+            dst,
+            "{}/offset = Vector2({}, {})",
+            glyph,
+            char.xoffset,
+            char.yoffset as i32 - base as i32
+        )?;
+        writeln!(dst, "{}/size = Vector2({}, {})", glyph, char.width, char.height)?;
+        writeln!(
+            dst,
+            "{}/uv_rect = Rect2({}, {}, {}, {})",
+            glyph, char.x, char.y, char.width, char.height
+        )?;
+        writeln!(dst, "{}/texture_idx = {}", glyph, char.page)?;
+    }
+    for kerning in &font.kernings {
+        writeln!(
+            dst,
+            "cache/0/{}/kerning_overrides/{}/{} = Vector2({}, 0)",
+            size, kerning.first, kerning.second, kerning.amount
+        )?;
+    }
+    Ok(())
+}
+
+/// Godot string escape.
+struct Escape<'a>(&'a str);
+
+impl fmt::Display for Escape<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '"' => f.write_str("\\\"")?,
+                '\\' => f.write_str("\\\\")?,
+                '\n' => f.write_str("\\n")?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -2,6 +2,11 @@ use crate::builder::FontProto;
 use crate::font::Font;
 use crate::LoadSettings;
 
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncRead;
+
 use std::io;
 
 /// Load JSON format font.
@@ -122,3 +127,42 @@ pub fn from_reader_ext<R: io::Read>(mut reader: R, settings: &LoadSettings) -> c
     reader.read_to_end(&mut vec)?;
     from_bytes_ext(&vec, settings)
 }
+
+/// Read JSON format font from an async reader.
+///
+/// As [from_reader], but reads from a `futures-io` [AsyncRead](futures_io::AsyncRead).
+/// This method buffers data internally, a buffered reader is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncRead;
+///
+/// async fn load<R: AsyncRead + Unpin>(reader: R) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::json::from_async_reader(reader).await?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn from_async_reader<R: AsyncRead + Unpin>(reader: R) -> crate::Result<Font> {
+    from_async_reader_ext(reader, &Default::default()).await
+}
+
+/// Read JSON format font from an async reader with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+#[cfg(feature = "async")]
+pub async fn from_async_reader_ext<R: AsyncRead + Unpin>(
+    mut reader: R,
+    settings: &LoadSettings,
+) -> crate::Result<Font> {
+    let mut vec = Vec::default();
+    async_io::read_to_end(&mut reader, &mut vec).await?;
+    from_bytes_ext(&vec, settings)
+}
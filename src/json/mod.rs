@@ -5,3 +5,8 @@ mod store;
 
 pub use load::{from_bytes, from_bytes_ext, from_reader, from_reader_ext, from_str, from_str_ext};
 pub use store::{to_string, to_string_pretty, to_vec, to_vec_pretty, to_writer, to_writer_pretty};
+
+#[cfg(feature = "async")]
+pub use load::{from_async_reader, from_async_reader_ext};
+#[cfg(feature = "async")]
+pub use store::to_async_writer;
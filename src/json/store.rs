@@ -1,5 +1,10 @@
 use crate::font::Font;
 
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncWrite;
+
 use std::io;
 
 /// Store JSON format font.
@@ -137,6 +142,37 @@ pub fn to_writer<W: io::Write>(mut writer: W, font: &Font) -> crate::Result<()>
     write!(writer, "{}", json).map_err(Into::into)
 }
 
+/// Write JSON format font to an async writer.
+///
+/// As [to_writer], but writes to a `futures-io` [AsyncWrite](futures_io::AsyncWrite). The writer
+/// is flushed on completion.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncWrite;
+///
+/// async fn store<W: AsyncWrite + Unpin>(writer: W) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     bmfont_rs::json::to_async_writer(writer, &font).await?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn to_async_writer<W: AsyncWrite + Unpin>(
+    mut writer: W,
+    font: &Font,
+) -> crate::Result<()> {
+    let vec = to_vec(font)?;
+    async_io::write_all(&mut writer, &vec).await?;
+    Ok(())
+}
+
 /// Write JSON pretty format font.
 ///
 /// Write a font to the specified writer in pretty JSON format.
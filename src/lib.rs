@@ -25,6 +25,33 @@ The modules are organized around the core BMFont file formats:
 - `binary` : binary format
 - `json` : JSON format, requires: `--features json`
 - `xml` : XML format, requires: `--features xml`
+- `yaml` : YAML format, requires: `--features yaml`
+- `toml` : TOML format, requires: `--features toml`
+- `msgpack` : MessagePack format, requires: `--features msgpack`
+- `cbor` : CBOR format, requires: `--features cbor`
+- `plist` : XML property list (Cocos2d/ Sparrow) format, requires: `--features plist`
+
+The `serde` feature, `--features serde`, derives `Serialize`/ `Deserialize` for the font types,
+for use with any serde format. Booleans are encoded as `0`/ `1` integers, as per BMFont JSON. The
+`native_bool` module provides an opt-in native boolean encoding.
+
+The `bmfc` module, `--features bmfc`, loads/ stores BMFont generator configuration (`.bmfc`) files
+and checks fonts against the configuration they were generated from.
+
+The `godot` module, `--features godot`, converts to and from Godot 4 `FontFile` text resources
+(`.tres`).
+
+The `spritefont` module, `--features spritefont`, converts to and from the XNA/ MonoGame sprite font
+model, with XML export and, with `--features xml`, import.
+
+The `atlas` module, `--features atlas`, exports font pages as TexturePacker style JSON sprite
+atlases, one per page.
+
+The `textmeshpro` module, `--features textmeshpro`, exports fonts as Unity TextMesh Pro font asset
+JSON: face info, glyph, character and kerning pair adjustment tables.
+
+The `codegen` module, `--features codegen`, generates Rust source defining a `StaticFont`, for
+build time font conversion.
 
 Each module is provides a number of import `from_...` and export: `to_...` functions.
 
@@ -69,6 +96,8 @@ When attempting to load these files, `bmfont_rs` will emit an error describing t
 
 We may be able to work around or ignore some of these problems using the [LoadSettings] struct.
 Simply build the `LoadSettings` instance using the desired behavior switches and pass it into the `ext` form of the load function.
+Text files written by libGDX Hiero are loaded with the `TextDialect::Hiero` profile, the matching
+`StoreSettings` profile writes them back for Hiero consumers.
 
 If you encounter a BMFont file that appears to work with other tools, but not `bmfont_rs` then kindly open a ticket.
 It may be possible to add the correct behavior switch in future versions of `bmfont_rs`.
@@ -96,6 +125,7 @@ When attempting to load files containing ASCII control characters, an [UnsafeVal
 
 Any additional string/ input sanitization MUST be undertaken by users in accordance with their use cases.
 Take particular care that Font page file names from untrusted sources do not allow unintended file access, e.g. `../../secret/file.png`.
+The [Font::resolve_pages] method, in conjunction with a [PathPolicy], resolves page file names against a base directory and rejects those that violate the policy.
 
 The BMFont format specifies strings at:
 - [Info::face]
@@ -154,6 +184,52 @@ Execute from the project root with:
 cargo run --example xml --features xml
 ```
 
+The Sparrow, Starling and Phaser XML dialects are loaded and stored by selecting an `XmlDialect`
+in the `LoadSettings`/ `StoreSettings` passed to the `ext` form of the load/ store functions.
+
+## no_std
+
+The `std` feature is enabled by default.
+With `default-features = false` the crate is `no_std` + `alloc`: the core model, binary load/ store
+and text load functions remain available. Reader/ writer methods, text store, `FontWriter`,
+`PathPolicy` and `Error::Io` require `std`, as do the `json`, `xml`, `yaml`, `toml`, `msgpack`,
+`cbor`, `plist` and `async` features.
+
+## Async
+
+Async load/ store functionality is feature gated: `--features async`.
+When activated, each format module additionally provides `from_async_reader` and
+`to_async_writer` functions that operate on `futures-io` `AsyncRead`/ `AsyncWrite` types.
+These are runtime agnostic and share the parsing logic of their synchronous counterparts.
+
+## Command line tool
+
+The `bmfont` command line tool is feature gated: `--features cli`.
+It converts between all formats and inspects fonts, reading from stdin and writing to stdout when
+no paths are given. Input formats are detected from the content, output formats from the output
+file extension, both may be set explicitly with `--from`/ `--to`.
+
+- `convert` : convert a font from one format to another
+- `info` : summarize the info and common blocks, pages, character and kerning pair counts
+- `validate` : report load warnings and broken references, exit code 1 on errors
+- `normalize` : sort characters and kerning pairs and remove duplicates
+- `subset` : retain the characters listed with `--chars`/ `--chars-file`
+- `merge` : merge fonts, characters already present take precedence
+- `scale` : scale font metrics by a factor, page textures are not resampled
+- `remap` : remap character ids as listed in a map file of `from to` id lines
+
+`info` and `validate` accept `--json` for scripting. The editing subcommands, `subset`, `merge`,
+`scale` and `remap`, are backed by the `Font` methods of the same name. They validate references
+before writing and store in the input format unless directed otherwise.
+
+```bash
+cargo install bmfont_rs --features cli
+bmfont convert font.fnt -o font.xml
+bmfont validate --json font.bin
+cat font.fnt | bmfont normalize > normalized.fnt
+bmfont scale 0.5 font.fnt | bmfont subset --chars-file ascii.txt > small.fnt
+```
+
 ## BMFont
 
 The BMFont homepage is [here](http://www.angelcode.com/products/bmfont/). The site includes
@@ -174,25 +250,85 @@ Licensed under either of
 
 at your option.
 */
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "async")]
+mod async_io;
 mod builder;
 mod charset;
+mod convert;
+mod diff;
+mod edit;
+mod encoding;
 mod error;
+mod event;
 mod font;
+mod font_ref;
+#[cfg(any(feature = "atlas", feature = "textmeshpro"))]
+mod json_escape;
 mod parse;
+#[cfg(feature = "std")]
+mod path;
+mod prelude;
+mod report;
 mod settings;
+mod static_font;
 mod tagged_attributes;
+#[cfg(feature = "std")]
+mod writer;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod tests;
 
+// Allow tests to include generated source, which refers to `::bmfont_rs`.
+#[cfg(test)]
+extern crate self as bmfont_rs;
+
+#[cfg(feature = "atlas")]
+pub mod atlas;
 pub mod binary;
+#[cfg(feature = "bmfc")]
+pub mod bmfc;
+#[cfg(feature = "cbor")]
+pub mod cbor;
+#[cfg(feature = "codegen")]
+pub mod codegen;
+#[cfg(feature = "godot")]
+pub mod godot;
 #[cfg(feature = "json")]
 pub mod json;
+#[cfg(feature = "msgpack")]
+pub mod msgpack;
+#[cfg(feature = "serde")]
+pub mod native_bool;
+#[cfg(feature = "plist")]
+pub mod plist;
+#[cfg(feature = "spritefont")]
+pub mod spritefont;
 pub mod text;
+#[cfg(feature = "textmeshpro")]
+pub mod textmeshpro;
+#[cfg(feature = "toml")]
+pub mod toml;
 #[cfg(feature = "xml")]
 pub mod xml;
+#[cfg(feature = "yaml")]
+pub mod yaml;
 
 pub use charset::*;
+pub use diff::{CharChange, FieldChange, FontDiff, KerningChange, PageChange};
+pub use encoding::{LegacyEncoding, TextEncoding};
 pub use error::{Error, Result};
+pub use event::Event;
 pub use font::{Char, Chnl, Common, Font, Info, Kerning, Packing, Padding, Spacing};
-pub use settings::LoadSettings;
+pub use font_ref::{CharsIter, CharsRef, FontRef, InfoRef, KerningsIter, KerningsRef};
+#[cfg(feature = "std")]
+pub use path::PathPolicy;
+pub use report::{LoadReport, Warning};
+pub use settings::{DuplicateCharPolicy, DuplicateKerningPolicy, LoadSettings};
+pub use settings::{NegativeUnsigned, Rounding, StoreSettings, TextDialect, XmlDialect};
+pub use static_font::StaticFont;
+#[cfg(feature = "std")]
+pub use writer::{FontWriter, Format};
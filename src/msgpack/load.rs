@@ -0,0 +1,129 @@
+use crate::builder::FontProto;
+use crate::font::Font;
+use crate::native_bool::NativeBool;
+use crate::LoadSettings;
+
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncRead;
+
+use std::io;
+
+/// Load MessagePack format font.
+///
+/// Load a font from the specified MessagePack format byte slice.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io;
+/// use std::io::prelude::*;
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let buf = fs::read("font.msgpack")?;
+///     let font = bmfont_rs::msgpack::from_bytes(&buf)?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+pub fn from_bytes(bytes: &[u8]) -> crate::Result<Font> {
+    from_bytes_ext(bytes, &Default::default())
+}
+
+/// Load MessagePack format font with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+pub fn from_bytes_ext(bytes: &[u8], settings: &LoadSettings) -> crate::Result<Font> {
+    let proto: FontProto = rmp_serde::from_slice::<NativeBool<Font>>(bytes)
+        .map_err(|e| crate::Error::Parse {
+            line: None,
+            entity: "msgpack".to_owned(),
+            err: e.to_string(),
+        })?
+        .0
+        .into();
+    proto.build(settings)
+}
+
+/// Read MessagePack format font.
+///
+/// Read a font from the specified MessagePack format reader.
+/// This method buffers data internally, a buffered reader is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io;
+/// use std::io::prelude::*;
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let mut f = File::open("font.msgpack")?;
+///     let font = bmfont_rs::msgpack::from_reader(f)?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+pub fn from_reader<R: io::Read>(reader: R) -> crate::Result<Font> {
+    from_reader_ext(reader, &Default::default())
+}
+
+/// Read MessagePack format font with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+pub fn from_reader_ext<R: io::Read>(mut reader: R, settings: &LoadSettings) -> crate::Result<Font> {
+    let mut vec = Vec::default();
+    reader.read_to_end(&mut vec)?;
+    from_bytes_ext(&vec, settings)
+}
+
+/// Read MessagePack format font from an async reader.
+///
+/// As [from_reader], but reads from a `futures-io` [AsyncRead](futures_io::AsyncRead).
+/// This method buffers data internally, a buffered reader is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncRead;
+///
+/// async fn load<R: AsyncRead + Unpin>(reader: R) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::msgpack::from_async_reader(reader).await?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn from_async_reader<R: AsyncRead + Unpin>(reader: R) -> crate::Result<Font> {
+    from_async_reader_ext(reader, &Default::default()).await
+}
+
+/// Read MessagePack format font from an async reader with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+#[cfg(feature = "async")]
+pub async fn from_async_reader_ext<R: AsyncRead + Unpin>(
+    mut reader: R,
+    settings: &LoadSettings,
+) -> crate::Result<Font> {
+    let mut vec = Vec::default();
+    async_io::read_to_end(&mut reader, &mut vec).await?;
+    from_bytes_ext(&vec, settings)
+}
@@ -0,0 +1,15 @@
+//! MessagePack format operations.
+//!
+//! A compact, self-describing encoding of the [Font](crate::Font) serde representation, with
+//! named fields and [native booleans](crate::native_bool).
+
+mod load;
+mod store;
+
+pub use load::{from_bytes, from_bytes_ext, from_reader, from_reader_ext};
+pub use store::{to_vec, to_writer};
+
+#[cfg(feature = "async")]
+pub use load::{from_async_reader, from_async_reader_ext};
+#[cfg(feature = "async")]
+pub use store::to_async_writer;
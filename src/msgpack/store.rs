@@ -0,0 +1,95 @@
+use crate::font::Font;
+use crate::native_bool::NativeBool;
+
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncWrite;
+
+use std::io;
+
+/// Store MessagePack format font.
+///
+/// Store a font into a [Vec] in MessagePack format.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let vec = bmfont_rs::msgpack::to_vec(&font)?;
+///     println!("{:02X?}", vec);
+///     Ok(())
+/// }
+/// ```
+pub fn to_vec(font: &Font) -> crate::Result<Vec<u8>> {
+    rmp_serde::to_vec_named(&NativeBool(font)).map_err(|e| crate::Error::UnsupportedEncoding {
+        line: None,
+        entity: "msgpack".to_owned(),
+        err: e.to_string(),
+    })
+}
+
+/// Write MessagePack format font.
+///
+/// Write a font to the specified writer in MessagePack format.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io;
+/// use std::io::prelude::*;
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let mut writer = File::create("font.msgpack")?;
+///     bmfont_rs::msgpack::to_writer(&mut writer, &font)?;
+///     Ok(())
+/// }
+/// ```
+pub fn to_writer<W: io::Write>(mut writer: W, font: &Font) -> crate::Result<()> {
+    let vec = to_vec(font)?;
+    writer.write_all(&vec)?;
+    Ok(())
+}
+
+/// Write MessagePack format font to an async writer.
+///
+/// As [to_writer], but writes to a `futures-io` [AsyncWrite](futures_io::AsyncWrite). The writer
+/// is flushed on completion.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncWrite;
+///
+/// async fn store<W: AsyncWrite + Unpin>(writer: W) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     bmfont_rs::msgpack::to_async_writer(writer, &font).await?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn to_async_writer<W: AsyncWrite + Unpin>(
+    mut writer: W,
+    font: &Font,
+) -> crate::Result<()> {
+    let vec = to_vec(font)?;
+    async_io::write_all(&mut writer, &vec).await?;
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//! Native boolean serde encoding.
+//!
+//! The [Font] serde implementation follows BMFont JSON, encoding the [Info] `bold`, `italic`,
+//! `unicode` and `smooth` fields, and the [Common] `packed` field, as `0`/ `1` integers. This
+//! module instead encodes these fields as native booleans, as used by the
+//! [yaml](crate::yaml), [toml](crate::toml), [msgpack](crate::msgpack) and [cbor](crate::cbor)
+//! modules, for use with other serde formats via the `with` attribute.
+//!
+//! Requires: `--features serde`.
+//!
+//! # Example
+//!
+//! ```
+//! use bmfont_rs::Font;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Asset {
+//!     name: String,
+//!     #[serde(with = "bmfont_rs::native_bool")]
+//!     font: Font,
+//! }
+//! ```
+use crate::charset::Charset;
+use crate::font::{Char, Common, Font, Info, Kerning, Packing, Padding, Spacing};
+use crate::prelude::*;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Serialize a [Font] with native booleans.
+pub fn serialize<S: Serializer>(font: &Font, serializer: S) -> Result<S::Ok, S::Error> {
+    FontDef::serialize(font, serializer)
+}
+
+/// Deserialize a [Font] with native booleans.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Font, D::Error> {
+    FontDef::deserialize(deserializer)
+}
+
+/// Native boolean encoding wrapper.
+#[cfg(any(feature = "yaml", feature = "toml", feature = "msgpack", feature = "cbor"))]
+pub(crate) struct NativeBool<T>(pub T);
+
+#[cfg(any(feature = "yaml", feature = "toml", feature = "msgpack", feature = "cbor"))]
+impl Serialize for NativeBool<&Font> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self.0, serializer)
+    }
+}
+
+#[cfg(any(feature = "yaml", feature = "toml", feature = "msgpack", feature = "cbor"))]
+impl<'de> Deserialize<'de> for NativeBool<Font> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(NativeBool)
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Font")]
+struct FontDef {
+    #[serde(with = "InfoDef")]
+    info: Info,
+    #[serde(with = "CommonDef")]
+    common: Common,
+    pages: Vec<String>,
+    chars: Vec<Char>,
+    kernings: Vec<Kerning>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Info", rename_all = "camelCase")]
+pub(crate) struct InfoDef {
+    face: String,
+    size: i16,
+    bold: bool,
+    italic: bool,
+    charset: Charset,
+    unicode: bool,
+    stretch_h: u16,
+    smooth: bool,
+    aa: u8,
+    padding: Padding,
+    spacing: Spacing,
+    #[serde(default)]
+    outline: u8,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(remote = "Common", rename_all = "camelCase")]
+pub(crate) struct CommonDef {
+    line_height: u16,
+    base: u16,
+    scale_w: u16,
+    scale_h: u16,
+    pages: u16,
+    packed: bool,
+    alpha_chnl: Packing,
+    red_chnl: Packing,
+    green_chnl: Packing,
+    blue_chnl: Packing,
+}
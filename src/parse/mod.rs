@@ -1,36 +1,78 @@
-use std::fmt;
-use std::num::ParseIntError;
-use std::str::Utf8Error;
-use std::string::FromUtf8Error;
+use crate::encoding::LegacyEncoding;
+use crate::prelude::*;
+use crate::settings::{LoadSettings, NegativeUnsigned, Rounding};
 
-pub type ParseResult<T> = std::result::Result<T, ParseError>;
+use alloc::string::FromUtf8Error;
+use core::fmt;
+use core::num::{IntErrorKind, ParseIntError};
+use core::str::Utf8Error;
+
+pub type ParseResult<T> = core::result::Result<T, ParseError>;
 
 pub trait Parse: Sized {
     fn parse(src: &str) -> ParseResult<Self>;
 
     #[inline(always)]
     fn parse_bytes(bytes: &[u8]) -> ParseResult<Self> {
-        Self::parse(std::str::from_utf8(bytes)?)
+        Self::parse(core::str::from_utf8(bytes)?)
+    }
+
+    /// Parse with the specified lenient settings, pushing any value adjustments.
+    #[inline(always)]
+    fn parse_ext(src: &str, _: &LoadSettings, _: &mut Vec<Adjustment>) -> ParseResult<Self> {
+        Self::parse(src)
+    }
+
+    #[inline(always)]
+    fn parse_bytes_ext(
+        bytes: &[u8],
+        settings: &LoadSettings,
+        adjustments: &mut Vec<Adjustment>,
+    ) -> ParseResult<Self> {
+        Self::parse_ext(core::str::from_utf8(bytes)?, settings, adjustments)
     }
 }
 
+/// Lenient value adjustment.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Adjustment {
+    Numeric { value: String, adjusted: String },
+    Transcoded { encoding: LegacyEncoding },
+}
+
 impl<T: Copy + Default + Parse, const N: usize> Parse for [T; N] {
-    #[allow(clippy::needless_range_loop)]
     fn parse(src: &str) -> ParseResult<Self> {
-        let mut arr = [T::default(); N];
-        let mut ts = src.split_terminator(',');
-        for i in 0..N {
-            if let Some(t) = ts.next() {
-                arr[i] = T::parse(t.trim())?;
-            } else {
-                return Err(ParseError::ArrayUnderflow);
-            }
-        }
-        if ts.next().is_some() {
-            return Err(ParseError::ArrayOverflow);
+        parse_array(src, T::parse)
+    }
+
+    fn parse_ext(
+        src: &str,
+        settings: &LoadSettings,
+        adjustments: &mut Vec<Adjustment>,
+    ) -> ParseResult<Self> {
+        parse_array(src, |t| T::parse_ext(t, settings, adjustments))
+    }
+}
+
+#[allow(clippy::needless_range_loop)]
+fn parse_array<T, F, const N: usize>(src: &str, mut parse: F) -> ParseResult<[T; N]>
+where
+    T: Copy + Default,
+    F: FnMut(&str) -> ParseResult<T>,
+{
+    let mut arr = [T::default(); N];
+    let mut ts = src.split_terminator(',');
+    for i in 0..N {
+        if let Some(t) = ts.next() {
+            arr[i] = parse(t.trim())?;
+        } else {
+            return Err(ParseError::ArrayUnderflow);
         }
-        Ok(arr)
     }
+    if ts.next().is_some() {
+        return Err(ParseError::ArrayOverflow);
+    }
+    Ok(arr)
 }
 
 impl Parse for String {
@@ -39,32 +81,137 @@ impl Parse for String {
     }
 
     fn parse_bytes(bytes: &[u8]) -> ParseResult<Self> {
-        std::str::from_utf8(bytes).map(ToOwned::to_owned).map_err(Into::into)
+        core::str::from_utf8(bytes).map(ToOwned::to_owned).map_err(Into::into)
     }
-}
 
-impl Parse for i16 {
-    fn parse(src: &str) -> ParseResult<Self> {
-        Self::from_str_radix(src, 10).map_err(Into::into)
+    fn parse_bytes_ext(
+        bytes: &[u8],
+        settings: &LoadSettings,
+        adjustments: &mut Vec<Adjustment>,
+    ) -> ParseResult<Self> {
+        match (Self::parse_bytes(bytes), settings.fallback_encoding) {
+            (Err(_), Some(encoding)) => {
+                adjustments.push(Adjustment::Transcoded { encoding });
+                Ok(encoding.decode(bytes))
+            }
+            (result, _) => result,
+        }
     }
 }
 
-impl Parse for u32 {
-    fn parse(src: &str) -> ParseResult<Self> {
-        Self::from_str_radix(src, 10).map_err(Into::into)
-    }
-}
+macro_rules! implement_parse_integer {
+    ($($type:ty),+) => {
+        $(
+            impl Parse for $type {
+                fn parse(src: &str) -> ParseResult<Self> {
+                    Self::from_str_radix(src, 10).map_err(Into::into)
+                }
 
-impl Parse for u16 {
-    fn parse(src: &str) -> ParseResult<Self> {
-        Self::from_str_radix(src, 10).map_err(Into::into)
-    }
+                fn parse_ext(
+                    src: &str,
+                    settings: &LoadSettings,
+                    adjustments: &mut Vec<Adjustment>,
+                ) -> ParseResult<Self> {
+                    // Strict first, we only pay for leniency on failure.
+                    let err = match Self::from_str_radix(src, 10) {
+                        Ok(v) => return Ok(v),
+                        Err(err) => err,
+                    };
+                    let lenient = parse_lenient(
+                        src,
+                        settings,
+                        Self::MIN as i128,
+                        Self::MAX as i128,
+                        Self::BITS,
+                    );
+                    match lenient {
+                        Some((v, adjusted)) => {
+                            if adjusted {
+                                let value = src.to_owned();
+                                adjustments.push(Adjustment::Numeric { value, adjusted: v.to_string() });
+                            }
+                            Ok(v as Self)
+                        }
+                        None => Err(err.into()),
+                    }
+                }
+            }
+        )+
+    };
 }
 
-impl Parse for u8 {
-    fn parse(src: &str) -> ParseResult<Self> {
-        Self::from_str_radix(src, 10).map_err(Into::into)
-    }
+implement_parse_integer!(i16, u32, u16, u8);
+
+/// Lenient integer parse. Returns the value, within the specified bounds, and whether it was
+/// adjusted, or None if the source cannot be accepted with the specified settings.
+fn parse_lenient(
+    src: &str,
+    settings: &LoadSettings,
+    min: i128,
+    max: i128,
+    bits: u32,
+) -> Option<(i128, bool)> {
+    let (negative, digits) = match src.as_bytes().first() {
+        Some(b'-') => (true, &src[1..]),
+        Some(b'+') => (false, &src[1..]),
+        _ => (false, src),
+    };
+    let hex = digits.strip_prefix("0x").or_else(|| digits.strip_prefix("0X"));
+    let (v, mut adjusted) = match hex {
+        Some(hex) if settings.allow_hex => {
+            // `from_str_radix` accepts a leading sign, the sign has already been consumed.
+            if !hex.as_bytes().first()?.is_ascii_hexdigit() {
+                return None;
+            }
+            let v = i128::from_str_radix(hex, 16).ok()?;
+            (if negative { -v } else { v }, false)
+        }
+        Some(_) => return None,
+        None => match src.parse::<i128>() {
+            Ok(v) => (v, false),
+            Err(err) if *err.kind() == IntErrorKind::PosOverflow => (i128::MAX, false),
+            Err(err) if *err.kind() == IntErrorKind::NegOverflow => (i128::MIN, false),
+            Err(_) => {
+                let rounding = settings.round_floats?;
+                let f: f64 = src.parse().ok()?;
+                if !f.is_finite() {
+                    return None;
+                }
+                // Saturating cast, rounds toward zero. We round via the fractional remainder as
+                // opposed to the f64 rounding methods, which are not available in `core`.
+                let t = f as i128;
+                let frac = f - t as f64;
+                let r = match rounding {
+                    Rounding::Nearest if frac >= 0.5 => t.saturating_add(1),
+                    Rounding::Nearest if frac <= -0.5 => t.saturating_sub(1),
+                    Rounding::Down if frac < 0.0 => t.saturating_sub(1),
+                    Rounding::Up if frac > 0.0 => t.saturating_add(1),
+                    _ => t,
+                };
+                // Integral floats, e.g. `4.0` or `1e3`, are reformatted and thus adjusted.
+                (r, true)
+            }
+        },
+    };
+    let v = if v < min {
+        adjusted = true;
+        match settings.negative_unsigned {
+            Some(NegativeUnsigned::Wrap) if min == 0 => v.rem_euclid(1 << bits),
+            Some(NegativeUnsigned::Clamp) if min == 0 => min,
+            _ if settings.clamp_out_of_range => min,
+            _ => return None,
+        }
+    } else if v > max {
+        adjusted = true;
+        if settings.clamp_out_of_range {
+            max
+        } else {
+            return None;
+        }
+    } else {
+        v
+    };
+    Some((v, adjusted))
 }
 
 impl Parse for bool {
@@ -83,6 +230,7 @@ pub enum ParseError {
     Other(String),
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for ParseError {}
 
 impl fmt::Display for ParseError {
@@ -139,4 +287,195 @@ mod tests {
     fn u8_4_overflow() {
         assert_eq!(<[u8; 4]>::parse("1,2,3,4,5"), Err(ParseError::ArrayOverflow));
     }
+
+    macro_rules! parse_ext_ok {
+        ($name:ident, $type:ty, $settings:expr, $src:expr, $v:expr, $adjusted:expr) => {
+            #[test]
+            fn $name() {
+                let mut adjustments = Vec::default();
+                assert_eq!(<$type>::parse_ext($src, &$settings, &mut adjustments), Ok($v));
+                assert_eq!(adjustments.len(), $adjusted as usize);
+            }
+        };
+    }
+
+    parse_ext_ok!(ext_strict, u16, LoadSettings::default(), "12", 12, false);
+    parse_ext_ok!(
+        ext_round_nearest,
+        i16,
+        LoadSettings::default().round_floats(Rounding::Nearest),
+        "1.5",
+        2,
+        true
+    );
+    parse_ext_ok!(
+        ext_round_down,
+        i16,
+        LoadSettings::default().round_floats(Rounding::Down),
+        "-1.5",
+        -2,
+        true
+    );
+    parse_ext_ok!(
+        ext_round_up,
+        i16,
+        LoadSettings::default().round_floats(Rounding::Up),
+        "1.25",
+        2,
+        true
+    );
+    parse_ext_ok!(
+        ext_round_toward_zero,
+        i16,
+        LoadSettings::default().round_floats(Rounding::TowardZero),
+        "-1.75",
+        -1,
+        true
+    );
+    parse_ext_ok!(
+        ext_round_nearest_negative,
+        i16,
+        LoadSettings::default().round_floats(Rounding::Nearest),
+        "-2.5",
+        -3,
+        true
+    );
+    parse_ext_ok!(
+        ext_round_nearest_below_half,
+        i16,
+        LoadSettings::default().round_floats(Rounding::Nearest),
+        "0.49999999999999994",
+        0,
+        true
+    );
+    parse_ext_ok!(
+        ext_round_integral,
+        u8,
+        LoadSettings::default().round_floats(Rounding::Nearest),
+        "4.0",
+        4,
+        true
+    );
+    parse_ext_ok!(
+        ext_round_exponent,
+        u16,
+        LoadSettings::default().round_floats(Rounding::Nearest),
+        "1e3",
+        1000,
+        true
+    );
+    parse_ext_ok!(
+        ext_clamp_max,
+        u8,
+        LoadSettings::default().clamp_out_of_range(),
+        "300",
+        255,
+        true
+    );
+    parse_ext_ok!(
+        ext_clamp_min,
+        i16,
+        LoadSettings::default().clamp_out_of_range(),
+        "-40000",
+        -32768,
+        true
+    );
+    parse_ext_ok!(
+        ext_clamp_huge,
+        u32,
+        LoadSettings::default().clamp_out_of_range(),
+        "99999999999999999999999999999999999999999",
+        u32::MAX,
+        true
+    );
+    parse_ext_ok!(
+        ext_negative_unsigned_wrap,
+        u16,
+        LoadSettings::default().negative_unsigned(NegativeUnsigned::Wrap),
+        "-1",
+        65535,
+        true
+    );
+    parse_ext_ok!(
+        ext_negative_unsigned_clamp,
+        u16,
+        LoadSettings::default().negative_unsigned(NegativeUnsigned::Clamp),
+        "-1",
+        0,
+        true
+    );
+    parse_ext_ok!(ext_hex, u32, LoadSettings::default().allow_hex(), "0x4E00", 0x4E00, false);
+    parse_ext_ok!(ext_hex_negative, i16, LoadSettings::default().allow_hex(), "-0x10", -16, false);
+    parse_ext_ok!(
+        ext_array,
+        [u8; 4],
+        LoadSettings::default().round_floats(Rounding::Nearest),
+        "1,2.5,3,4",
+        [1, 3, 3, 4],
+        true
+    );
+
+    macro_rules! parse_ext_err {
+        ($name:ident, $type:ty, $settings:expr, $src:expr) => {
+            #[test]
+            fn $name() {
+                let mut adjustments = Vec::default();
+                assert!(<$type>::parse_ext($src, &$settings, &mut adjustments).is_err());
+            }
+        };
+    }
+
+    parse_ext_err!(ext_err_float, i16, LoadSettings::default(), "1.5");
+    parse_ext_err!(ext_err_range, u8, LoadSettings::default(), "256");
+    parse_ext_err!(ext_err_negative, u16, LoadSettings::default(), "-1");
+    parse_ext_err!(
+        ext_err_negative_signed,
+        i16,
+        LoadSettings::default().negative_unsigned(NegativeUnsigned::Clamp),
+        "-40000"
+    );
+    parse_ext_err!(ext_err_hex, u32, LoadSettings::default(), "0x10");
+    parse_ext_err!(ext_err_hex_sign, i16, LoadSettings::default().allow_hex(), "0x-5");
+    parse_ext_err!(ext_err_hex_double_sign, i16, LoadSettings::default().allow_hex(), "-0x-5");
+    parse_ext_err!(ext_err_hex_plus_sign, u32, LoadSettings::default().allow_hex(), "0x+5");
+    parse_ext_err!(
+        ext_err_nan,
+        u32,
+        LoadSettings::default().round_floats(Rounding::Nearest),
+        "NaN"
+    );
+    parse_ext_err!(
+        ext_err_inf,
+        u32,
+        LoadSettings::default().round_floats(Rounding::Nearest).clamp_out_of_range(),
+        "inf"
+    );
+
+    #[test]
+    fn ext_string_fallback() {
+        let mut adjustments = Vec::default();
+        let settings = LoadSettings::default().fallback_encoding(LegacyEncoding::Windows1252);
+        let string = String::parse_bytes_ext(b"Caf\xE9", &settings, &mut adjustments);
+        assert_eq!(string, Ok("Café".to_owned()));
+        assert_eq!(
+            adjustments,
+            vec![Adjustment::Transcoded { encoding: LegacyEncoding::Windows1252 }]
+        );
+    }
+
+    #[test]
+    fn ext_string_fallback_utf8() {
+        let mut adjustments = Vec::default();
+        let settings = LoadSettings::default().fallback_encoding(LegacyEncoding::Windows1252);
+        let string = String::parse_bytes_ext("Café".as_bytes(), &settings, &mut adjustments);
+        assert_eq!(string, Ok("Café".to_owned()));
+        assert!(adjustments.is_empty());
+    }
+
+    #[test]
+    fn ext_string_err() {
+        let mut adjustments = Vec::default();
+        let settings = LoadSettings::default();
+        assert!(String::parse_bytes_ext(b"Caf\xE9", &settings, &mut adjustments).is_err());
+    }
 }
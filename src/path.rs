@@ -0,0 +1,299 @@
+use crate::font::Font;
+
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Page path resolution policy.
+///
+/// This struct specifies how [Font::resolve_pages] treats page file names. The default policy is
+/// strict: page files must be relative paths that resolve to a location within the base
+/// directory.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let src = fs::read_to_string("fonts/font.txt")?;
+///     let font = bmfont_rs::text::from_str(&src)?;
+///     let policy = bmfont_rs::PathPolicy::default().normalize_separators();
+///     for path in font.resolve_pages("fonts", &policy)? {
+///         println!("{}", path.display());
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy)]
+#[non_exhaustive]
+pub struct PathPolicy {
+    /// Allow absolute paths. Absolute paths are not required to reside within the base
+    /// directory.
+    pub allow_absolute: bool,
+    /// Allow parent directory `..` components. The resolved path must still reside within the
+    /// base directory.
+    pub allow_parent_dir: bool,
+    /// Allow symbolic links that resolve to a location outside of the base directory.
+    pub allow_symlink_escape: bool,
+    /// Allow Windows drive letter prefixes, e.g. `C:`.
+    pub allow_drive_letters: bool,
+    /// Allow backslash characters.
+    pub allow_backslashes: bool,
+    /// Convert backslash separators to forward slashes prior to resolution.
+    pub normalize_separators: bool,
+    /// Convert page file names to lower case prior to resolution.
+    pub lowercase: bool,
+}
+
+impl PathPolicy {
+    // As with `LoadSettings`, we rely on builder type setter functions as opposed to a 'new'
+    // function.
+
+    /// Set allow_absolute to true. Returns self.
+    pub fn allow_absolute(mut self) -> Self {
+        self.allow_absolute = true;
+        self
+    }
+
+    /// Set allow_parent_dir to true. Returns self.
+    pub fn allow_parent_dir(mut self) -> Self {
+        self.allow_parent_dir = true;
+        self
+    }
+
+    /// Set allow_symlink_escape to true. Returns self.
+    pub fn allow_symlink_escape(mut self) -> Self {
+        self.allow_symlink_escape = true;
+        self
+    }
+
+    /// Set allow_drive_letters to true. Returns self.
+    pub fn allow_drive_letters(mut self) -> Self {
+        self.allow_drive_letters = true;
+        self
+    }
+
+    /// Set allow_backslashes to true. Returns self.
+    pub fn allow_backslashes(mut self) -> Self {
+        self.allow_backslashes = true;
+        self
+    }
+
+    /// Set normalize_separators to true. Returns self.
+    pub fn normalize_separators(mut self) -> Self {
+        self.normalize_separators = true;
+        self
+    }
+
+    /// Set lowercase to true. Returns self.
+    pub fn lowercase(mut self) -> Self {
+        self.lowercase = true;
+        self
+    }
+}
+
+impl Font {
+    /// Resolve page file paths.
+    ///
+    /// Resolve the page file names against the specified base directory, typically the directory
+    /// containing the font descriptor, in accordance with the specified policy. Returns the
+    /// canonical page paths, the index corresponds to the page id.
+    ///
+    /// Page files are not required to exist. In this case the path is canonicalized up to the
+    /// deepest existing ancestor.
+    ///
+    /// # Errors
+    ///
+    /// * [Error::UnsafePagePath](crate::Error::UnsafePagePath) if a page file name violates the
+    ///   policy.
+    /// * [Error::Io](crate::Error::Io) if the base directory cannot be canonicalized.
+    pub fn resolve_pages<P: AsRef<Path>>(
+        &self,
+        base_dir: P,
+        policy: &PathPolicy,
+    ) -> crate::Result<Vec<PathBuf>> {
+        let base_dir = fs::canonicalize(base_dir)?;
+        self.pages
+            .iter()
+            .enumerate()
+            .map(|(id, file)| {
+                resolve(&base_dir, file, policy).map_err(|err| crate::Error::UnsafePagePath {
+                    id: id as u32,
+                    file: file.to_owned(),
+                    err,
+                })
+            })
+            .collect()
+    }
+}
+
+fn resolve(base_dir: &Path, file: &str, policy: &PathPolicy) -> Result<PathBuf, String> {
+    let mut file = file.to_owned();
+    if policy.normalize_separators {
+        file = file.replace('\\', "/");
+    }
+    if policy.lowercase {
+        file = file.to_lowercase();
+    }
+    if file.is_empty() {
+        return Err("empty path".to_owned());
+    }
+    if !policy.allow_backslashes && file.contains('\\') {
+        return Err("backslash".to_owned());
+    }
+    if !policy.allow_drive_letters && has_drive_letter(&file) {
+        return Err("drive letter".to_owned());
+    }
+    let path = Path::new(&file);
+    let absolute = path.has_root() || path.is_absolute();
+    if absolute && !policy.allow_absolute {
+        return Err("absolute path".to_owned());
+    }
+    let mut resolved = if absolute { PathBuf::new() } else { base_dir.to_owned() };
+    for component in path.components() {
+        match component {
+            Component::Prefix(prefix) => resolved.push(prefix.as_os_str()),
+            Component::RootDir => resolved.push(Component::RootDir),
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if !policy.allow_parent_dir {
+                    return Err("parent directory".to_owned());
+                }
+                if !resolved.pop() || (!absolute && !resolved.starts_with(base_dir)) {
+                    return Err("escapes base directory".to_owned());
+                }
+            }
+            Component::Normal(name) => resolved.push(name),
+        }
+    }
+    let resolved = canonicalize_existing(&resolved);
+    if !absolute && !policy.allow_symlink_escape && !resolved.starts_with(base_dir) {
+        return Err("symbolic link escapes base directory".to_owned());
+    }
+    Ok(resolved)
+}
+
+/// Canonicalize the deepest existing ancestor and append the remaining components.
+fn canonicalize_existing(path: &Path) -> PathBuf {
+    let mut head = path;
+    let mut tail = Vec::default();
+    loop {
+        if let Ok(mut canonical) = fs::canonicalize(head) {
+            tail.iter().rev().for_each(|u| canonical.push(u));
+            return canonical;
+        }
+        match (head.parent(), head.file_name()) {
+            (Some(parent), Some(name)) => {
+                tail.push(name);
+                head = parent;
+            }
+            _ => return path.to_owned(),
+        }
+    }
+}
+
+fn has_drive_letter(file: &str) -> bool {
+    let bytes = file.as_bytes();
+    bytes.len() >= 2 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join(format!("bmfont_rs_path_{}_{}", name, std::process::id()))
+            .join("base");
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::canonicalize(dir).unwrap()
+    }
+
+    fn font(pages: &[&str]) -> Font {
+        Font { pages: pages.iter().map(|&u| u.to_owned()).collect(), ..Default::default() }
+    }
+
+    macro_rules! resolve_ok {
+        ($name:ident, $policy:expr, $file:expr, $path:expr) => {
+            #[test]
+            fn $name() -> crate::Result<()> {
+                let dir = test_dir(stringify!($name));
+                let paths = font(&[$file]).resolve_pages(&dir, &$policy)?;
+                assert_eq!(paths, vec![dir.join($path)]);
+                Ok(())
+            }
+        };
+    }
+
+    resolve_ok!(ok_file, PathPolicy::default(), "sheet_0.png", "sheet_0.png");
+    resolve_ok!(ok_sub_dir, PathPolicy::default(), "pages/sheet_0.png", "pages/sheet_0.png");
+    resolve_ok!(ok_cur_dir, PathPolicy::default(), "./sheet_0.png", "sheet_0.png");
+    resolve_ok!(
+        ok_parent_dir,
+        PathPolicy::default().allow_parent_dir(),
+        "pages/../sheet_0.png",
+        "sheet_0.png"
+    );
+    resolve_ok!(
+        ok_separators,
+        PathPolicy::default().normalize_separators(),
+        "pages\\sheet_0.png",
+        "pages/sheet_0.png"
+    );
+    resolve_ok!(ok_lowercase, PathPolicy::default().lowercase(), "Sheet_0.PNG", "sheet_0.png");
+
+    macro_rules! resolve_err {
+        ($name:ident, $policy:expr, $file:expr) => {
+            #[test]
+            fn $name() {
+                let dir = test_dir(stringify!($name));
+                match font(&["ok.png", $file]).resolve_pages(&dir, &$policy) {
+                    Err(crate::Error::UnsafePagePath { id: 1, .. }) => {}
+                    Err(e) => panic!("unexpected error: {}", e),
+                    Ok(_) => panic!("error expected"),
+                }
+            }
+        };
+    }
+
+    resolve_err!(err_empty, PathPolicy::default(), "");
+    resolve_err!(err_absolute, PathPolicy::default(), "/secret/sheet_0.png");
+    resolve_err!(err_parent_dir, PathPolicy::default(), "../sheet_0.png");
+    resolve_err!(err_parent_dir_escape, PathPolicy::default().allow_parent_dir(), "a/../../b.png");
+    resolve_err!(err_backslash, PathPolicy::default(), "pages\\sheet_0.png");
+    resolve_err!(err_drive_letter, PathPolicy::default(), "C:sheet_0.png");
+    resolve_err!(
+        err_drive_letter_separators,
+        PathPolicy::default().normalize_separators(),
+        "C:\\sheet_0.png"
+    );
+
+    #[test]
+    fn absolute() -> crate::Result<()> {
+        let dir = test_dir("absolute");
+        let file = dir.join("sheet_0.png");
+        let paths = font(&[file.to_str().unwrap()])
+            .resolve_pages(&dir, &PathPolicy::default().allow_absolute())?;
+        assert_eq!(paths, vec![file]);
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlink_escape() -> crate::Result<()> {
+        let dir = test_dir("symlink_escape");
+        let outside = dir.parent().unwrap().join("outside");
+        fs::create_dir_all(&outside)?;
+        std::os::unix::fs::symlink(&outside, dir.join("link"))?;
+        let font = font(&["link/sheet_0.png"]);
+        match font.resolve_pages(&dir, &PathPolicy::default()) {
+            Err(crate::Error::UnsafePagePath { id: 0, .. }) => {}
+            Err(e) => panic!("unexpected error: {}", e),
+            Ok(_) => panic!("error expected"),
+        }
+        let paths = font.resolve_pages(&dir, &PathPolicy::default().allow_symlink_escape())?;
+        assert_eq!(paths, vec![outside.join("sheet_0.png")]);
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+extern crate roxmltree as xml;
+
+use crate::builder::attributes::{Attribute, Attributes};
+use crate::builder::FontBuilder;
+use crate::font::Font;
+use crate::{LoadReport, LoadSettings};
+
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncRead;
+
+use std::io;
+
+/// Load plist format font.
+///
+/// Load a font from the specified XML property list format [str].
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let src = fs::read_to_string("font.plist")?;
+///     let font = bmfont_rs::plist::from_str(&src)?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+pub fn from_str(src: &str) -> crate::Result<Font> {
+    from_str_ext(src, &Default::default())
+}
+
+/// Load plist format font with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+pub fn from_str_ext(src: &str, settings: &LoadSettings) -> crate::Result<Font> {
+    FontBuilderPlist::new(settings).load_str(src)?.build()
+}
+
+/// Load plist format font with the specified import behavior settings, reporting warnings.
+///
+/// As [from_str_ext], additionally reporting any non-fatal [Warning](crate::Warning)s raised
+/// by lenient import behavior settings.
+pub fn from_str_report(src: &str, settings: &LoadSettings) -> crate::Result<LoadReport> {
+    FontBuilderPlist::new(settings).load_str(src)?.build_report()
+}
+
+/// Load plist format font.
+///
+/// Load a font from the specified XML property list format byte slice.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let buf = fs::read("font.plist")?;
+///     let font = bmfont_rs::plist::from_bytes(&buf)?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+pub fn from_bytes(bytes: &[u8]) -> crate::Result<Font> {
+    from_bytes_ext(bytes, &Default::default())
+}
+
+/// Load plist format font with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+pub fn from_bytes_ext(bytes: &[u8], settings: &LoadSettings) -> crate::Result<Font> {
+    from_str_ext(utf8(bytes)?, settings)
+}
+
+/// Load plist format font with the specified import behavior settings, reporting warnings.
+///
+/// As [from_bytes_ext], additionally reporting any non-fatal [Warning](crate::Warning)s raised
+/// by lenient import behavior settings.
+pub fn from_bytes_report(bytes: &[u8], settings: &LoadSettings) -> crate::Result<LoadReport> {
+    from_str_report(utf8(bytes)?, settings)
+}
+
+fn utf8(bytes: &[u8]) -> crate::Result<&str> {
+    std::str::from_utf8(bytes).map_err(|e| crate::Error::Parse {
+        line: None,
+        entity: "font".to_owned(),
+        err: e.to_string(),
+    })
+}
+
+/// Read plist format font.
+///
+/// Read a font from the specified XML property list format reader.
+/// This method buffers data internally, a buffered reader is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let f = File::open("font.plist")?;
+///     let font = bmfont_rs::plist::from_reader(f)?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+pub fn from_reader<R: io::Read>(reader: R) -> crate::Result<Font> {
+    from_reader_ext(reader, &Default::default())
+}
+
+/// Read plist format font with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+pub fn from_reader_ext<R: io::Read>(mut reader: R, settings: &LoadSettings) -> crate::Result<Font> {
+    let mut vec = Vec::default();
+    reader.read_to_end(&mut vec)?;
+    from_bytes_ext(&vec, settings)
+}
+
+/// Read plist format font from an async reader.
+///
+/// As [from_reader], but reads from a `futures-io` [AsyncRead](futures_io::AsyncRead).
+/// This method buffers data internally, a buffered reader is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncRead;
+///
+/// async fn load<R: AsyncRead + Unpin>(reader: R) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::plist::from_async_reader(reader).await?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn from_async_reader<R: AsyncRead + Unpin>(reader: R) -> crate::Result<Font> {
+    from_async_reader_ext(reader, &Default::default()).await
+}
+
+/// Read plist format font from an async reader with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+#[cfg(feature = "async")]
+pub async fn from_async_reader_ext<R: AsyncRead + Unpin>(
+    mut reader: R,
+    settings: &LoadSettings,
+) -> crate::Result<Font> {
+    let mut vec = Vec::default();
+    async_io::read_to_end(&mut reader, &mut vec).await?;
+    from_bytes_ext(&vec, settings)
+}
+
+#[derive(Debug)]
+struct FontBuilderPlist {
+    builder: FontBuilder,
+}
+
+impl FontBuilderPlist {
+    fn new(settings: &LoadSettings) -> Self {
+        Self { builder: FontBuilder::new(settings) }
+    }
+
+    fn load_str(mut self, src: &str) -> crate::Result<FontBuilder> {
+        let options = xml::ParsingOptions { allow_dtd: true, ..Default::default() };
+        let document = xml::Document::parse_with_options(src, options).map_err(|e| {
+            crate::Error::Parse { line: None, entity: "font".to_owned(), err: e.to_string() }
+        })?;
+        let root = document.root_element();
+        check_tag_name(&root, "plist")?;
+        let mut elements = child_elements(&root)?.into_iter();
+        let dict = match (elements.next(), elements.next()) {
+            (Some(dict), None) => dict,
+            _ => return Err(parse_err(&root, "expected a single root dict".to_owned())),
+        };
+        check_tag_name(&dict, "dict")?;
+        for (key, value) in dict_entries(&dict)? {
+            match key {
+                "info" => {
+                    check_tag_name(&value, "dict")?;
+                    self.builder.set_info_attributes(line(&value), &mut Dict::new(&value)?)?;
+                }
+                "common" => {
+                    check_tag_name(&value, "dict")?;
+                    self.builder.set_common_attributes(line(&value), &mut Dict::new(&value)?)?;
+                }
+                "pages" => array(&value, |u| self.builder.add_page_attributes(u))?,
+                "chars" => array(&value, |u| self.builder.add_char_attributes(None, u))?,
+                "kernings" => array(&value, |u| self.builder.add_kerning_attributes(None, u))?,
+                key => {
+                    if !self.builder.settings().ignore_invalid_tags {
+                        return Err(crate::Error::InvalidTag {
+                            line: line(&value),
+                            tag: key.to_owned(),
+                        });
+                    }
+                }
+            }
+        }
+        Ok(self.builder)
+    }
+}
+
+/// Dictionary entries as attributes.
+struct Dict<'a> {
+    entries: std::vec::IntoIter<(&'a str, xml::Node<'a, 'a>)>,
+}
+
+impl<'a> Dict<'a> {
+    fn new(node: &xml::Node<'a, 'a>) -> crate::Result<Self> {
+        Ok(Self { entries: dict_entries(node)?.into_iter() })
+    }
+}
+
+impl<'a> Attributes<'a> for Dict<'a> {
+    fn next_attribute(&mut self) -> crate::Result<Option<Attribute<'a>>> {
+        let (key, value) = match self.entries.next() {
+            Some(entry) => entry,
+            None => return Ok(None),
+        };
+        let text = match value.tag_name().name() {
+            "integer" | "real" | "string" => value.text().unwrap_or_default(),
+            "true" => "1",
+            "false" => "0",
+            tag => {
+                return Err(parse_err(&value, format!("{}: unsupported value type: {}", key, tag)))
+            }
+        };
+        Ok(Some(Attribute::new(key.as_bytes(), text.as_bytes(), line(&value))))
+    }
+}
+
+/// Array of dictionaries, each loaded as attributes.
+fn array<'a, F>(node: &xml::Node<'a, 'a>, mut op: F) -> crate::Result<()>
+where
+    F: FnMut(&mut Dict<'a>) -> crate::Result<()>,
+{
+    check_tag_name(node, "array")?;
+    for dict in child_elements(node)? {
+        check_tag_name(&dict, "dict")?;
+        op(&mut Dict::new(&dict)?)?;
+    }
+    Ok(())
+}
+
+/// Dictionary key/ value element pairs.
+fn dict_entries<'a>(node: &xml::Node<'a, 'a>) -> crate::Result<Vec<(&'a str, xml::Node<'a, 'a>)>> {
+    let mut elements = child_elements(node)?.into_iter();
+    let mut entries = Vec::default();
+    while let Some(key) = elements.next() {
+        check_tag_name(&key, "key")?;
+        let value =
+            elements.next().ok_or_else(|| parse_err(&key, "dict: key without value".to_owned()))?;
+        entries.push((key.text().unwrap_or_default(), value));
+    }
+    Ok(entries)
+}
+
+/// Child elements, rejecting non whitespace text.
+fn child_elements<'a>(node: &xml::Node<'a, 'a>) -> crate::Result<Vec<xml::Node<'a, 'a>>> {
+    let mut elements = Vec::default();
+    for child in node.children() {
+        match child.node_type() {
+            xml::NodeType::Element => elements.push(child),
+            xml::NodeType::Text if child.text().unwrap_or_default().trim().is_empty() => {}
+            xml::NodeType::Text => {
+                let err = format!("{}: unexpected text", node.tag_name().name());
+                return Err(parse_err(&child, err));
+            }
+            _ => {}
+        }
+    }
+    Ok(elements)
+}
+
+fn check_tag_name(node: &xml::Node, tag_name: &str) -> crate::Result<()> {
+    if node.tag_name().name() == tag_name {
+        Ok(())
+    } else {
+        Err(crate::Error::InvalidTag { line: line(node), tag: node.tag_name().name().to_owned() })
+    }
+}
+
+fn line(node: &xml::Node) -> Option<usize> {
+    Some(node.document().text_pos_at(node.range().start).row as usize)
+}
+
+fn parse_err(node: &xml::Node, err: String) -> crate::Error {
+    crate::Error::Parse { line: line(node), entity: "plist".to_owned(), err }
+}
@@ -0,0 +1,39 @@
+//! XML property list format operations.
+//!
+//! The Cocos2d/ Sparrow style plist wrapped BMFont descriptor: a root dictionary with `info` and
+//! `common` dictionaries and `pages`, `chars` and `kernings` arrays of dictionaries. Dictionary
+//! keys are the BMFont attribute names, values are `<integer>`, `<string>` or `<true/>`/
+//! `<false/>` elements.
+//!
+//! ```xml
+//! <?xml version="1.0" encoding="UTF-8"?>
+//! <!DOCTYPE plist PUBLIC "-//Apple//DTD PLIST 1.0//EN" "http://www.apple.com/DTDs/PropertyList-1.0.dtd">
+//! <plist version="1.0">
+//! <dict>
+//!     <key>info</key>
+//!     <dict>
+//!         <key>face</key>
+//!         <string>Arial</string>
+//!         ...
+//!     </dict>
+//!     ...
+//!     <key>chars</key>
+//!     <array>
+//!         <dict><key>id</key><integer>32</integer>...</dict>
+//!     </array>
+//! </dict>
+//! </plist>
+//! ```
+
+mod load;
+mod store;
+
+pub use load::{from_bytes, from_bytes_ext, from_reader, from_reader_ext, from_str, from_str_ext};
+pub use load::{from_bytes_report, from_str_report};
+pub(crate) use store::StorePlist;
+pub use store::{to_string, to_vec, to_writer};
+
+#[cfg(feature = "async")]
+pub use load::{from_async_reader, from_async_reader_ext};
+#[cfg(feature = "async")]
+pub use store::to_async_writer;
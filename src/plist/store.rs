@@ -0,0 +1,274 @@
+use crate::font::{Char, Common, Font, Info, Kerning};
+use crate::writer::{FontWriter, Format, StoreStream};
+
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncWrite;
+
+use std::fmt::Display;
+use std::io;
+
+/// Store plist format font.
+///
+/// Store a font into a [String] in XML property list format.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let string = bmfont_rs::plist::to_string(&font)?;
+///     assert!(string.contains("<plist version=\"1.0\">"));
+///     Ok(())
+/// }
+/// ```
+pub fn to_string(font: &Font) -> crate::Result<String> {
+    let vec = to_vec(font)?;
+    String::from_utf8(vec).map_err(|e| crate::Error::Parse {
+        line: None,
+        entity: "font".to_owned(),
+        err: format!("UTF8: {}", e),
+    })
+}
+
+/// Store plist format font.
+///
+/// Store a font into a [Vec] in XML property list format.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+pub fn to_vec(font: &Font) -> crate::Result<Vec<u8>> {
+    let mut vec: Vec<u8> = Vec::default();
+    to_writer(&mut vec, font)?;
+    Ok(vec)
+}
+
+/// Write plist format font.
+///
+/// Write a font to the specified writer in XML property list format.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io::BufWriter;
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let mut writer = BufWriter::new(File::create("font.plist")?);
+///     bmfont_rs::plist::to_writer(&mut writer, &font)?;
+///     Ok(())
+/// }
+/// ```
+pub fn to_writer<W: io::Write>(writer: W, font: &Font) -> crate::Result<()> {
+    let mut writer = FontWriter::new(writer, Format::Plist);
+    writer.write_font(font)?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Write plist format font to an async writer.
+///
+/// As [to_writer], but writes to a `futures-io` [AsyncWrite](futures_io::AsyncWrite). The writer
+/// is flushed on completion.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncWrite;
+///
+/// async fn store<W: AsyncWrite + Unpin>(writer: W) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     bmfont_rs::plist::to_async_writer(writer, &font).await?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn to_async_writer<W: AsyncWrite + Unpin>(
+    mut writer: W,
+    font: &Font,
+) -> crate::Result<()> {
+    let vec = to_vec(font)?;
+    async_io::write_all(&mut writer, &vec).await?;
+    Ok(())
+}
+
+/// Plist format streaming store.
+#[derive(Debug, Default)]
+pub(crate) struct StorePlist;
+
+impl StoreStream for StorePlist {
+    fn info<W: io::Write>(&mut self, writer: &mut W, info: &Info) -> crate::Result<()> {
+        writeln!(writer, "<?xml version=\"1.0\" encoding=\"UTF-8\"?>")?;
+        writeln!(
+            writer,
+            "<!DOCTYPE plist PUBLIC \"-//Apple//DTD PLIST 1.0//EN\" \
+             \"http://www.apple.com/DTDs/PropertyList-1.0.dtd\">"
+        )?;
+        writeln!(writer, "<plist version=\"1.0\">")?;
+        writeln!(writer, "<dict>")?;
+        writeln!(writer, "\t<key>info</key>")?;
+        writeln!(writer, "\t<dict>")?;
+        entry(writer, "face", Value::String(&escape("info face", &info.face)?))?;
+        entry(writer, "size", Value::Integer(&info.size))?;
+        entry(writer, "bold", Value::Bool(info.bold))?;
+        entry(writer, "italic", Value::Bool(info.italic))?;
+        entry(writer, "charset", Value::String(&info.charset.to_string()))?;
+        entry(writer, "unicode", Value::Bool(info.unicode))?;
+        entry(writer, "stretchH", Value::Integer(&info.stretch_h))?;
+        entry(writer, "smooth", Value::Bool(info.smooth))?;
+        entry(writer, "aa", Value::Integer(&info.aa))?;
+        let padding = &info.padding;
+        let padding = format!("{},{},{},{}", padding.up, padding.right, padding.down, padding.left);
+        entry(writer, "padding", Value::String(&padding))?;
+        let spacing = format!("{},{}", info.spacing.horizontal, info.spacing.vertical);
+        entry(writer, "spacing", Value::String(&spacing))?;
+        entry(writer, "outline", Value::Integer(&info.outline))?;
+        writeln!(writer, "\t</dict>")?;
+        Ok(())
+    }
+
+    fn common<W: io::Write>(&mut self, writer: &mut W, common: &Common) -> crate::Result<()> {
+        writeln!(writer, "\t<key>common</key>")?;
+        writeln!(writer, "\t<dict>")?;
+        entry(writer, "lineHeight", Value::Integer(&common.line_height))?;
+        entry(writer, "base", Value::Integer(&common.base))?;
+        entry(writer, "scaleW", Value::Integer(&common.scale_w))?;
+        entry(writer, "scaleH", Value::Integer(&common.scale_h))?;
+        entry(writer, "pages", Value::Integer(&common.pages))?;
+        entry(writer, "packed", Value::Bool(common.packed))?;
+        entry(writer, "alphaChnl", Value::Integer(&(common.alpha_chnl as u8)))?;
+        entry(writer, "redChnl", Value::Integer(&(common.red_chnl as u8)))?;
+        entry(writer, "greenChnl", Value::Integer(&(common.green_chnl as u8)))?;
+        entry(writer, "blueChnl", Value::Integer(&(common.blue_chnl as u8)))?;
+        writeln!(writer, "\t</dict>")?;
+        writeln!(writer, "\t<key>pages</key>")?;
+        writeln!(writer, "\t<array>")?;
+        Ok(())
+    }
+
+    fn page<W: io::Write>(&mut self, writer: &mut W, id: u32, file: &str) -> crate::Result<()> {
+        let file = escape("page file", file)?;
+        inline_dict(writer, &[("id", Value::Integer(&id)), ("file", Value::String(&file))])
+    }
+
+    fn chars<W: io::Write>(&mut self, writer: &mut W, _: u32) -> crate::Result<()> {
+        writeln!(writer, "\t</array>")?;
+        writeln!(writer, "\t<key>chars</key>")?;
+        writeln!(writer, "\t<array>")?;
+        Ok(())
+    }
+
+    fn char<W: io::Write>(&mut self, writer: &mut W, char: &Char) -> crate::Result<()> {
+        inline_dict(
+            writer,
+            &[
+                ("id", Value::Integer(&char.id)),
+                ("x", Value::Integer(&char.x)),
+                ("y", Value::Integer(&char.y)),
+                ("width", Value::Integer(&char.width)),
+                ("height", Value::Integer(&char.height)),
+                ("xoffset", Value::Integer(&char.xoffset)),
+                ("yoffset", Value::Integer(&char.yoffset)),
+                ("xadvance", Value::Integer(&char.xadvance)),
+                ("page", Value::Integer(&char.page)),
+                ("chnl", Value::Integer(&u8::from(char.chnl))),
+            ],
+        )
+    }
+
+    fn kernings<W: io::Write>(&mut self, writer: &mut W, _: u32) -> crate::Result<()> {
+        writeln!(writer, "\t</array>")?;
+        writeln!(writer, "\t<key>kernings</key>")?;
+        writeln!(writer, "\t<array>")?;
+        Ok(())
+    }
+
+    fn kerning<W: io::Write>(&mut self, writer: &mut W, kerning: &Kerning) -> crate::Result<()> {
+        inline_dict(
+            writer,
+            &[
+                ("first", Value::Integer(&kerning.first)),
+                ("second", Value::Integer(&kerning.second)),
+                ("amount", Value::Integer(&kerning.amount)),
+            ],
+        )
+    }
+
+    fn finish<W: io::Write>(&mut self, writer: &mut W) -> crate::Result<()> {
+        writeln!(writer, "\t</array>")?;
+        writeln!(writer, "</dict>")?;
+        writeln!(writer, "</plist>")?;
+        Ok(())
+    }
+}
+
+/// Plist value element.
+enum Value<'a> {
+    Integer(&'a dyn Display),
+    String(&'a str),
+    Bool(bool),
+}
+
+impl Value<'_> {
+    fn store<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        match self {
+            Value::Integer(u) => write!(writer, "<integer>{}</integer>", u),
+            Value::String(u) => write!(writer, "<string>{}</string>", u),
+            Value::Bool(true) => write!(writer, "<true/>"),
+            Value::Bool(false) => write!(writer, "<false/>"),
+        }
+    }
+}
+
+fn entry<W: io::Write>(writer: &mut W, key: &str, value: Value) -> crate::Result<()> {
+    writeln!(writer, "\t\t<key>{}</key>", key)?;
+    write!(writer, "\t\t")?;
+    value.store(writer)?;
+    writeln!(writer)?;
+    Ok(())
+}
+
+fn inline_dict<W: io::Write>(writer: &mut W, entries: &[(&str, Value)]) -> crate::Result<()> {
+    write!(writer, "\t\t<dict>")?;
+    for (key, value) in entries {
+        write!(writer, "<key>{}</key>", key)?;
+        value.store(writer)?;
+    }
+    writeln!(writer, "</dict>")?;
+    Ok(())
+}
+
+fn escape(path: &str, value: &str) -> crate::Result<String> {
+    let mut dst = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '<' => dst.push_str("&lt;"),
+            '>' => dst.push_str("&gt;"),
+            '&' => dst.push_str("&amp;"),
+            '\x00'..='\x1F' | '\x7F' => {
+                return Err(crate::Error::UnsupportedValueEncoding {
+                    path: path.to_owned(),
+                    value: value.to_owned(),
+                })
+            }
+            _ => dst.push(c),
+        }
+    }
+    Ok(dst)
+}
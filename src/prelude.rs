@@ -0,0 +1,7 @@
+//! Crate prelude.
+//!
+//! The `alloc` types that the `std` prelude would otherwise provide, allowing `no_std` builds.
+pub use alloc::borrow::ToOwned;
+pub use alloc::format;
+pub use alloc::string::{String, ToString};
+pub use alloc::vec::Vec;
@@ -0,0 +1,106 @@
+use crate::encoding::TextEncoding;
+use crate::font::Font;
+use crate::prelude::*;
+
+use core::fmt;
+
+/// Font load report.
+///
+/// The loaded [Font] along with any non-fatal [Warning]s raised during the load.
+///
+/// # Example
+///
+/// ```
+/// use bmfont_rs::{LoadSettings, Rounding};
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let src = "info face=\"\" size=0 bold=0 italic=0 charset=\"\" unicode=0 stretchH=0 \
+///                smooth=0 aa=0 padding=0,0,0,0 spacing=0,0 outline=0\n\
+///                common lineHeight=16.5 base=0 scaleW=0 scaleH=0 pages=0 packed=0\n";
+///     let settings = LoadSettings::default().round_floats(Rounding::Nearest);
+///     let report = bmfont_rs::text::from_str_report(src, &settings)?;
+///     assert_eq!(report.font.common.line_height, 17);
+///     assert_eq!(report.warnings.len(), 1);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct LoadReport {
+    /// The loaded font.
+    pub font: Font,
+    /// The source text encoding. Formats other than text report [TextEncoding::Utf8].
+    pub encoding: TextEncoding,
+    /// Warnings, in the order they were raised.
+    pub warnings: Vec<Warning>,
+    /// The [Phaser](crate::XmlDialect::Phaser) XML `<info>` `letterSpacing`, if present. This
+    /// attribute has no BMFont equivalent. Other formats and dialects report `None`.
+    pub letter_spacing: Option<i16>,
+}
+
+impl LoadReport {
+    /// Construct a new LoadReport.
+    #[inline(always)]
+    pub fn new(font: Font, encoding: TextEncoding, warnings: Vec<Warning>) -> Self {
+        Self { font, encoding, warnings, letter_spacing: None }
+    }
+}
+
+/// Load warnings.
+///
+/// Describes the non-fatal adjustments made when loading partially broken/ non-compliant BMFont
+/// files with lenient [LoadSettings](crate::LoadSettings).
+///
+/// The list of variants may change over time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Warning {
+    /// Numeric value adjusted, e.g. a rounded float or a clamped out of range value.
+    NumericAdjusted {
+        /// Line number, if known.
+        line: Option<usize>,
+        /// Key.
+        key: String,
+        /// Source value.
+        value: String,
+        /// Adjusted value.
+        adjusted: String,
+    },
+    /// String value transcoded from a legacy encoding, as it was not valid UTF-8.
+    Transcoded {
+        /// Line number, if known.
+        line: Option<usize>,
+        /// Key.
+        key: String,
+        /// Source encoding.
+        encoding: TextEncoding,
+    },
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Warning::NumericAdjusted { line, key, value, adjusted } => {
+                write!(
+                    f,
+                    "{}numeric value adjusted: {}: '{}' -> '{}'",
+                    format_line(line),
+                    key,
+                    value,
+                    adjusted
+                )
+            }
+            Warning::Transcoded { line, key, encoding } => {
+                write!(f, "{}string value transcoded: {}: {:?}", format_line(line), key, encoding)
+            }
+        }
+    }
+}
+
+fn format_line(line: &Option<usize>) -> String {
+    if let Some(line) = line {
+        format!("line: {}: ", line)
+    } else {
+        "".to_owned()
+    }
+}
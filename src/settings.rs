@@ -1,8 +1,19 @@
+use crate::encoding::LegacyEncoding;
+
 /// Font import behavior settings.
 ///
 /// This struct specifies Font import behavior, allowing us to import certain partially
 /// broken/ non-compliant BMFont files.
 ///
+/// The lenient numeric settings: `allow_hex`, `clamp_out_of_range`, `negative_unsigned` and
+/// `round_floats` apply to the text and XML formats. Adjusted values are reported as
+/// [Warning](crate::Warning)s by the `from_xxx_report` load methods.
+///
+/// The encoding settings: `detect_encoding` and `fallback_encoding` apply to the text format.
+///
+/// The `text_dialect` setting applies to the text format and the `xml_dialect` setting to the
+/// XML format.
+///
 /// # Example
 ///
 /// ```no_run
@@ -23,10 +34,30 @@
 pub struct LoadSettings {
     /// Allow String control characters.
     pub allow_string_control_characters: bool,
+    /// Allow hexadecimal `0x` prefixed integer values.
+    pub allow_hex: bool,
+    /// Clamp out of range integer values to the field bounds.
+    pub clamp_out_of_range: bool,
+    /// Detect and transcode UTF-8 BOM and UTF-16 encoded text.
+    pub detect_encoding: bool,
+    /// Duplicate character resolution policy.
+    pub duplicate_chars: DuplicateCharPolicy,
+    /// Duplicate kerning pair resolution policy.
+    pub duplicate_kernings: DuplicateKerningPolicy,
+    /// Decode string values that are not valid UTF-8 with the specified legacy encoding.
+    pub fallback_encoding: Option<LegacyEncoding>,
     /// Ignore incorrect character and kerning counts.
     pub ignore_counts: bool,
     /// Ignore invalid tags.
     pub ignore_invalid_tags: bool,
+    /// Negative values in unsigned integer fields.
+    pub negative_unsigned: Option<NegativeUnsigned>,
+    /// Accept floating point values in integer fields, rounded as specified.
+    pub round_floats: Option<Rounding>,
+    /// Text dialect.
+    pub text_dialect: TextDialect,
+    /// XML dialect.
+    pub xml_dialect: XmlDialect,
 }
 
 impl LoadSettings {
@@ -51,4 +82,247 @@ impl LoadSettings {
         self.allow_string_control_characters = true;
         self
     }
+
+    /// Set allow_hex to true. Returns self.
+    pub fn allow_hex(mut self) -> Self {
+        self.allow_hex = true;
+        self
+    }
+
+    /// Set clamp_out_of_range to true. Returns self.
+    pub fn clamp_out_of_range(mut self) -> Self {
+        self.clamp_out_of_range = true;
+        self
+    }
+
+    /// Set detect_encoding to true. Returns self.
+    pub fn detect_encoding(mut self) -> Self {
+        self.detect_encoding = true;
+        self
+    }
+
+    /// Set fallback_encoding. Returns self.
+    pub fn fallback_encoding(mut self, encoding: LegacyEncoding) -> Self {
+        self.fallback_encoding = Some(encoding);
+        self
+    }
+
+    /// Set negative_unsigned. Returns self.
+    pub fn negative_unsigned(mut self, mode: NegativeUnsigned) -> Self {
+        self.negative_unsigned = Some(mode);
+        self
+    }
+
+    /// Set round_floats. Returns self.
+    pub fn round_floats(mut self, rounding: Rounding) -> Self {
+        self.round_floats = Some(rounding);
+        self
+    }
+
+    /// Set duplicate_chars. Returns self.
+    pub fn duplicate_chars(mut self, policy: DuplicateCharPolicy) -> Self {
+        self.duplicate_chars = policy;
+        self
+    }
+
+    /// Set duplicate_kernings. Returns self.
+    pub fn duplicate_kernings(mut self, policy: DuplicateKerningPolicy) -> Self {
+        self.duplicate_kernings = policy;
+        self
+    }
+
+    /// Set text_dialect. Returns self.
+    pub fn text_dialect(mut self, dialect: TextDialect) -> Self {
+        self.text_dialect = dialect;
+        self
+    }
+
+    /// Set xml_dialect. Returns self.
+    pub fn xml_dialect(mut self, dialect: XmlDialect) -> Self {
+        self.xml_dialect = dialect;
+        self
+    }
+}
+
+/// Font export behavior settings.
+///
+/// The `text_dialect` setting applies to the text format and the `xml_dialect` setting to the
+/// XML format.
+#[derive(Debug, Default, Clone, Copy)]
+#[non_exhaustive]
+pub struct StoreSettings {
+    /// Text dialect.
+    pub text_dialect: TextDialect,
+    /// XML dialect.
+    pub xml_dialect: XmlDialect,
+}
+
+impl StoreSettings {
+    /// Set text_dialect. Returns self.
+    pub fn text_dialect(mut self, dialect: TextDialect) -> Self {
+        self.text_dialect = dialect;
+        self
+    }
+
+    /// Set xml_dialect. Returns self.
+    pub fn xml_dialect(mut self, dialect: XmlDialect) -> Self {
+        self.xml_dialect = dialect;
+        self
+    }
+}
+
+/// BMFont text dialect.
+///
+/// * `BmFont`: AngelCode BMFont.
+/// * `Hiero`: libGDX Hiero. The `padding` values are ordered up, left, down, right. On load,
+///   character and kerning pair counts are not verified, as Hiero may omit or misstate them, and
+///   `page` attributes other than `id` and `file` are discarded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TextDialect {
+    /// AngelCode BMFont.
+    BmFont,
+    /// libGDX Hiero.
+    Hiero,
+}
+
+impl Default for TextDialect {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::BmFont
+    }
+}
+
+/// BMFont XML dialect.
+///
+/// Game frameworks read and emit BMFont XML with small differences:
+///
+/// | Dialect    | `<?xml?>` declaration | `<chars>` count | `<kernings>` count | `letterSpacing` |
+/// |------------|-----------------------|-----------------|--------------------|-----------------|
+/// | `BmFont`   | yes                   | yes             | yes                | no              |
+/// | `Sparrow`  | no                    | yes             | yes                | no              |
+/// | `Starling` | yes                   | yes             | no                 | no              |
+/// | `Phaser`   | yes                   | no              | no                 | `<info>`        |
+///
+/// On load, the declaration is always optional and a count that the dialect omits is optional,
+/// if present it is still verified. The `letterSpacing` attribute has no BMFont equivalent, on
+/// load it is accepted and discarded, on store it is written as `0`. The loaded value is available
+/// through the [LoadReport](crate::LoadReport) of the `xml::from_xxx_report` methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum XmlDialect {
+    /// AngelCode BMFont.
+    BmFont,
+    /// Sparrow.
+    Sparrow,
+    /// Starling.
+    Starling,
+    /// Phaser.
+    Phaser,
+}
+
+#[cfg(feature = "xml")]
+impl XmlDialect {
+    /// The dialect emits the `<?xml?>` declaration.
+    pub(crate) fn declaration(self) -> bool {
+        self != Self::Sparrow
+    }
+
+    /// The dialect emits the `<chars>` count.
+    pub(crate) fn char_count(self) -> bool {
+        self != Self::Phaser
+    }
+
+    /// The dialect emits the `<kernings>` count.
+    pub(crate) fn kerning_count(self) -> bool {
+        !matches!(self, Self::Starling | Self::Phaser)
+    }
+
+    /// The dialect emits the `<info>` `letterSpacing` attribute.
+    pub(crate) fn letter_spacing(self) -> bool {
+        self == Self::Phaser
+    }
+}
+
+impl Default for XmlDialect {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::BmFont
+    }
+}
+
+/// Duplicate character resolution policy.
+///
+/// Characters are considered duplicates if they share the same id.
+/// Resolved characters retain the position of the first occurrence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateCharPolicy {
+    /// Retain all characters, duplicates included.
+    KeepAll,
+    /// Throw a [DuplicateChar](crate::Error::DuplicateChar) error.
+    Error,
+    /// Retain the first character.
+    FirstWins,
+    /// Retain the last character.
+    LastWins,
+}
+
+impl Default for DuplicateCharPolicy {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::KeepAll
+    }
+}
+
+/// Duplicate kerning pair resolution policy.
+///
+/// Kerning pairs are considered duplicates if they share the same first and second character ids.
+/// Resolved kerning pairs retain the position of the first occurrence.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DuplicateKerningPolicy {
+    /// Retain all kerning pairs, duplicates included.
+    KeepAll,
+    /// Throw a [DuplicateKerningPair](crate::Error::DuplicateKerningPair) error.
+    Error,
+    /// Retain the first kerning pair.
+    FirstWins,
+    /// Retain the last kerning pair.
+    LastWins,
+    /// Sum the kerning amounts, saturating at the `i16` bounds.
+    Sum,
+}
+
+impl Default for DuplicateKerningPolicy {
+    #[inline(always)]
+    fn default() -> Self {
+        Self::KeepAll
+    }
+}
+
+/// Floating point to integer rounding mode.
+///
+/// Used by [LoadSettings::round_floats] to accept floating point values, e.g. `xoffset=1.5`, in
+/// integer fields.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Rounding {
+    /// Round to the nearest integer, half way cases away from zero.
+    Nearest,
+    /// Round toward negative infinity.
+    Down,
+    /// Round toward positive infinity.
+    Up,
+    /// Round toward zero.
+    TowardZero,
+}
+
+/// Negative value handling for unsigned integer fields.
+///
+/// Used by [LoadSettings::negative_unsigned] to accept negative values, e.g. `xadvance=-1` in an
+/// unsigned field.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NegativeUnsigned {
+    /// Two's complement wrap, e.g. `-1` as `u16` is `65535`.
+    Wrap,
+    /// Clamp to zero.
+    Clamp,
 }
@@ -0,0 +1,206 @@
+extern crate roxmltree as xml;
+
+use crate::prelude::*;
+
+use core::str::FromStr;
+use std::io;
+
+use super::model::{Abc, Glyph, Rect, SpriteFont};
+use super::ENTITY;
+
+/// Load sprite font XML.
+///
+/// Load a sprite font from the specified XNA intermediate XML [str].
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let src = fs::read_to_string("font.xml")?;
+///     let sprite_font = bmfont_rs::spritefont::from_str(&src)?;
+///     let font = sprite_font.to_font()?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+pub fn from_str(src: &str) -> crate::Result<SpriteFont> {
+    let document = xml::Document::parse(src).map_err(|e| parse_err(None, e.to_string()))?;
+    let root = document.root_element();
+    check_tag_name(&document, &root, "XnaContent")?;
+    let mut assets = root.children().filter(xml::Node::is_element);
+    let asset = match (assets.next(), assets.next()) {
+        (Some(asset), None) => asset,
+        _ => return Err(parse_err(None, "expected a single Asset".to_owned())),
+    };
+    check_tag_name(&document, &asset, "Asset")?;
+    if asset.attribute("Type").and_then(|u| u.rsplit(':').next()) != Some("SpriteFontContent") {
+        return Err(parse_err(line(&document, &asset), "expected SpriteFontContent".to_owned()));
+    }
+    let mut sprite_font = SpriteFont::default();
+    let mut bounds = Vec::default();
+    let mut cropping = Vec::default();
+    let mut characters = Vec::default();
+    let mut kerning = Vec::default();
+    for node in asset.children().filter(xml::Node::is_element) {
+        let text = node.text().unwrap_or_default();
+        match node.tag_name().name() {
+            "Texture" => sprite_font.texture = text.to_owned(),
+            "Glyphs" => bounds = items(&document, &node, rect)?,
+            "Cropping" => cropping = items(&document, &node, rect)?,
+            "CharacterMap" => characters = items(&document, &node, character)?,
+            "LineSpacing" => sprite_font.line_spacing = value(&document, &node, text.trim())?,
+            "Spacing" => sprite_font.spacing = value(&document, &node, text.trim())?,
+            "Kerning" => kerning = items(&document, &node, abc)?,
+            "DefaultCharacter" => {
+                sprite_font.default_character =
+                    Some(character(text).map_err(|err| parse_err(line(&document, &node), err))?)
+            }
+            tag => {
+                return Err(crate::Error::InvalidTag {
+                    line: line(&document, &node),
+                    tag: tag.to_owned(),
+                })
+            }
+        }
+    }
+    let len = characters.len();
+    if bounds.len() != len || cropping.len() != len || kerning.len() != len {
+        return Err(parse_err(
+            None,
+            format!(
+                "item count mismatch: Glyphs: {}, Cropping: {}, CharacterMap: {}, Kerning: {}",
+                bounds.len(),
+                cropping.len(),
+                len,
+                kerning.len()
+            ),
+        ));
+    }
+    sprite_font.glyphs = characters
+        .into_iter()
+        .zip(bounds)
+        .zip(cropping)
+        .zip(kerning)
+        .map(|(((character, bounds), cropping), kerning)| Glyph {
+            character,
+            bounds,
+            cropping,
+            kerning,
+        })
+        .collect();
+    Ok(sprite_font)
+}
+
+/// Load sprite font XML.
+///
+/// Load a sprite font from the specified XNA intermediate XML byte slice.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+pub fn from_bytes(bytes: &[u8]) -> crate::Result<SpriteFont> {
+    from_str(std::str::from_utf8(bytes).map_err(|e| parse_err(None, e.to_string()))?)
+}
+
+/// Read sprite font XML.
+///
+/// Read a sprite font from the specified XNA intermediate XML reader.
+/// This method buffers data internally, a buffered reader is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let f = File::open("font.xml")?;
+///     let sprite_font = bmfont_rs::spritefont::from_reader(f)?;
+///     println!("{:?}", sprite_font);
+///     Ok(())
+/// }
+/// ```
+pub fn from_reader<R: io::Read>(mut reader: R) -> crate::Result<SpriteFont> {
+    let mut vec = Vec::default();
+    reader.read_to_end(&mut vec)?;
+    from_bytes(&vec)
+}
+
+fn items<T, F>(document: &xml::Document, node: &xml::Node, mut parse: F) -> crate::Result<Vec<T>>
+where
+    F: FnMut(&str) -> Result<T, String>,
+{
+    node.children()
+        .filter(xml::Node::is_element)
+        .map(|item| {
+            check_tag_name(document, &item, "Item")?;
+            parse(item.text().unwrap_or_default())
+                .map_err(|err| parse_err(line(document, &item), err))
+        })
+        .collect()
+}
+
+fn value<T: FromStr>(document: &xml::Document, node: &xml::Node, text: &str) -> crate::Result<T> {
+    text.parse().map_err(|_| parse_err(line(document, node), format!("invalid value: {:?}", text)))
+}
+
+fn rect(text: &str) -> Result<Rect, String> {
+    let [x, y, width, height] = fields(text)?;
+    Ok(Rect { x, y, width, height })
+}
+
+fn abc(text: &str) -> Result<Abc, String> {
+    let [a, b, c] = fields(text)?;
+    Ok(Abc { a, b, c })
+}
+
+fn fields<T: FromStr + Default + Copy, const N: usize>(text: &str) -> Result<[T; N], String> {
+    let mut array = [T::default(); N];
+    let mut parts = text.split_whitespace();
+    for v in array.iter_mut() {
+        *v = parts
+            .next()
+            .and_then(|u| u.parse().ok())
+            .ok_or_else(|| format!("invalid item: {:?}", text))?;
+    }
+    match parts.next() {
+        Some(_) => Err(format!("invalid item: {:?}", text)),
+        None => Ok(array),
+    }
+}
+
+fn character(text: &str) -> Result<char, String> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) => Ok(c),
+        _ => Err(format!("invalid character: {:?}", text)),
+    }
+}
+
+fn check_tag_name(document: &xml::Document, node: &xml::Node, tag_name: &str) -> crate::Result<()> {
+    if node.tag_name().name() == tag_name {
+        Ok(())
+    } else {
+        Err(crate::Error::InvalidTag {
+            line: line(document, node),
+            tag: node.tag_name().name().to_owned(),
+        })
+    }
+}
+
+fn line(document: &xml::Document, node: &xml::Node) -> Option<usize> {
+    Some(document.text_pos_at(node.range().start).row as usize)
+}
+
+fn parse_err(line: Option<usize>, err: String) -> crate::Error {
+    crate::Error::Parse { line, entity: ENTITY.to_owned(), err }
+}
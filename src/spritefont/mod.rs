@@ -0,0 +1,52 @@
+//! XNA/ MonoGame sprite font operations.
+//!
+//! Convert between a [Font] and the XNA/ MonoGame sprite font content model, [SpriteFont], and
+//! load/ store that model as XNA intermediate XML:
+//!
+//! ```xml
+//! <?xml version="1.0" encoding="utf-8"?>
+//! <XnaContent xmlns:Graphics="Microsoft.Xna.Framework.Content.Pipeline.Graphics">
+//!   <Asset Type="Graphics:SpriteFontContent">
+//!     <Texture>font_0.png</Texture>
+//!     <Glyphs><Item>0 0 8 12</Item></Glyphs>
+//!     <Cropping><Item>0 4 8 16</Item></Cropping>
+//!     <CharacterMap><Item>A</Item></CharacterMap>
+//!     <LineSpacing>16</LineSpacing>
+//!     <Spacing>0</Spacing>
+//!     <Kerning><Item>1 8 1</Item></Kerning>
+//!   </Asset>
+//! </XnaContent>
+//! ```
+//!
+//! Loading requires: `--features xml`.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::fs;
+//!
+//! fn main() -> bmfont_rs::Result<()> {
+//!     let font = bmfont_rs::text::from_bytes(&fs::read("font.fnt")?)?;
+//!     let sprite_font = bmfont_rs::spritefont::SpriteFont::from_font(&font)?;
+//!     fs::write("font.xml", bmfont_rs::spritefont::to_string(&sprite_font)?)?;
+//!     Ok(())
+//! }
+//! ```
+//!
+//! [Font]: crate::Font
+
+#[cfg(feature = "xml")]
+mod load;
+mod model;
+mod store;
+
+pub use model::{Abc, Glyph, Rect, SpriteFont};
+pub use store::{to_string, to_vec};
+
+#[cfg(feature = "xml")]
+pub use load::{from_bytes, from_reader, from_str};
+#[cfg(feature = "std")]
+pub use store::to_writer;
+
+/// Sprite font XML entity.
+const ENTITY: &str = "spritefont";
@@ -0,0 +1,204 @@
+use crate::charset::Charset;
+use crate::convert::int;
+use crate::font::{Char, Chnl, Common, Font, Info};
+use crate::prelude::*;
+
+/// XNA/ MonoGame sprite font.
+///
+/// The sprite font content model: a single texture with per glyph bounds, cropping and A/B/C
+/// kerning widths.
+///
+/// # Example
+///
+/// ```
+/// fn main() -> bmfont_rs::Result<()> {
+///     let mut font = bmfont_rs::Font::default();
+///     font.pages.push("font_0.png".to_owned());
+///     font.chars.push(bmfont_rs::Char { id: 65, width: 8, xoffset: 1, xadvance: 10, ..Default::default() });
+///     let sprite_font = bmfont_rs::spritefont::SpriteFont::from_font(&font)?;
+///     let kerning = sprite_font.glyphs[0].kerning;
+///     assert_eq!((kerning.a, kerning.b, kerning.c), (1.0, 8.0, 1.0));
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SpriteFont {
+    /// The texture file.
+    pub texture: String,
+    /// Glyphs, sorted by character.
+    pub glyphs: Vec<Glyph>,
+    /// The distance from one line of text to the next.
+    pub line_spacing: i32,
+    /// Additional spacing between characters.
+    pub spacing: f32,
+    /// The character substituted for characters that are not present.
+    pub default_character: Option<char>,
+}
+
+/// Sprite font glyph.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Glyph {
+    /// The character.
+    pub character: char,
+    /// The glyph image rectangle in the texture.
+    pub bounds: Rect,
+    /// The glyph cropping rectangle, the x/ y components offset the image when drawn.
+    pub cropping: Rect,
+    /// A/B/C kerning widths.
+    pub kerning: Abc,
+}
+
+/// Integer rectangle.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Rect {
+    /// The left edge.
+    pub x: i32,
+    /// The top edge.
+    pub y: i32,
+    /// The width.
+    pub width: i32,
+    /// The height.
+    pub height: i32,
+}
+
+impl Rect {
+    /// Construct a new Rect.
+    #[inline(always)]
+    pub const fn new(x: i32, y: i32, width: i32, height: i32) -> Self {
+        Self { x, y, width, height }
+    }
+}
+
+/// A/B/C kerning widths.
+///
+/// The advance for a glyph is the sum of the three widths.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Abc {
+    /// The space before the glyph image.
+    pub a: f32,
+    /// The glyph image width.
+    pub b: f32,
+    /// The space after the glyph image.
+    pub c: f32,
+}
+
+impl Abc {
+    /// Construct a new Abc.
+    #[inline(always)]
+    pub const fn new(a: f32, b: f32, c: f32) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl SpriteFont {
+    /// Convert from a [Font].
+    ///
+    /// For each character:
+    /// * bounds: `x`, `y`, `width`, `height`
+    /// * cropping: `0`, `yoffset`, `width`, `lineHeight`
+    /// * kerning: A `xoffset`, B `width`, C `xadvance - xoffset - width`
+    ///
+    /// The texture is the first page. Kerning pairs have no sprite font equivalent and are not
+    /// retained. Duplicate characters are removed, the first occurrence is retained.
+    ///
+    /// # Errors
+    ///
+    /// * [Error](crate::Error) if the font has multiple pages or a character id is not a valid
+    ///   Unicode scalar value or is an XML 1.0 restricted control character.
+    pub fn from_font(font: &Font) -> crate::Result<Self> {
+        if font.pages.len() > 1 || font.chars.iter().any(|u| u.page != 0) {
+            return Err(crate::Error::UnsupportedEncoding {
+                line: None,
+                entity: super::ENTITY.to_owned(),
+                err: "multiple pages are not supported".to_owned(),
+            });
+        }
+        let line_spacing = font.common.line_height as i32;
+        let mut chars = font.chars.clone();
+        chars.sort_by_key(|u| u.id);
+        chars.dedup_by_key(|u| u.id);
+        let glyphs = chars
+            .iter()
+            .map(|u| Glyph::from_char(u, line_spacing))
+            .collect::<crate::Result<_>>()?;
+        Ok(Self {
+            texture: font.pages.first().cloned().unwrap_or_default(),
+            glyphs,
+            line_spacing,
+            spacing: 0.0,
+            default_character: None,
+        })
+    }
+
+    /// Convert to a [Font].
+    ///
+    /// For each glyph:
+    /// * `x`, `y`, `width`, `height`: bounds
+    /// * `xoffset`: A + cropping x
+    /// * `yoffset`: cropping y
+    /// * `xadvance`: A + B + C
+    ///
+    /// The line spacing maps onto the `lineHeight` and the texture onto the single page. The
+    /// `base`, other common fields and the info fields have no sprite font equivalent and take
+    /// their default values, Unicode is set. The spacing and default character are not retained.
+    ///
+    /// # Errors
+    ///
+    /// * [Error](crate::Error) if a value is out of range or a kerning width is not integral.
+    pub fn to_font(&self) -> crate::Result<Font> {
+        let line_height = int("line spacing", self.line_spacing as i64)?;
+        let chars = self.glyphs.iter().map(|&u| u.to_char()).collect::<crate::Result<_>>()?;
+        let info = Info { charset: Charset::Null, unicode: true, ..Default::default() };
+        let common = Common { line_height, pages: 1, ..Default::default() };
+        Ok(Font::new(info, common, alloc::vec![self.texture.clone()], chars, Vec::default()))
+    }
+}
+
+impl Glyph {
+    fn from_char(char: &Char, line_spacing: i32) -> crate::Result<Self> {
+        let character = core::char::from_u32(char.id)
+            .filter(|&u| !super::store::is_restricted(u))
+            .ok_or_else(|| crate::Error::UnsupportedValueEncoding {
+                path: "char id".to_owned(),
+                value: char.id.to_string(),
+            })?;
+        let (width, xoffset) = (char.width as i32, char.xoffset as i32);
+        Ok(Self {
+            character,
+            bounds: Rect::new(char.x as i32, char.y as i32, width, char.height as i32),
+            cropping: Rect::new(0, char.yoffset as i32, width, line_spacing),
+            kerning: Abc::new(
+                xoffset as f32,
+                width as f32,
+                (char.xadvance as i32 - xoffset - width) as f32,
+            ),
+        })
+    }
+
+    fn to_char(self) -> crate::Result<Char> {
+        let path = |field: &str| format!("glyph {:?} {}", self.character, field);
+        let Abc { a, b, c } = self.kerning;
+        Ok(Char {
+            id: self.character as u32,
+            x: int(&path("bounds"), self.bounds.x as i64)?,
+            y: int(&path("bounds"), self.bounds.y as i64)?,
+            width: int(&path("bounds"), self.bounds.width as i64)?,
+            height: int(&path("bounds"), self.bounds.height as i64)?,
+            xoffset: int(&path("kerning"), whole(&path("kerning"), a)? + self.cropping.x as i64)?,
+            yoffset: int(&path("cropping"), self.cropping.y as i64)?,
+            xadvance: int(&path("kerning"), whole(&path("kerning"), a + b + c)?)?,
+            page: 0,
+            chnl: Chnl::ALL,
+        })
+    }
+}
+
+/// Integral float conversion, without loss.
+fn whole(path: &str, v: f32) -> crate::Result<i64> {
+    let i = v as i64;
+    if i as f32 == v {
+        Ok(i)
+    } else {
+        Err(crate::Error::UnsupportedValueEncoding { path: path.to_owned(), value: v.to_string() })
+    }
+}
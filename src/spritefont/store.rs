@@ -0,0 +1,174 @@
+use crate::prelude::*;
+
+use core::fmt::{self, Write};
+
+#[cfg(feature = "std")]
+use std::io;
+
+use super::model::{Rect, SpriteFont};
+
+/// Store sprite font XML.
+///
+/// Store a sprite font into a [String] as XNA intermediate XML.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```
+/// fn main() -> bmfont_rs::Result<()> {
+///     let sprite_font = bmfont_rs::spritefont::SpriteFont::default();
+///     let string = bmfont_rs::spritefont::to_string(&sprite_font)?;
+///     assert!(string.contains("<Asset Type=\"Graphics:SpriteFontContent\">"));
+///     Ok(())
+/// }
+/// ```
+pub fn to_string(sprite_font: &SpriteFont) -> crate::Result<String> {
+    check(sprite_font)?;
+    let mut dst = String::default();
+    store(&mut dst, sprite_font).map_err(|e| crate::Error::Internal { err: e.to_string() })?;
+    Ok(dst)
+}
+
+/// Store sprite font XML.
+///
+/// Store a sprite font into a [Vec] as XNA intermediate XML.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+pub fn to_vec(sprite_font: &SpriteFont) -> crate::Result<Vec<u8>> {
+    to_string(sprite_font).map(String::into_bytes)
+}
+
+/// Write sprite font XML.
+///
+/// Write a sprite font to the specified writer as XNA intermediate XML.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let sprite_font = bmfont_rs::spritefont::SpriteFont::default();
+///     let mut writer = File::create("font.xml")?;
+///     bmfont_rs::spritefont::to_writer(&mut writer, &sprite_font)?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn to_writer<W: io::Write>(mut writer: W, sprite_font: &SpriteFont) -> crate::Result<()> {
+    let string = to_string(sprite_font)?;
+    writer.write_all(string.as_bytes())?;
+    Ok(())
+}
+
+/// Check for characters that XML 1.0 cannot represent.
+fn check(sprite_font: &SpriteFont) -> crate::Result<()> {
+    check_value("texture", &sprite_font.texture)?;
+    for glyph in &sprite_font.glyphs {
+        check_value("glyph character", glyph.character.encode_utf8(&mut [0; 4]))?;
+    }
+    if let Some(c) = sprite_font.default_character {
+        check_value("default character", c.encode_utf8(&mut [0; 4]))?;
+    }
+    Ok(())
+}
+
+fn check_value(path: &str, value: &str) -> crate::Result<()> {
+    if value.chars().any(is_restricted) {
+        Err(crate::Error::UnsupportedValueEncoding {
+            path: path.to_owned(),
+            value: value.to_owned(),
+        })
+    } else {
+        Ok(())
+    }
+}
+
+/// XML 1.0 restricted control characters, these are not permitted even as numeric references.
+pub(super) fn is_restricted(c: char) -> bool {
+    matches!(c, '\x00'..='\x08' | '\x0B' | '\x0C' | '\x0E'..='\x1F')
+}
+
+fn store(dst: &mut String, sprite_font: &SpriteFont) -> fmt::Result {
+    let glyphs = &sprite_font.glyphs;
+    writeln!(dst, "<?xml version=\"1.0\" encoding=\"utf-8\"?>")?;
+    writeln!(
+        dst,
+        "<XnaContent xmlns:Graphics=\"Microsoft.Xna.Framework.Content.Pipeline.Graphics\">"
+    )?;
+    writeln!(dst, "  <Asset Type=\"Graphics:SpriteFontContent\">")?;
+    writeln!(dst, "    <Texture>{}</Texture>", Escape(&sprite_font.texture))?;
+    items(dst, "Glyphs", glyphs.iter().map(|u| RectItem(u.bounds)))?;
+    items(dst, "Cropping", glyphs.iter().map(|u| RectItem(u.cropping)))?;
+    items(dst, "CharacterMap", glyphs.iter().map(|u| CharItem(u.character)))?;
+    writeln!(dst, "    <LineSpacing>{}</LineSpacing>", sprite_font.line_spacing)?;
+    writeln!(dst, "    <Spacing>{}</Spacing>", sprite_font.spacing)?;
+    items(
+        dst,
+        "Kerning",
+        glyphs.iter().map(|u| format!("{} {} {}", u.kerning.a, u.kerning.b, u.kerning.c)),
+    )?;
+    if let Some(c) = sprite_font.default_character {
+        writeln!(dst, "    <DefaultCharacter>{}</DefaultCharacter>", CharItem(c))?;
+    }
+    writeln!(dst, "  </Asset>")?;
+    writeln!(dst, "</XnaContent>")
+}
+
+fn items<T, I>(dst: &mut String, tag: &str, items: I) -> fmt::Result
+where
+    T: fmt::Display,
+    I: Iterator<Item = T>,
+{
+    writeln!(dst, "    <{}>", tag)?;
+    for item in items {
+        writeln!(dst, "      <Item>{}</Item>", item)?;
+    }
+    writeln!(dst, "    </{}>", tag)
+}
+
+/// Rectangle item, space separated.
+struct RectItem(Rect);
+
+impl fmt::Display for RectItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} {} {} {}", self.0.x, self.0.y, self.0.width, self.0.height)
+    }
+}
+
+/// Character item, escaped.
+struct CharItem(char);
+
+impl fmt::Display for CharItem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        Escape(self.0.encode_utf8(&mut [0; 4])).fmt(f)
+    }
+}
+
+/// XML text escape, permitted control characters as numeric references.
+struct Escape<'a>(&'a str);
+
+impl fmt::Display for Escape<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for c in self.0.chars() {
+            match c {
+                '&' => f.write_str("&amp;")?,
+                '<' => f.write_str("&lt;")?,
+                '>' => f.write_str("&gt;")?,
+                c if c.is_control() => write!(f, "&#x{:X};", c as u32)?,
+                c => f.write_char(c)?,
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,93 @@
+use crate::font::{Char, Common, Font, Info, Kerning};
+use crate::prelude::*;
+
+/// Static bitmap font descriptor.
+///
+/// A font descriptor defined entirely by `&'static` data, as emitted by the `codegen` module,
+/// `--features codegen`. Intended for embedded targets where the font is converted at build time
+/// and resides in flash, no parsing or allocation takes place at runtime.
+///
+/// Characters are sorted by id and kerning pairs by their first/ second character ids, without
+/// duplicates, lookups are binary searches. Of the info block, only the face and size are
+/// retained.
+///
+/// # Example
+///
+/// ```
+/// use bmfont_rs::{Char, Common, Kerning, StaticFont};
+///
+/// static CHARS: [Char; 2] = [
+///     Char::new(65, 0, 0, 8, 8, 0, 0, 9, 0, bmfont_rs::Chnl::ALL),
+///     Char::new(86, 8, 0, 8, 8, 0, 0, 9, 0, bmfont_rs::Chnl::ALL),
+/// ];
+/// static KERNINGS: [Kerning; 1] = [Kerning::new(65, 86, -1)];
+/// static FONT: StaticFont = StaticFont::new("Ace", 8, Common::new(
+///     10, 8, 16, 8, 1, false,
+///     bmfont_rs::Packing::Glyph,
+///     bmfont_rs::Packing::Glyph,
+///     bmfont_rs::Packing::Glyph,
+///     bmfont_rs::Packing::Glyph,
+/// ), &["ace_0.png"], &CHARS, &KERNINGS);
+///
+/// assert_eq!(FONT.char('A' as u32).map(|u| u.xadvance), Some(9));
+/// assert_eq!(FONT.kerning('A' as u32, 'V' as u32), -1);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaticFont {
+    /// The name of the true type font.
+    pub face: &'static str,
+    /// The size of the true type font.
+    pub size: i16,
+    /// Common block.
+    pub common: Common,
+    /// Page file names, the index corresponds to the page id.
+    pub pages: &'static [&'static str],
+    /// Characters, sorted by id.
+    pub chars: &'static [Char],
+    /// Kerning pairs, sorted by first/ second character ids.
+    pub kernings: &'static [Kerning],
+}
+
+impl StaticFont {
+    /// Construct a new StaticFont.
+    ///
+    /// N.B. The supplied arguments are not validated. Characters must be sorted by id and kerning
+    /// pairs by first/ second character ids, otherwise lookups will fail.
+    #[inline(always)]
+    pub const fn new(
+        face: &'static str,
+        size: i16,
+        common: Common,
+        pages: &'static [&'static str],
+        chars: &'static [Char],
+        kernings: &'static [Kerning],
+    ) -> Self {
+        Self { face, size, common, pages, chars, kernings }
+    }
+
+    /// Returns the character with the specified id, or None if not present.
+    pub fn char(&self, id: u32) -> Option<&'static Char> {
+        let chars = self.chars;
+        chars.binary_search_by_key(&id, |u| u.id).ok().map(|i| &chars[i])
+    }
+
+    /// Returns the kerning amount for the specified character pair, or zero if not present.
+    pub fn kerning(&self, first: u32, second: u32) -> i16 {
+        self.kernings
+            .binary_search_by_key(&(first, second), |u| (u.first, u.second))
+            .map(|i| self.kernings[i].amount)
+            .unwrap_or_default()
+    }
+
+    /// Convert to an owned [Font]. Info fields other than the face and size take their default
+    /// values.
+    pub fn to_font(&self) -> Font {
+        Font::new(
+            Info { face: self.face.to_owned(), size: self.size, ..Default::default() },
+            self.common,
+            self.pages.iter().map(|&u| u.to_owned()).collect(),
+            self.chars.to_vec(),
+            self.kernings.to_vec(),
+        )
+    }
+}
@@ -1,4 +1,4 @@
-use std::fmt;
+use core::fmt;
 
 const CR: u8 = b'\r';
 const EQ: u8 = b'=';
@@ -227,7 +227,7 @@ impl<'a> TaggedAttributes<'a> {
     }
 }
 
-pub type Result<T> = std::result::Result<T, Error>;
+pub type Result<T> = core::result::Result<T, Error>;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Error {
@@ -248,6 +248,7 @@ impl fmt::Display for Error {
     }
 }
 
+#[cfg(feature = "std")]
 impl std::error::Error for Error {}
 
 #[cfg(test)]
@@ -1,12 +1,38 @@
+#[cfg(feature = "atlas")]
+use crate::atlas::{self, AtlasSettings, Naming};
 use crate::binary;
+#[cfg(feature = "bmfc")]
+use crate::bmfc;
+#[cfg(feature = "cbor")]
+use crate::cbor;
 use crate::charset::Charset;
 use crate::font::*;
+#[cfg(feature = "godot")]
+use crate::godot;
 #[cfg(feature = "json")]
 use crate::json;
+#[cfg(feature = "msgpack")]
+use crate::msgpack;
+#[cfg(feature = "plist")]
+use crate::plist;
+#[cfg(feature = "spritefont")]
+use crate::spritefont::{self, Abc, Rect, SpriteFont};
 use crate::text;
+#[cfg(feature = "textmeshpro")]
+use crate::textmeshpro;
+#[cfg(feature = "toml")]
+use crate::toml;
 #[cfg(feature = "xml")]
 use crate::xml;
-use crate::LoadSettings;
+#[cfg(feature = "yaml")]
+use crate::yaml;
+#[cfg(feature = "bmfc")]
+use crate::FieldChange;
+#[cfg(feature = "xml")]
+use crate::XmlDialect;
+use crate::{DuplicateCharPolicy, DuplicateKerningPolicy, LoadSettings};
+use crate::{LegacyEncoding, NegativeUnsigned, Rounding, TextEncoding, Warning};
+use crate::{StoreSettings, TextDialect};
 
 use std::error::Error;
 use std::result::Result;
@@ -222,6 +248,22 @@ fn serde_json_small_from_slice() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[cfg(feature = "json")]
+#[test]
+fn serde_json_small_native_bool() -> Result<(), Box<dyn Error>> {
+    let value = serde_json::to_value(small())?;
+    assert_eq!(value["info"]["unicode"], serde_json::Value::from(1));
+    assert_eq!(value["common"]["packed"], serde_json::Value::from(0));
+    let mut vec = Vec::default();
+    crate::native_bool::serialize(&small(), &mut serde_json::Serializer::new(&mut vec))?;
+    let value: serde_json::Value = serde_json::from_slice(&vec)?;
+    assert_eq!(value["info"]["unicode"], serde_json::Value::Bool(true));
+    assert_eq!(value["common"]["packed"], serde_json::Value::Bool(false));
+    let font = crate::native_bool::deserialize(&mut serde_json::Deserializer::from_slice(&vec))?;
+    assert_eq!(font, small());
+    Ok(())
+}
+
 #[cfg(feature = "json")]
 #[test]
 fn serde_json_small_to_vec() -> Result<(), Box<dyn Error>> {
@@ -379,6 +421,184 @@ fn json_small_to_string_pretty() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_small_from_str() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.yaml");
+    let font = yaml::from_str(src)?;
+    assert_eq!(font, small());
+    Ok(())
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_small_from_reader() -> Result<(), Box<dyn Error>> {
+    let src = include_bytes!("../../data/ok/small.yaml");
+    let font = yaml::from_reader(src.as_ref())?;
+    assert_eq!(font, small());
+    Ok(())
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_small_to_string() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.yaml");
+    assert_eq!(yaml::to_string(&small())?, src);
+    Ok(())
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_small_to_writer() -> Result<(), Box<dyn Error>> {
+    let mut vec: Vec<u8> = Vec::default();
+    yaml::to_writer(&mut vec, &small())?;
+    assert_eq!(vec, yaml::to_vec(&small())?);
+    assert_eq!(yaml::from_bytes(&vec)?, small());
+    Ok(())
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_medium_store_load() -> Result<(), Box<dyn Error>> {
+    let font = binary::from_bytes(include_bytes!("../../data/ok/medium.bin"))?;
+    assert_eq!(yaml::from_str(&yaml::to_string(&font)?)?, font);
+    Ok(())
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_empty_store_load() -> Result<(), Box<dyn Error>> {
+    let src = yaml::to_string(&Font::default())?;
+    assert_eq!(yaml::from_str(&src)?, Font::default());
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_small_from_str() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.toml");
+    let font = toml::from_str(src)?;
+    assert_eq!(font, small());
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_small_from_reader() -> Result<(), Box<dyn Error>> {
+    let src = include_bytes!("../../data/ok/small.toml");
+    let font = toml::from_reader(src.as_ref())?;
+    assert_eq!(font, small());
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_small_to_string() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.toml");
+    assert_eq!(toml::to_string(&small())?, src);
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_small_to_writer() -> Result<(), Box<dyn Error>> {
+    let mut vec: Vec<u8> = Vec::default();
+    toml::to_writer(&mut vec, &small())?;
+    assert_eq!(vec, toml::to_vec(&small())?);
+    assert_eq!(toml::from_bytes(&vec)?, small());
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_medium_store_load() -> Result<(), Box<dyn Error>> {
+    let font = binary::from_bytes(include_bytes!("../../data/ok/medium.bin"))?;
+    assert_eq!(toml::from_str(&toml::to_string(&font)?)?, font);
+    Ok(())
+}
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_empty_store_load() -> Result<(), Box<dyn Error>> {
+    let src = toml::to_string(&Font::default())?;
+    assert_eq!(toml::from_str(&src)?, Font::default());
+    Ok(())
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack_binary_medium_cmp() -> Result<(), Box<dyn Error>> {
+    let msgpack_src = include_bytes!("../../data/ok/medium.msgpack");
+    let msgpack_font = msgpack::from_bytes(msgpack_src)?;
+    let binary_src = include_bytes!("../../data/ok/medium.bin");
+    let binary_font = binary::from_bytes(binary_src)?;
+    assert_eq!(msgpack_font, binary_font);
+    Ok(())
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack_medium_to_vec() -> Result<(), Box<dyn Error>> {
+    let font = binary::from_bytes(include_bytes!("../../data/ok/medium.bin"))?;
+    assert_eq!(msgpack::to_vec(&font)?, include_bytes!("../../data/ok/medium.msgpack"));
+    Ok(())
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack_medium_round_trip() -> Result<(), Box<dyn Error>> {
+    let font = text::from_bytes(include_bytes!("../../data/ok/medium.txt"))?;
+    let mut vec: Vec<u8> = Vec::default();
+    msgpack::to_writer(&mut vec, &font)?;
+    assert_eq!(msgpack::from_reader(vec.as_slice())?, font);
+    Ok(())
+}
+
+#[cfg(feature = "msgpack")]
+#[test]
+fn msgpack_empty_store_load() -> Result<(), Box<dyn Error>> {
+    let vec = msgpack::to_vec(&Font::default())?;
+    assert_eq!(msgpack::from_bytes(&vec)?, Font::default());
+    Ok(())
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor_binary_medium_cmp() -> Result<(), Box<dyn Error>> {
+    let cbor_src = include_bytes!("../../data/ok/medium.cbor");
+    let cbor_font = cbor::from_bytes(cbor_src)?;
+    let binary_src = include_bytes!("../../data/ok/medium.bin");
+    let binary_font = binary::from_bytes(binary_src)?;
+    assert_eq!(cbor_font, binary_font);
+    Ok(())
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor_medium_to_vec() -> Result<(), Box<dyn Error>> {
+    let font = binary::from_bytes(include_bytes!("../../data/ok/medium.bin"))?;
+    assert_eq!(cbor::to_vec(&font)?, include_bytes!("../../data/ok/medium.cbor"));
+    Ok(())
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor_medium_round_trip() -> Result<(), Box<dyn Error>> {
+    let font = text::from_bytes(include_bytes!("../../data/ok/medium.txt"))?;
+    let mut vec: Vec<u8> = Vec::default();
+    cbor::to_writer(&mut vec, &font)?;
+    assert_eq!(cbor::from_reader(vec.as_slice())?, font);
+    Ok(())
+}
+
+#[cfg(feature = "cbor")]
+#[test]
+fn cbor_empty_store_load() -> Result<(), Box<dyn Error>> {
+    let vec = cbor::to_vec(&Font::default())?;
+    assert_eq!(cbor::from_bytes(&vec)?, Font::default());
+    Ok(())
+}
+
 #[test]
 fn text_binary_medium_cmp() -> Result<(), Box<dyn Error>> {
     let text_src = include_bytes!("../../data/ok/medium.txt");
@@ -483,6 +703,29 @@ fn validate_medium() -> crate::Result<()> {
     font.validate_references()
 }
 
+#[test]
+fn validation_errors() {
+    assert!(small().validation_errors().is_empty());
+    let mut font = small();
+    font.common.pages = 2;
+    font.chars[0].page = 1;
+    font.kernings.push(Kerning::new(98, 99, -1));
+    let id = font.chars[0].id;
+    assert!(matches!(
+        font.validation_errors().as_slice(),
+        [
+            crate::Error::InvalidPageCount { specified: 2, realized: 1 },
+            crate::Error::InvalidCharPage { char_id, page_id: 1 },
+            crate::Error::InvalidKerningChar { id: 98 },
+            crate::Error::InvalidKerningChar { id: 99 },
+        ] if *char_id == id
+    ));
+    assert!(matches!(
+        font.validate_references(),
+        Err(crate::Error::InvalidCharPage { char_id, page_id: 1 }) if char_id == id
+    ));
+}
+
 macro_rules! err {
     ($name:ident, $op:expr, $err:pat) => {
         #[test]
@@ -689,3 +932,1519 @@ fn load_settings_ignore_kerning_count() -> Result<(), Box<dyn Error>> {
     assert_eq!(text::from_bytes_ext(src, &settings)?, small());
     Ok(())
 }
+
+#[test]
+fn diff_small_null() {
+    assert!(small().diff(&small()).is_empty());
+}
+
+#[test]
+fn diff_small_reordered() {
+    let mut reordered = small();
+    reordered.chars.reverse();
+    reordered.kernings.reverse();
+    assert!(small().diff(&reordered).is_empty());
+}
+
+#[test]
+fn diff_small() {
+    let mut other = small();
+    other.info.face = "Other".to_owned();
+    other.common.line_height = 33;
+    other.pages[0] = "other_sheet_0.png".to_owned();
+    other.chars[0].x = 282;
+    other.chars[0].chnl = Chnl::BLUE;
+    other.chars.remove(1);
+    other.chars.push(Char { id: 65, ..Default::default() });
+    other.kernings[0].amount = -3;
+    other.kernings.remove(1);
+    other.kernings.push(Kerning::new(10, 65, 4));
+    let diff = small().diff(&other);
+    assert_eq!(diff.removed_chars, vec![small().chars[1]]);
+    assert_eq!(diff.added_kernings, vec![Kerning::new(10, 65, 4)]);
+    assert_eq!(
+        diff.to_string(),
+        "~ info face: \"Small Test\" -> \"Other\"\n\
+         ~ common lineHeight: 32 -> 33\n\
+         ~ page id=0 file: \"small_sheet_0.png\" -> \"other_sheet_0.png\"\n\
+         - char id=32\n\
+         + char id=65\n\
+         ~ char id=10: x: 281 -> 282, chnl: 15 -> 1\n\
+         - kerning first=32 second=10 amount=1\n\
+         + kerning first=10 second=65 amount=4\n\
+         ~ kerning first=10 second=32: amount: -2 -> -3\n"
+    );
+}
+
+#[test]
+fn normalize_small() {
+    let mut font = small();
+    font.chars.reverse();
+    font.chars.push(Char { id: 10, ..Default::default() });
+    font.kernings.reverse();
+    font.kernings.push(Kerning::new(32, 10, 5));
+    font.normalize();
+    assert_eq!(font, small());
+}
+
+#[test]
+fn semantically_eq_small() {
+    let mut font = small();
+    font.chars.reverse();
+    font.kernings.reverse();
+    assert_ne!(font, small());
+    assert!(font.semantically_eq(&small()));
+    font.kernings[0].amount += 1;
+    assert!(!font.semantically_eq(&small()));
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn normalize_medium_cycle() -> Result<(), Box<dyn Error>> {
+    let mut font = text::from_bytes(include_bytes!("../../data/ok/medium.txt"))?;
+    font.chars.reverse();
+    font.kernings.reverse();
+    let mut cycle = binary::from_bytes(&binary::to_vec(&font)?)?;
+    cycle = xml::from_bytes(&xml::to_vec(&cycle)?)?;
+    cycle = text::from_bytes(&text::to_vec(&cycle)?)?;
+    assert!(cycle.semantically_eq(&font));
+    font.normalize();
+    cycle.normalize();
+    assert_eq!(text::to_vec(&cycle)?, text::to_vec(&font)?);
+    Ok(())
+}
+
+err!(
+    text_duplicate_char,
+    text::from_bytes_ext(
+        include_bytes!("../../data/bad/duplicate_char.txt").as_ref(),
+        &LoadSettings::default().duplicate_chars(DuplicateCharPolicy::Error)
+    ),
+    crate::Error::DuplicateChar { line: Some(7), id: 32 }
+);
+
+err!(
+    text_duplicate_kerning,
+    text::from_bytes_ext(
+        include_bytes!("../../data/bad/duplicate_kerning.txt").as_ref(),
+        &LoadSettings::default().duplicate_kernings(DuplicateKerningPolicy::Error)
+    ),
+    crate::Error::DuplicateKerningPair { line: Some(10), first: 10, second: 32 }
+);
+
+#[test]
+fn load_settings_duplicate_keep_all() -> Result<(), Box<dyn Error>> {
+    let font = text::from_bytes(include_bytes!("../../data/bad/duplicate_char.txt"))?;
+    assert_eq!(font.chars.len(), 3);
+    let font = text::from_bytes(include_bytes!("../../data/bad/duplicate_kerning.txt"))?;
+    assert_eq!(font.kernings.len(), 3);
+    Ok(())
+}
+
+err!(
+    binary_duplicate_char,
+    {
+        let mut small = small();
+        small.chars.push(small.chars[0]);
+        binary::from_bytes_ext(
+            &binary::to_vec(&small).unwrap(),
+            &LoadSettings::default().duplicate_chars(DuplicateCharPolicy::Error),
+        )
+    },
+    crate::Error::DuplicateChar { id: 10, .. }
+);
+
+#[cfg(feature = "json")]
+err!(
+    json_duplicate_kerning,
+    {
+        let mut small = small();
+        small.kernings.push(small.kernings[0]);
+        json::from_str_ext(
+            &json::to_string(&small).unwrap(),
+            &LoadSettings::default().duplicate_kernings(DuplicateKerningPolicy::Error),
+        )
+    },
+    crate::Error::DuplicateKerningPair { first: 10, second: 32, .. }
+);
+
+#[cfg(feature = "yaml")]
+err!(
+    yaml_duplicate_char,
+    {
+        let mut small = small();
+        small.chars.push(small.chars[0]);
+        yaml::from_str_ext(
+            &yaml::to_string(&small).unwrap(),
+            &LoadSettings::default().duplicate_chars(DuplicateCharPolicy::Error),
+        )
+    },
+    crate::Error::DuplicateChar { id: 10, .. }
+);
+
+#[cfg(feature = "toml")]
+err!(
+    toml_duplicate_char,
+    {
+        let mut small = small();
+        small.chars.push(small.chars[0]);
+        toml::from_str_ext(
+            &toml::to_string(&small).unwrap(),
+            &LoadSettings::default().duplicate_chars(DuplicateCharPolicy::Error),
+        )
+    },
+    crate::Error::DuplicateChar { id: 10, .. }
+);
+
+#[cfg(feature = "toml")]
+#[test]
+fn toml_load_settings_duplicate_chars() -> Result<(), Box<dyn Error>> {
+    let mut font = small();
+    font.chars.push(Char { x: 1, ..font.chars[0] });
+    let src = toml::to_string(&font)?;
+    let settings = LoadSettings::default().duplicate_chars(DuplicateCharPolicy::LastWins);
+    font.chars.swap_remove(0);
+    assert_eq!(toml::from_str_ext(&src, &settings)?, font);
+    Ok(())
+}
+
+#[cfg(feature = "yaml")]
+#[test]
+fn yaml_load_settings_duplicate_kernings() -> Result<(), Box<dyn Error>> {
+    let mut font = small();
+    font.kernings.push(Kerning::new(10, 32, -3));
+    let src = yaml::to_string(&font)?;
+    let settings = LoadSettings::default().duplicate_kernings(DuplicateKerningPolicy::Sum);
+    font.kernings.pop();
+    font.kernings[0].amount = -5;
+    assert_eq!(yaml::from_str_ext(&src, &settings)?, font);
+    Ok(())
+}
+
+#[cfg(feature = "msgpack")]
+err!(
+    msgpack_duplicate_kerning,
+    {
+        let mut small = small();
+        small.kernings.push(small.kernings[0]);
+        msgpack::from_bytes_ext(
+            &msgpack::to_vec(&small).unwrap(),
+            &LoadSettings::default().duplicate_kernings(DuplicateKerningPolicy::Error),
+        )
+    },
+    crate::Error::DuplicateKerningPair { first: 10, second: 32, .. }
+);
+
+#[cfg(feature = "msgpack")]
+err!(
+    msgpack_truncated,
+    msgpack::from_bytes(&msgpack::to_vec(&small()).unwrap()[..64]),
+    crate::Error::Parse { .. }
+);
+
+#[cfg(feature = "cbor")]
+err!(
+    cbor_duplicate_kerning,
+    {
+        let mut small = small();
+        small.kernings.push(small.kernings[0]);
+        cbor::from_bytes_ext(
+            &cbor::to_vec(&small).unwrap(),
+            &LoadSettings::default().duplicate_kernings(DuplicateKerningPolicy::Error),
+        )
+    },
+    crate::Error::DuplicateKerningPair { first: 10, second: 32, .. }
+);
+
+#[cfg(feature = "cbor")]
+err!(
+    cbor_truncated,
+    cbor::from_bytes(&cbor::to_vec(&small()).unwrap()[..64]),
+    crate::Error::Parse { .. }
+);
+
+#[cfg(feature = "bmfc")]
+fn small_bmfc() -> bmfc::Config {
+    bmfc::Config {
+        font_name: "Small Test".to_owned(),
+        aa: 4,
+        padding: Padding { up: 1, right: 2, down: 3, left: 4 },
+        spacing: Spacing { horizontal: 5, vertical: 6 },
+        outline_thickness: 7,
+        out_width: 1024,
+        out_height: 2048,
+        out_bit_depth: 32,
+        alpha_chnl: Packing::Glyph,
+        red_chnl: Packing::GlyphOutline,
+        green_chnl: Packing::One,
+        blue_chnl: Packing::Zero,
+        chars: vec![10..=10, 32..=126],
+        icons: vec![bmfc::Icon::new("icons/star.png".to_owned(), 0xE000, 0, 2, 20)],
+        ..Default::default()
+    }
+}
+
+#[cfg(feature = "bmfc")]
+#[test]
+fn bmfc_small_from_str() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.bmfc");
+    assert_eq!(bmfc::from_str(src)?, small_bmfc());
+    Ok(())
+}
+
+#[cfg(feature = "bmfc")]
+#[test]
+fn bmfc_small_to_string() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.bmfc");
+    assert_eq!(bmfc::to_string(&small_bmfc())?, src);
+    Ok(())
+}
+
+#[cfg(feature = "bmfc")]
+#[test]
+fn bmfc_small_info_common() {
+    let config = small_bmfc();
+    assert_eq!(config.info(), small().info);
+    let common = Common { line_height: 32, base: 24, pages: 1, ..config.common() };
+    assert_eq!(common, small().common);
+}
+
+#[cfg(feature = "bmfc")]
+#[test]
+fn bmfc_small_check() {
+    assert!(small_bmfc().check(&small()).is_empty());
+}
+
+#[cfg(feature = "bmfc")]
+#[test]
+fn bmfc_small_check_mismatch() {
+    let mut config = small_bmfc();
+    config.is_bold = true;
+    config.out_bit_depth = 8;
+    config.four_chnl_packed = true;
+    config.chars = vec![32..=126];
+    config.dont_include_kerning_pairs = true;
+    let mut font = small();
+    font.common.packed = true;
+    let diff = config.check(&font);
+    assert_eq!(diff.info, vec![FieldChange::new("bold", "1".to_owned(), "0".to_owned())]);
+    assert_eq!(diff.common, vec![FieldChange::new("packed", "0".to_owned(), "1".to_owned())]);
+    assert_eq!(diff.added_chars, vec![small().chars[0]]);
+    assert_eq!(diff.added_kernings, small().kernings);
+}
+
+#[cfg(feature = "bmfc")]
+err!(
+    bmfc_invalid_value,
+    bmfc::from_str("fontName=A\nfontSize=big\n"),
+    crate::Error::Parse { line: Some(2), .. }
+);
+
+#[cfg(feature = "bmfc")]
+err!(
+    bmfc_unsupported_value,
+    bmfc::to_string(&bmfc::Config { font_name: "A\nB".to_owned(), ..Default::default() }),
+    crate::Error::UnsupportedValueEncoding { .. }
+);
+
+/// Font as retained by the Godot format.
+#[cfg(feature = "godot")]
+fn godot_retained(font: &Font) -> Font {
+    let info = Info {
+        face: font.info.face.clone(),
+        size: font.info.size,
+        charset: Charset::Null,
+        unicode: true,
+        ..Default::default()
+    };
+    let common = Common {
+        line_height: font.common.line_height,
+        base: font.common.base,
+        pages: font.common.pages,
+        ..Default::default()
+    };
+    let chars = font.chars.iter().map(|&u| Char { chnl: Chnl::ALL, ..u }).collect();
+    let mut font = Font::new(info, common, font.pages.clone(), chars, font.kernings.clone());
+    font.normalize();
+    font
+}
+
+#[cfg(feature = "godot")]
+#[test]
+fn godot_small_to_string() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.tres");
+    assert_eq!(godot::to_string(&small())?, src);
+    Ok(())
+}
+
+#[cfg(feature = "godot")]
+#[test]
+fn godot_small_from_str() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.tres");
+    assert_eq!(godot::from_str(src)?, godot_retained(&small()));
+    Ok(())
+}
+
+#[cfg(feature = "godot")]
+#[test]
+fn godot_medium_store_load() -> Result<(), Box<dyn Error>> {
+    let font = binary::from_bytes(include_bytes!("../../data/ok/medium.bin"))?;
+    let mut godot = godot::from_bytes(&godot::to_vec(&font)?)?;
+    godot.normalize();
+    assert_eq!(godot, godot_retained(&font));
+    Ok(())
+}
+
+#[cfg(feature = "godot")]
+err!(
+    godot_packed,
+    godot::to_string(&Font { common: Common { packed: true, ..small().common }, ..small() }),
+    crate::Error::UnsupportedEncoding { .. }
+);
+
+#[cfg(feature = "godot")]
+err!(
+    godot_type,
+    godot::from_str("[gd_resource type=\"Theme\" format=3]\n"),
+    crate::Error::Parse { line: Some(1), .. }
+);
+
+#[cfg(feature = "godot")]
+err!(
+    godot_broken_page_list,
+    godot::from_str(
+        &include_str!("../../data/ok/small.tres").replace("textures/0/image", "textures/1/image")
+    ),
+    crate::Error::BrokenPageList
+);
+
+#[cfg(feature = "plist")]
+#[test]
+fn plist_small_from_str() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.plist");
+    assert_eq!(plist::from_str(src)?, small());
+    assert_eq!(plist::from_reader(src.as_bytes())?, small());
+    Ok(())
+}
+
+#[cfg(feature = "plist")]
+#[test]
+fn plist_small_to_string() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.plist");
+    assert_eq!(plist::to_string(&small())?, src);
+    Ok(())
+}
+
+#[cfg(feature = "plist")]
+#[test]
+fn plist_medium_store_load() -> Result<(), Box<dyn Error>> {
+    let font = binary::from_bytes(include_bytes!("../../data/ok/medium.bin"))?;
+    assert_eq!(plist::from_bytes(&plist::to_vec(&font)?)?, font);
+    assert_eq!(plist::from_str(&plist::to_string(&Font::default())?)?, Font::default());
+    Ok(())
+}
+
+#[cfg(feature = "plist")]
+#[test]
+fn plist_integer_booleans() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.plist")
+        .replacen("<true/>", "<integer>1</integer>", 1)
+        .replacen("<false/>", "<integer>0</integer>", 1);
+    assert_eq!(plist::from_str(&src)?, small());
+    Ok(())
+}
+
+#[cfg(feature = "plist")]
+#[test]
+fn plist_lenient() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.plist").replace(
+        "<key>lineHeight</key>\n\t\t<integer>32</integer>",
+        "<key>lineHeight</key>\n\t\t<integer>99999</integer>",
+    );
+    let settings = LoadSettings::default().clamp_out_of_range();
+    let report = plist::from_str_report(&src, &settings)?;
+    let mut font = small();
+    font.common.line_height = u16::MAX;
+    assert_eq!(report.font, font);
+    assert_eq!(
+        report.warnings,
+        vec![Warning::NumericAdjusted {
+            line: Some(35),
+            key: "lineHeight".to_owned(),
+            value: "99999".to_owned(),
+            adjusted: "65535".to_owned(),
+        }]
+    );
+    Ok(())
+}
+
+#[cfg(feature = "plist")]
+#[test]
+fn plist_load_settings_ignore_invalid_tags() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.plist").replace(
+        "<key>pages</key>\n\t<array>",
+        "<key>frames</key>\n\t<dict/>\n\t<key>pages</key>\n\t<array>",
+    );
+    assert!(matches!(plist::from_str(&src), Err(crate::Error::InvalidTag { .. })));
+    let settings = LoadSettings::default().ignore_invalid_tags();
+    assert_eq!(plist::from_str_ext(&src, &settings)?, small());
+    Ok(())
+}
+
+#[cfg(feature = "plist")]
+err!(
+    plist_invalid_value,
+    plist::from_str(
+        &include_str!("../../data/ok/small.plist").replace(
+            "<integer>4</integer>\n\t\t<key>padding",
+            "<real>4.5</real>\n\t\t<key>padding"
+        )
+    ),
+    crate::Error::Parse { line: Some(24), .. }
+);
+
+#[cfg(feature = "plist")]
+err!(
+    plist_data_value_type,
+    plist::from_str(
+        &include_str!("../../data/ok/small.plist")
+            .replace("<string>5,6</string>", "<data>AA==</data>")
+    ),
+    crate::Error::Parse { line: Some(28), .. }
+);
+
+#[cfg(feature = "plist")]
+err!(
+    plist_key_without_value,
+    plist::from_str(
+        &include_str!("../../data/ok/small.plist").replace("\t\t<integer>7</integer>\n", "")
+    ),
+    crate::Error::Parse { .. }
+);
+
+#[cfg(feature = "plist")]
+err!(
+    plist_broken_page_list,
+    plist::from_str(&include_str!("../../data/ok/small.plist").replace(
+        "<key>id</key><integer>0</integer><key>file",
+        "<key>id</key><integer>1</integer><key>file"
+    )),
+    crate::Error::BrokenPageList
+);
+
+#[cfg(feature = "plist")]
+err!(
+    plist_control_character,
+    plist::to_string(&Font { info: Info { face: "A\nB".to_owned(), ..small().info }, ..small() }),
+    crate::Error::UnsupportedValueEncoding { .. }
+);
+
+#[cfg(feature = "atlas")]
+#[test]
+fn atlas_small_to_string() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.atlas.json");
+    assert_eq!(atlas::to_string(&small(), 0, &AtlasSettings::default())?, src);
+    Ok(())
+}
+
+#[cfg(all(feature = "atlas", feature = "json"))]
+#[test]
+fn atlas_medium_to_strings() -> Result<(), Box<dyn Error>> {
+    let font = binary::from_bytes(include_bytes!("../../data/ok/medium.bin"))?;
+    let settings = AtlasSettings::default().naming(Naming::Decimal);
+    let atlases = atlas::to_strings(&font, &settings)?;
+    assert_eq!(atlases.len(), font.pages.len());
+    for (page, atlas) in atlases.iter().enumerate() {
+        let value: serde_json::Value = serde_json::from_str(atlas)?;
+        assert_eq!(value["meta"]["image"], font.pages[page].as_str());
+        let frames = value["frames"].as_object().unwrap();
+        let chars = font.chars.iter().filter(|u| u.page as usize == page).collect::<Vec<_>>();
+        assert_eq!(frames.len(), chars.len());
+        for char in chars {
+            let frame = &frames[&char.id.to_string()];
+            assert_eq!(frame["frame"]["x"], char.x);
+            assert_eq!(frame["frame"]["y"], char.y);
+            assert_eq!(frame["spriteSourceSize"]["w"], char.width);
+            assert_eq!(frame["spriteSourceSize"]["h"], char.height);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(feature = "atlas")]
+#[test]
+fn atlas_negative_offsets() -> Result<(), Box<dyn Error>> {
+    let mut font = small();
+    font.chars = vec![Char {
+        id: 106,
+        width: 6,
+        height: 30,
+        xoffset: -2,
+        yoffset: -1,
+        xadvance: 5,
+        ..Default::default()
+    }];
+    let atlas = atlas::to_string(&font, 0, &AtlasSettings::default())?;
+    assert!(atlas.contains("\"spriteSourceSize\": {\"x\":0,\"y\":0,\"w\":6,\"h\":30},\n"));
+    assert!(atlas.contains("\"sourceSize\": {\"w\":7,\"h\":33}\n"));
+    Ok(())
+}
+
+#[cfg(feature = "atlas")]
+#[test]
+fn atlas_naming() {
+    let settings = AtlasSettings::default();
+    assert_eq!(settings.name(0x41), "U+0041");
+    assert_eq!(settings.name(0x1F600), "U+1F600");
+    let settings = AtlasSettings::default().naming(Naming::Character).prefix("font/");
+    assert_eq!(settings.name(0x41), "font/A");
+    assert_eq!(settings.name(0x0A), "font/U+000A");
+    assert_eq!(settings.name(0xD800), "font/U+D800");
+}
+
+#[cfg(feature = "atlas")]
+#[test]
+fn atlas_escape() -> Result<(), Box<dyn Error>> {
+    let mut font = small();
+    font.chars[0].id = 34;
+    font.pages[0] = "a\\b.png".to_owned();
+    let atlas = atlas::to_string(&font, 0, &AtlasSettings::default().naming(Naming::Character))?;
+    assert!(atlas.contains("\"\\\"\":\n"));
+    assert!(atlas.contains("\"image\": \"a\\\\b.png\","));
+    Ok(())
+}
+
+#[cfg(feature = "atlas")]
+err!(
+    atlas_packed,
+    atlas::to_string(
+        &Font { common: Common { packed: true, ..small().common }, ..small() },
+        0,
+        &AtlasSettings::default()
+    ),
+    crate::Error::UnsupportedEncoding { .. }
+);
+
+#[cfg(feature = "atlas")]
+err!(
+    atlas_page,
+    atlas::to_string(&small(), 1, &AtlasSettings::default()),
+    crate::Error::InvalidPage { id: 1 }
+);
+
+#[cfg(feature = "atlas")]
+err!(
+    atlas_duplicate_name,
+    {
+        let mut font = small();
+        font.chars[1].id = 10;
+        atlas::to_string(&font, 0, &AtlasSettings::default())
+    },
+    crate::Error::DuplicateFrameName { .. }
+);
+
+#[cfg(feature = "textmeshpro")]
+#[test]
+fn textmeshpro_small_to_string() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.textmeshpro.json");
+    assert_eq!(textmeshpro::to_string(&small())?, src);
+    Ok(())
+}
+
+#[cfg(all(feature = "textmeshpro", feature = "json"))]
+#[test]
+fn textmeshpro_medium_to_string() -> Result<(), Box<dyn Error>> {
+    let font = binary::from_bytes(include_bytes!("../../data/ok/medium.bin"))?;
+    let value: serde_json::Value = serde_json::from_str(&textmeshpro::to_string(&font)?)?;
+    assert_eq!(value["m_FaceInfo"]["m_FamilyName"], font.info.face.as_str());
+    assert_eq!(value["m_FaceInfo"]["m_AscentLine"], font.common.base);
+    let glyphs = value["m_GlyphTable"].as_array().unwrap();
+    let characters = value["m_CharacterTable"].as_array().unwrap();
+    assert_eq!(glyphs.len(), font.chars.len());
+    assert_eq!(characters.len(), font.chars.len());
+    for (index, char) in font.chars.iter().enumerate() {
+        assert_eq!(characters[index]["m_Unicode"], char.id);
+        assert_eq!(characters[index]["m_GlyphIndex"], index);
+        assert_eq!(glyphs[index]["m_Index"], index);
+        assert_eq!(glyphs[index]["m_GlyphRect"]["m_X"], char.x);
+        assert_eq!(
+            glyphs[index]["m_GlyphRect"]["m_Y"],
+            font.common.scale_h as i32 - char.y as i32 - char.height as i32
+        );
+        assert_eq!(glyphs[index]["m_Metrics"]["m_HorizontalAdvance"], char.xadvance);
+        assert_eq!(glyphs[index]["m_AtlasIndex"], char.page);
+    }
+    let pairs = value["m_FontFeatureTable"]["m_GlyphPairAdjustmentRecords"].as_array().unwrap();
+    assert_eq!(pairs.len(), font.kernings.len());
+    Ok(())
+}
+
+#[cfg(feature = "textmeshpro")]
+#[test]
+fn textmeshpro_face_info() -> Result<(), Box<dyn Error>> {
+    let mut font = small();
+    font.info.bold = true;
+    font.info.italic = true;
+    font.chars[0].id = 72;
+    font.chars[1].id = 120;
+    let string = textmeshpro::to_string(&font)?;
+    assert!(string.contains("\"m_StyleName\": \"Bold Italic\",\n"));
+    assert!(string.contains("\"m_CapLine\": 0,\n"));
+    assert!(string.contains("\"m_MeanLine\": 7,\n"));
+    assert!(string.contains("\"m_TabWidth\": 0\n"));
+    Ok(())
+}
+
+#[cfg(feature = "textmeshpro")]
+#[test]
+fn textmeshpro_absent_kerning() -> Result<(), Box<dyn Error>> {
+    let mut font = small();
+    font.kernings.push(Kerning::new(10, 65, 3));
+    let string = textmeshpro::to_string(&font)?;
+    assert_eq!(string.matches("\"m_FirstAdjustmentRecord\"").count(), 2);
+    Ok(())
+}
+
+#[cfg(feature = "textmeshpro")]
+err!(
+    textmeshpro_packed,
+    textmeshpro::to_string(&Font { common: Common { packed: true, ..small().common }, ..small() }),
+    crate::Error::UnsupportedEncoding { .. }
+);
+
+#[cfg(feature = "textmeshpro")]
+err!(
+    textmeshpro_duplicate_char,
+    {
+        let mut font = small();
+        font.chars[1].id = 10;
+        textmeshpro::to_string(&font)
+    },
+    crate::Error::DuplicateChar { line: None, id: 10 }
+);
+
+#[test]
+fn edit_subset() {
+    let mut font = small();
+    font.subset(&[32, 65].iter().copied().collect());
+    assert_eq!(font.chars, vec![small().chars[1]]);
+    assert_eq!(font.kernings, vec![]);
+    assert_eq!(font.pages, small().pages);
+}
+
+#[test]
+fn edit_merge() -> Result<(), Box<dyn Error>> {
+    let mut other = small();
+    other.pages = vec!["other_0.png".to_owned(), "small_sheet_0.png".to_owned()];
+    other.chars[0].id = 65;
+    other.chars[0].page = 0;
+    other.chars[1].page = 1;
+    other.kernings = vec![Kerning::new(65, 32, 3), Kerning::new(10, 32, 5)];
+    let mut font = small();
+    font.merge(&other)?;
+    assert_eq!(font.pages, vec!["small_sheet_0.png".to_owned(), "other_0.png".to_owned()]);
+    assert_eq!(font.common.pages, 2);
+    assert_eq!(font.chars.len(), 3);
+    assert_eq!(font.chars[2], Char { id: 65, page: 1, ..small().chars[0] });
+    assert_eq!(font.kernings.len(), 3);
+    assert_eq!(font.kernings[2], Kerning::new(65, 32, 3));
+    font.validate_references()?;
+    Ok(())
+}
+
+err!(
+    edit_merge_incongruent,
+    {
+        let mut other = small();
+        other.common.scale_w = 512;
+        small().merge(&other)
+    },
+    crate::Error::IncongruentFonts { .. }
+);
+
+err!(
+    edit_merge_invalid_char_page,
+    {
+        let mut other = small();
+        other.chars[0].id = 65;
+        other.chars[0].page = 1;
+        small().merge(&other)
+    },
+    crate::Error::InvalidCharPage { char_id: 65, page_id: 1 }
+);
+
+#[test]
+fn edit_scale() -> Result<(), Box<dyn Error>> {
+    let mut font = small();
+    font.scale(0.5)?;
+    assert_eq!(font.info.size, 16);
+    assert_eq!(font.info.padding, Padding::new(1, 1, 2, 2));
+    assert_eq!(font.info.spacing, Spacing::new(3, 3));
+    assert_eq!(font.info.outline, 4);
+    assert_eq!((font.common.line_height, font.common.base), (16, 12));
+    assert_eq!((font.common.scale_w, font.common.scale_h), (512, 1024));
+    let char = font.chars[0];
+    assert_eq!((char.x, char.y, char.width, char.height), (141, 5, 2, 3));
+    assert_eq!((char.xoffset, char.yoffset, char.xadvance), (1, 12, 4));
+    let char = font.chars[1];
+    assert_eq!((char.x, char.y, char.width, char.height), (0, 0, 4, 10));
+    assert_eq!((char.xoffset, char.yoffset, char.xadvance), (2, 9, 5));
+    assert_eq!(font.kernings, vec![Kerning::new(10, 32, -1), Kerning::new(32, 10, 1)]);
+    Ok(())
+}
+
+#[test]
+fn edit_scale_round_trip() -> Result<(), Box<dyn Error>> {
+    let mut font = small();
+    font.scale(2.0)?;
+    font.scale(0.5)?;
+    assert_eq!(font, small());
+    Ok(())
+}
+
+#[test]
+fn edit_scale_range() {
+    let mut font = small();
+    match font.scale(64.0) {
+        Err(crate::Error::UnsupportedValueEncoding { path, .. }) => {
+            assert_eq!(path, "info padding")
+        }
+        _ => panic!("error expected"),
+    }
+    assert_eq!(font, small());
+}
+
+err!(edit_scale_zero, small().scale(0.0), crate::Error::UnsupportedValueEncoding { .. });
+
+err!(edit_scale_nan, small().scale(f64::NAN), crate::Error::UnsupportedValueEncoding { .. });
+
+#[test]
+fn edit_remap() -> Result<(), Box<dyn Error>> {
+    let mut font = small();
+    font.remap(&[(10, 65), (66, 67)].iter().copied().collect())?;
+    assert_eq!(font.chars.iter().map(|u| u.id).collect::<Vec<_>>(), vec![65, 32]);
+    assert_eq!(font.kernings, vec![Kerning::new(65, 32, -2), Kerning::new(32, 65, 1)]);
+    Ok(())
+}
+
+err!(
+    edit_remap_duplicate,
+    small().remap(&[(10, 32)].iter().copied().collect()),
+    crate::Error::DuplicateChar { id: 32, .. }
+);
+
+/// Font as retained by the sprite font model.
+#[cfg(feature = "spritefont")]
+fn spritefont_retained(font: &Font) -> Font {
+    let info = Info { charset: Charset::Null, unicode: true, ..Default::default() };
+    let common = Common { line_height: font.common.line_height, pages: 1, ..Default::default() };
+    let chars = font.chars.iter().map(|&u| Char { chnl: Chnl::ALL, ..u }).collect();
+    let mut font = Font::new(info, common, font.pages.clone(), chars, Vec::default());
+    font.normalize();
+    font
+}
+
+#[cfg(feature = "spritefont")]
+#[test]
+fn spritefont_small_from_font() -> Result<(), Box<dyn Error>> {
+    let sprite_font = SpriteFont::from_font(&small())?;
+    assert_eq!(sprite_font.texture, "small_sheet_0.png");
+    assert_eq!(sprite_font.line_spacing, 32);
+    let glyph = sprite_font.glyphs[1];
+    assert_eq!(glyph.character, ' ');
+    assert_eq!(glyph.bounds, Rect::new(0, 0, 7, 20));
+    assert_eq!(glyph.cropping, Rect::new(0, 17, 7, 32));
+    assert_eq!(glyph.kerning, Abc::new(4.0, 7.0, -2.0));
+    Ok(())
+}
+
+#[cfg(feature = "spritefont")]
+#[test]
+fn spritefont_small_to_font() -> Result<(), Box<dyn Error>> {
+    let font = SpriteFont::from_font(&small())?.to_font()?;
+    assert_eq!(font, spritefont_retained(&small()));
+    Ok(())
+}
+
+#[cfg(feature = "spritefont")]
+#[test]
+fn spritefont_small_to_string() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.spritefont.xml");
+    assert_eq!(spritefont::to_string(&SpriteFont::from_font(&small())?)?, src);
+    Ok(())
+}
+
+#[cfg(all(feature = "spritefont", feature = "xml"))]
+#[test]
+fn spritefont_small_from_str() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.spritefont.xml");
+    assert_eq!(spritefont::from_str(src)?, SpriteFont::from_font(&small())?);
+    Ok(())
+}
+
+#[cfg(all(feature = "spritefont", feature = "xml"))]
+#[test]
+fn spritefont_store_load() -> Result<(), Box<dyn Error>> {
+    let mut sprite_font = SpriteFont::from_font(&small())?;
+    sprite_font.texture = "a & <b>.png".to_owned();
+    sprite_font.spacing = 1.5;
+    sprite_font.default_character = Some('<');
+    sprite_font.glyphs[0].kerning.a = -0.5;
+    assert_eq!(spritefont::from_bytes(&spritefont::to_vec(&sprite_font)?)?, sprite_font);
+    Ok(())
+}
+
+#[cfg(feature = "spritefont")]
+err!(
+    spritefont_multiple_pages,
+    SpriteFont::from_font(&Font { pages: vec!["0.png".to_owned(), "1.png".to_owned()], ..small() }),
+    crate::Error::UnsupportedEncoding { .. }
+);
+
+#[cfg(feature = "spritefont")]
+err!(
+    spritefont_fractional_kerning,
+    {
+        let mut sprite_font = SpriteFont::from_font(&small()).unwrap();
+        sprite_font.glyphs[0].kerning.c = 0.5;
+        sprite_font.to_font()
+    },
+    crate::Error::UnsupportedValueEncoding { .. }
+);
+
+#[cfg(all(feature = "spritefont", feature = "xml"))]
+#[test]
+fn spritefont_store_load_control() -> Result<(), Box<dyn Error>> {
+    let mut sprite_font = SpriteFont::from_font(&small())?;
+    sprite_font.glyphs[0].character = '\t';
+    sprite_font.default_character = Some('\u{80}');
+    assert_eq!(spritefont::from_bytes(&spritefont::to_vec(&sprite_font)?)?, sprite_font);
+    Ok(())
+}
+
+#[cfg(feature = "spritefont")]
+err!(
+    spritefont_restricted_char_id,
+    SpriteFont::from_font(&Font { chars: vec![Char { id: 1, ..small().chars[0] }], ..small() }),
+    crate::Error::UnsupportedValueEncoding { .. }
+);
+
+#[cfg(feature = "spritefont")]
+err!(
+    spritefont_restricted_character,
+    {
+        let mut sprite_font = SpriteFont::from_font(&small()).unwrap();
+        sprite_font.glyphs[0].character = '\u{1}';
+        spritefont::to_string(&sprite_font)
+    },
+    crate::Error::UnsupportedValueEncoding { .. }
+);
+
+#[cfg(feature = "spritefont")]
+err!(
+    spritefont_restricted_texture,
+    spritefont::to_string(&SpriteFont { texture: "\x0B.png".to_owned(), ..Default::default() }),
+    crate::Error::UnsupportedValueEncoding { .. }
+);
+
+#[cfg(all(feature = "spritefont", feature = "xml"))]
+err!(
+    spritefont_item_count,
+    spritefont::from_str(&include_str!("../../data/ok/small.spritefont.xml").replacen(
+        "<Item>4 7 -2</Item>",
+        "",
+        1
+    )),
+    crate::Error::Parse { line: None, .. }
+);
+
+#[test]
+fn load_settings_duplicate_chars() -> Result<(), Box<dyn Error>> {
+    let src = include_bytes!("../../data/bad/duplicate_char.txt");
+    let settings = LoadSettings::default().duplicate_chars(DuplicateCharPolicy::FirstWins);
+    assert_eq!(text::from_bytes_ext(src, &settings)?, small());
+    let settings = LoadSettings::default().duplicate_chars(DuplicateCharPolicy::LastWins);
+    let mut font = small();
+    font.chars[1].x = 1;
+    font.chars[1].y = 1;
+    assert_eq!(text::from_bytes_ext(src, &settings)?, font);
+    Ok(())
+}
+
+#[test]
+fn load_settings_duplicate_kernings() -> Result<(), Box<dyn Error>> {
+    let src = include_bytes!("../../data/bad/duplicate_kerning.txt");
+    let mut font = small();
+    for (policy, amount) in [
+        (DuplicateKerningPolicy::FirstWins, -2),
+        (DuplicateKerningPolicy::LastWins, -3),
+        (DuplicateKerningPolicy::Sum, -5),
+    ] {
+        let settings = LoadSettings::default().duplicate_kernings(policy);
+        font.kernings[0].amount = amount;
+        assert_eq!(text::from_bytes_ext(src, &settings)?, font);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn xml_load_settings_duplicate_kernings() -> Result<(), Box<dyn Error>> {
+    let mut font = small();
+    font.kernings.push(Kerning::new(10, 32, -3));
+    let src = xml::to_string(&font)?;
+    let settings = LoadSettings::default().duplicate_kernings(DuplicateKerningPolicy::Sum);
+    font.kernings.pop();
+    font.kernings[0].amount = -5;
+    assert_eq!(xml::from_str_ext(&src, &settings)?, font);
+    Ok(())
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn xml_dialect_round_trip() -> Result<(), Box<dyn Error>> {
+    let font = binary::from_bytes(include_bytes!("../../data/ok/medium.bin"))?;
+    for dialect in
+        [XmlDialect::BmFont, XmlDialect::Sparrow, XmlDialect::Starling, XmlDialect::Phaser]
+    {
+        let src = xml::to_string_ext(&font, &StoreSettings::default().xml_dialect(dialect))?;
+        let settings = LoadSettings::default().xml_dialect(dialect);
+        assert_eq!(xml::from_str_ext(&src, &settings)?, font);
+    }
+    Ok(())
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn xml_dialect_store() -> Result<(), Box<dyn Error>> {
+    let bmfont = xml::to_string(&small())?;
+    let store =
+        |dialect| xml::to_string_ext(&small(), &StoreSettings::default().xml_dialect(dialect));
+    assert_eq!(store(XmlDialect::BmFont)?, bmfont);
+    assert_eq!(store(XmlDialect::Sparrow)?, bmfont.replace("<?xml version=\"1.0\"?>\n", ""));
+    assert_eq!(
+        store(XmlDialect::Starling)?,
+        bmfont.replace("<kernings count=\"2\">", "<kernings>")
+    );
+    assert_eq!(
+        store(XmlDialect::Phaser)?,
+        bmfont
+            .replace("outline=\"7\" />", "outline=\"7\" letterSpacing=\"0\" />")
+            .replace("<chars count=\"2\">", "<chars>")
+            .replace("<kernings count=\"2\">", "<kernings>")
+    );
+    Ok(())
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn xml_dialect_load() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.xml");
+    let phaser = src
+        .replace("outline=\"7\"", "outline=\"7\" letterSpacing=\"-1\"")
+        .replace("<chars count=\"2\">", "<chars>")
+        .replace("<kernings count=\"2\">", "<kernings>");
+    let settings = LoadSettings::default().xml_dialect(XmlDialect::Phaser);
+    assert_eq!(xml::from_str_ext(&phaser, &settings)?, small());
+    assert_eq!(xml::from_str_ext(src, &settings)?, small());
+    assert_eq!(xml::from_str_report(&phaser, &settings)?.letter_spacing, Some(-1));
+    assert_eq!(xml::from_str_report(src, &settings)?.letter_spacing, None);
+    assert!(matches!(xml::from_str(&phaser), Err(crate::Error::InvalidKey { .. })));
+    let starling = src.replace("<kernings count=\"2\">", "<kernings>");
+    let settings = LoadSettings::default().xml_dialect(XmlDialect::Starling);
+    assert_eq!(xml::from_str_ext(&starling, &settings)?, small());
+    assert!(matches!(xml::from_str(&starling), Err(crate::Error::InvalidKerningCount { .. })));
+    Ok(())
+}
+
+#[cfg(feature = "xml")]
+err!(
+    xml_dialect_count_verified,
+    xml::from_str_ext(
+        &include_str!("../../data/ok/small.xml")
+            .replace("<chars count=\"2\">", "<chars count=\"3\">"),
+        &LoadSettings::default().xml_dialect(XmlDialect::Phaser)
+    ),
+    crate::Error::InvalidCharCount { specified: 3, realized: 2 }
+);
+
+#[cfg(feature = "xml")]
+#[test]
+fn xml_dialect_letter_spacing_report() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.xml")
+        .replace("outline=\"7\"", "outline=\"7\" letterSpacing=\"1.6\"");
+    let settings =
+        LoadSettings::default().xml_dialect(XmlDialect::Phaser).round_floats(Rounding::Nearest);
+    let report = xml::from_str_report(&src, &settings)?;
+    assert_eq!(report.font, small());
+    assert_eq!(report.letter_spacing, Some(2));
+    assert!(matches!(
+        report.warnings.as_slice(),
+        [Warning::NumericAdjusted { key, .. }] if key == "letterSpacing"
+    ));
+    Ok(())
+}
+
+#[cfg(feature = "xml")]
+err!(
+    xml_dialect_letter_spacing,
+    xml::from_str_ext(
+        &include_str!("../../data/ok/small.xml")
+            .replace("outline=\"7\"", "outline=\"7\" letterSpacing=\"x\""),
+        &LoadSettings::default().xml_dialect(XmlDialect::Phaser)
+    ),
+    crate::Error::Parse { .. }
+);
+
+#[test]
+fn text_hiero_load() -> Result<(), Box<dyn Error>> {
+    let src = include_bytes!("../../data/bad/hiero.txt");
+    let settings = LoadSettings::default().text_dialect(TextDialect::Hiero);
+    assert_eq!(text::from_bytes_ext(src, &settings)?, small());
+    let font = text::from_bytes_ref_ext(src, &settings)?;
+    assert_eq!(font.info.padding, small().info.padding);
+    assert_eq!(font.pages, vec!["small_sheet_0.png"]);
+    assert!(text::from_bytes(src).is_err());
+    Ok(())
+}
+
+#[test]
+fn text_hiero_store() -> Result<(), Box<dyn Error>> {
+    let settings = StoreSettings::default().text_dialect(TextDialect::Hiero);
+    let src = include_str!("../../data/ok/small.txt").replace("padding=1,2,3,4", "padding=1,4,3,2");
+    assert_eq!(text::to_string_ext(&small(), &settings)?, src);
+    Ok(())
+}
+
+#[test]
+fn text_hiero_round_trip() -> Result<(), Box<dyn Error>> {
+    let font = binary::from_bytes(include_bytes!("../../data/ok/medium.bin"))?;
+    let font = Font { info: Info { padding: Padding::new(1, 2, 3, 4), ..font.info }, ..font };
+    let src = text::to_vec_ext(&font, &StoreSettings::default().text_dialect(TextDialect::Hiero))?;
+    let settings = LoadSettings::default().text_dialect(TextDialect::Hiero);
+    assert_eq!(text::from_bytes_ext(&src, &settings)?, font);
+    Ok(())
+}
+
+err!(
+    text_lenient_strict,
+    text::from_bytes(include_bytes!("../../data/bad/lenient.txt").as_ref()),
+    crate::Error::Parse { line: Some(5), .. }
+);
+
+#[test]
+fn text_lenient() -> Result<(), Box<dyn Error>> {
+    let src = include_bytes!("../../data/bad/lenient.txt");
+    let settings = LoadSettings::default()
+        .round_floats(Rounding::Nearest)
+        .negative_unsigned(NegativeUnsigned::Clamp)
+        .allow_hex();
+    let report = text::from_bytes_report(src, &settings)?;
+    assert_eq!(report.font, small());
+    let warning = |line: usize, key: &str, value: &str, adjusted: &str| Warning::NumericAdjusted {
+        line: Some(line),
+        key: key.to_owned(),
+        value: value.to_owned(),
+        adjusted: adjusted.to_owned(),
+    };
+    assert_eq!(
+        report.warnings,
+        vec![
+            warning(5, "x", "281.4", "281"),
+            warning(5, "y", "8.5", "9"),
+            warning(6, "x", "-3", "0")
+        ]
+    );
+    assert_eq!(report.warnings[2].to_string(), "line: 6: numeric value adjusted: x: '-3' -> '0'");
+    assert_eq!(text::from_bytes_ext(src, &settings)?, small());
+    Ok(())
+}
+
+#[cfg(feature = "xml")]
+#[test]
+fn xml_lenient() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.xml")
+        .replace("lineHeight=\"32\"", "lineHeight=\"99999\"");
+    let settings = LoadSettings::default().clamp_out_of_range();
+    let report = xml::from_str_report(&src, &settings)?;
+    let mut font = small();
+    font.common.line_height = u16::MAX;
+    assert_eq!(report.font, font);
+    assert_eq!(
+        report.warnings,
+        vec![Warning::NumericAdjusted {
+            line: None,
+            key: "lineHeight".to_owned(),
+            value: "99999".to_owned(),
+            adjusted: "65535".to_owned(),
+        }]
+    );
+    Ok(())
+}
+
+#[test]
+fn text_encoding_utf16() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.txt");
+    let settings = LoadSettings::default().detect_encoding();
+    for (bytes, encoding) in [
+        (format!("\u{FEFF}{}", src).into_bytes(), TextEncoding::Utf8Bom),
+        (src.encode_utf16().flat_map(u16::to_le_bytes).collect(), TextEncoding::Utf16Le),
+        (src.encode_utf16().flat_map(u16::to_be_bytes).collect(), TextEncoding::Utf16Be),
+        (
+            "\u{FEFF}"
+                .encode_utf16()
+                .chain(src.encode_utf16())
+                .flat_map(u16::to_le_bytes)
+                .collect(),
+            TextEncoding::Utf16Le,
+        ),
+    ] {
+        assert_eq!(text::detect_encoding(&bytes), encoding);
+        let report = text::from_bytes_report(&bytes, &settings)?;
+        assert_eq!(report.font, small());
+        assert_eq!(report.encoding, encoding);
+        assert!(report.warnings.is_empty());
+        assert!(text::from_bytes(&bytes).is_err());
+    }
+    Ok(())
+}
+
+#[test]
+fn text_encoding_fallback() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.txt").replace("Small Test", "Caf\u{1}");
+    let mut bytes = src.into_bytes();
+    let index = bytes.iter().position(|&u| u == 1).unwrap();
+    bytes[index] = 0xE9;
+    assert_eq!(text::detect_encoding(&bytes), TextEncoding::Windows1252);
+    assert!(text::from_bytes(&bytes).is_err());
+    let settings = LoadSettings::default().fallback_encoding(LegacyEncoding::Windows1252);
+    let report = text::from_bytes_report(&bytes, &settings)?;
+    let mut font = small();
+    font.info.face = "Café".to_owned();
+    assert_eq!(report.font, font);
+    assert_eq!(report.encoding, TextEncoding::Windows1252);
+    assert_eq!(
+        report.warnings,
+        vec![Warning::Transcoded {
+            line: Some(1),
+            key: "face".to_owned(),
+            encoding: TextEncoding::Windows1252
+        }]
+    );
+    assert_eq!(text::from_bytes_ext(&bytes, &settings)?, font);
+    Ok(())
+}
+
+#[test]
+fn binary_ref_small() -> Result<(), Box<dyn Error>> {
+    let src = binary::to_vec(&small())?;
+    let font = binary::from_bytes_ref(&src)?;
+    let range = src.as_ptr_range();
+    assert!(range.contains(&font.info.face.as_ptr()));
+    assert!(range.contains(&font.pages[0].as_ptr()));
+    assert_eq!(font.info.face, "Small Test");
+    assert_eq!(font.chars.len(), 2);
+    assert_eq!(font.chars.get(1).transpose()?, Some(small().chars[1]));
+    assert!(font.chars.get(2).is_none());
+    assert_eq!(font.kernings.iter().next_back().transpose()?, Some(small().kernings[1]));
+    assert_eq!(font.to_owned()?, small());
+    Ok(())
+}
+
+#[test]
+fn binary_ref_medium() -> Result<(), Box<dyn Error>> {
+    let src = include_bytes!("../../data/ok/medium.bin");
+    let font = binary::from_bytes_ref(src)?;
+    assert_eq!(font.chars.iter().len(), font.chars.len());
+    assert_eq!(font.to_owned()?, binary::from_bytes(src)?);
+    Ok(())
+}
+
+#[test]
+fn text_ref_small() -> Result<(), Box<dyn Error>> {
+    let src = include_str!("../../data/ok/small.txt");
+    let font = text::from_str_ref(src)?;
+    let range = src.as_bytes().as_ptr_range();
+    assert!(range.contains(&font.info.face.as_ptr()));
+    assert!(range.contains(&font.pages[0].as_ptr()));
+    assert_eq!(font.to_owned()?, small());
+    assert_eq!(font, binary::from_bytes_ref(&binary::to_vec(&small())?)?);
+    Ok(())
+}
+
+#[test]
+fn text_ref_medium() -> Result<(), Box<dyn Error>> {
+    let src = include_bytes!("../../data/ok/medium.txt");
+    assert_eq!(text::from_bytes_ref(src)?.to_owned()?, text::from_bytes(src)?);
+    Ok(())
+}
+
+#[test]
+fn text_ref_bad() {
+    let srcs: [&[u8]; 6] = [
+        include_bytes!("../../data/bad/invalid_char_count.txt"),
+        include_bytes!("../../data/bad/invalid_kerning_count.txt"),
+        include_bytes!("../../data/bad/no_info.txt"),
+        include_bytes!("../../data/bad/no_common.txt"),
+        include_bytes!("../../data/bad/duplicate_key.txt"),
+        include_bytes!("../../data/bad/invalid_charset_string.txt"),
+    ];
+    for src in srcs {
+        let owned = text::from_bytes(src).map(|_| ()).map_err(|e| e.to_string());
+        let borrowed = text::from_bytes_ref(src).map(|_| ()).map_err(|e| e.to_string());
+        assert!(owned.is_err());
+        assert_eq!(borrowed, owned);
+    }
+}
+
+/// Returns the offset of the specified binary block data.
+fn binary_block_offset(src: &[u8], id: u8) -> usize {
+    use std::convert::TryInto;
+
+    let mut offset = 4;
+    loop {
+        let len = u32::from_le_bytes(src[offset + 1..offset + 5].try_into().unwrap()) as usize;
+        if src[offset] == id {
+            return offset + 5;
+        }
+        offset += 5 + len;
+    }
+}
+
+#[test]
+fn binary_block_trailing_bytes() -> Result<(), Box<dyn Error>> {
+    use std::convert::TryInto;
+
+    for id in [1, 2] {
+        let mut src = binary::to_vec(&small())?;
+        let offset = binary_block_offset(&src, id);
+        let len = u32::from_le_bytes(src[offset - 4..offset].try_into()?);
+        src[offset - 4..offset].copy_from_slice(&(len + 1).to_le_bytes());
+        src.insert(offset + len as usize, 0);
+        assert!(binary::from_bytes(&src).is_err());
+        assert!(binary::from_bytes_ref(&src).is_err());
+        assert!(binary::Parser::new(&src).any(|u| u.is_err()));
+    }
+    Ok(())
+}
+
+err!(
+    binary_ref_invalid_chnl,
+    {
+        let mut src = binary::to_vec(&small()).unwrap();
+        let offset = binary_block_offset(&src, 4);
+        src[offset + 19] = 0xFF;
+        binary::from_bytes_ref(&src).and_then(|u| u.to_owned())
+    },
+    crate::Error::Parse { .. }
+);
+
+#[test]
+fn binary_ref_invalid_chnl_lazy() -> Result<(), Box<dyn Error>> {
+    let mut src = binary::to_vec(&small())?;
+    let offset = binary_block_offset(&src, 4);
+    src[offset + 19] = 0xFF;
+    let font = binary::from_bytes_ref(&src)?;
+    assert!(matches!(font.chars.get(0), Some(Err(crate::Error::Parse { .. }))));
+    assert_eq!(font.chars.get(1).transpose()?, Some(small().chars[1]));
+    Ok(())
+}
+
+err!(
+    binary_ref_invalid_chars_len,
+    {
+        let mut src = binary::to_vec(&small()).unwrap();
+        let offset = binary_block_offset(&src, 4);
+        let len = u32::from_le_bytes([
+            src[offset - 4],
+            src[offset - 3],
+            src[offset - 2],
+            src[offset - 1],
+        ]) - 1;
+        src[offset - 4..offset].copy_from_slice(&len.to_le_bytes());
+        src.remove(offset);
+        binary::from_bytes_ref(&src).and_then(|u| u.to_owned())
+    },
+    crate::Error::Parse { .. }
+);
+
+err!(
+    text_ref_invalid_face_string,
+    text::from_str_ref("info face=\"\x01\"\ncommon pages=0").and_then(|u| u.to_owned()),
+    crate::Error::UnsafeValueString { .. }
+);
+
+#[test]
+fn parser_medium() -> Result<(), Box<dyn Error>> {
+    let txt = include_bytes!("../../data/ok/medium.txt");
+    let bin = include_bytes!("../../data/ok/medium.bin");
+    let txt_events = text::Parser::new(txt).collect::<crate::Result<Vec<_>>>()?;
+    let bin_events = binary::Parser::new(bin).collect::<crate::Result<Vec<_>>>()?;
+    let font = binary::from_bytes(bin)?;
+    for events in [txt_events, bin_events] {
+        let chars: Vec<Char> = events
+            .iter()
+            .filter_map(|u| if let crate::Event::Char(char) = u { Some(*char) } else { None })
+            .collect();
+        assert_eq!(chars, font.chars);
+        assert!(events.contains(&crate::Event::CharCount(font.chars.len() as u32)));
+        assert!(events.contains(&crate::Event::Info(font.info.clone())));
+    }
+    Ok(())
+}
+
+fn font_writer(font: &Font, format: crate::Format) -> crate::Result<Vec<u8>> {
+    let mut writer = crate::FontWriter::new(Vec::default(), format);
+    writer.write_info(&font.info)?;
+    writer.write_common(&font.common)?;
+    for page in &font.pages {
+        writer.write_page(page)?;
+    }
+    writer.declare_chars(font.chars.len() as u32)?;
+    for char in &font.chars {
+        writer.write_char(char)?;
+    }
+    writer.declare_kernings(font.kernings.len() as u32)?;
+    for kerning in &font.kernings {
+        writer.write_kerning(kerning)?;
+    }
+    writer.finish()
+}
+
+#[test]
+fn font_writer_medium() -> Result<(), Box<dyn Error>> {
+    let font = binary::from_bytes(include_bytes!("../../data/ok/medium.bin"))?;
+    for font in [small(), font] {
+        assert_eq!(font_writer(&font, crate::Format::Text)?, text::to_vec(&font)?);
+        assert_eq!(font_writer(&font, crate::Format::Binary)?, binary::to_vec(&font)?);
+        #[cfg(feature = "xml")]
+        assert_eq!(font_writer(&font, crate::Format::Xml)?, xml::to_vec(&font)?);
+        #[cfg(feature = "plist")]
+        assert_eq!(font_writer(&font, crate::Format::Plist)?, plist::to_vec(&font)?);
+    }
+    Ok(())
+}
+
+#[test]
+fn font_writer_binary_no_kernings() -> Result<(), Box<dyn Error>> {
+    let mut font = small();
+    font.kernings.clear();
+    assert_eq!(font_writer(&font, crate::Format::Binary)?, binary::to_vec(&font)?);
+    Ok(())
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn async_small_round_trip() -> Result<(), Box<dyn Error>> {
+    let font = small();
+    pollster::block_on(async {
+        let mut vec = Vec::default();
+        text::to_async_writer(&mut vec, &font).await?;
+        assert_eq!(vec, text::to_vec(&font)?);
+        assert_eq!(text::from_async_reader(vec.as_slice()).await?, font);
+        let mut vec = Vec::default();
+        binary::to_async_writer(&mut vec, &font).await?;
+        assert_eq!(vec, binary::to_vec(&font)?);
+        assert_eq!(binary::from_async_reader(vec.as_slice()).await?, font);
+        #[cfg(feature = "xml")]
+        {
+            let mut vec = Vec::default();
+            xml::to_async_writer(&mut vec, &font).await?;
+            assert_eq!(xml::from_async_reader(vec.as_slice()).await?, font);
+        }
+        #[cfg(feature = "plist")]
+        {
+            let mut vec = Vec::default();
+            plist::to_async_writer(&mut vec, &font).await?;
+            assert_eq!(plist::from_async_reader(vec.as_slice()).await?, font);
+        }
+        #[cfg(feature = "json")]
+        {
+            let mut vec = Vec::default();
+            json::to_async_writer(&mut vec, &font).await?;
+            assert_eq!(json::from_async_reader(vec.as_slice()).await?, font);
+        }
+        #[cfg(feature = "yaml")]
+        {
+            let mut vec = Vec::default();
+            yaml::to_async_writer(&mut vec, &font).await?;
+            assert_eq!(yaml::from_async_reader(vec.as_slice()).await?, font);
+        }
+        #[cfg(feature = "toml")]
+        {
+            let mut vec = Vec::default();
+            toml::to_async_writer(&mut vec, &font).await?;
+            assert_eq!(toml::from_async_reader(vec.as_slice()).await?, font);
+        }
+        #[cfg(feature = "msgpack")]
+        {
+            let mut vec = Vec::default();
+            msgpack::to_async_writer(&mut vec, &font).await?;
+            assert_eq!(msgpack::from_async_reader(vec.as_slice()).await?, font);
+        }
+        #[cfg(feature = "cbor")]
+        {
+            let mut vec = Vec::default();
+            cbor::to_async_writer(&mut vec, &font).await?;
+            assert_eq!(cbor::from_async_reader(vec.as_slice()).await?, font);
+        }
+        Ok(())
+    })
+}
+
+#[cfg(feature = "async")]
+#[test]
+fn async_medium_from_async_reader() -> Result<(), Box<dyn Error>> {
+    let bin = include_bytes!("../../data/ok/medium.bin");
+    let txt = include_bytes!("../../data/ok/medium.txt");
+    let font = pollster::block_on(binary::from_async_reader(&bin[..]))?;
+    assert_eq!(font, binary::from_bytes(bin)?);
+    let font = pollster::block_on(text::from_async_reader(&txt[..]))?;
+    assert_eq!(font, text::from_bytes(txt)?);
+    Ok(())
+}
+
+#[cfg(feature = "codegen")]
+#[test]
+fn codegen_small_gen() -> Result<(), Box<dyn Error>> {
+    let src = crate::codegen::to_string(&small(), "SMALL")?;
+    assert_eq!(src, include_str!("../../data/ok/small.rs"));
+    Ok(())
+}
+
+#[cfg(feature = "codegen")]
+#[test]
+fn codegen_name() {
+    for name in ["", "_", "1FONT", "FONT-1", "r#fn", "fn", "static", "match", "self", "Self"] {
+        assert!(
+            matches!(
+                crate::codegen::to_string(&small(), name),
+                Err(crate::Error::UnsupportedValueEncoding { .. })
+            ),
+            "{:?}",
+            name
+        );
+    }
+    for name in ["FONT", "_FONT", "font_1", "fn_"] {
+        assert!(crate::codegen::to_string(&small(), name).is_ok(), "{:?}", name);
+    }
+}
+
+mod small_static {
+    include!("../../data/ok/small.rs");
+}
+
+#[test]
+fn codegen_small_static() -> Result<(), Box<dyn Error>> {
+    let font = small();
+    let font_static = &small_static::SMALL;
+    assert_eq!(font_static.char(32), font.chars.iter().find(|u| u.id == 32));
+    assert_eq!(font_static.char(33), None);
+    assert_eq!(font_static.kerning(10, 32), -2);
+    assert_eq!(font_static.kerning(32, 32), 0);
+    let mut owned = font_static.to_font();
+    assert_eq!(owned.info.face, font.info.face);
+    owned.info = font.info.clone();
+    assert!(owned.semantically_eq(&font));
+    Ok(())
+}
@@ -1,9 +1,19 @@
-use crate::builder::tags::{Tag, Tags};
-use crate::builder::FontBuilder;
-use crate::font::Font;
-use crate::tagged_attributes::TaggedAttributes;
-use crate::LoadSettings;
+use crate::builder::{self, FontBuilder};
+use crate::encoding;
+use crate::font::{Common, Font};
+use crate::font_ref::{CharsRef, FontRef, InfoRef, KerningsRef};
+use crate::prelude::*;
 
+use super::parser::Parser;
+use crate::event::Event;
+use crate::{LoadReport, LoadSettings, TextDialect, TextEncoding, Warning};
+
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncRead;
+
+#[cfg(feature = "std")]
 use std::io;
 
 /// Load text format font.
@@ -71,7 +81,198 @@ pub fn from_bytes(bytes: &[u8]) -> crate::Result<Font> {
 /// This function specifies Font import behavior, allowing us to import certain partially
 /// broken/ non-compliant BMFont files.
 pub fn from_bytes_ext(bytes: &[u8], settings: &LoadSettings) -> crate::Result<Font> {
-    FontBuilderText::default().load_bytes(bytes, settings)?.build(settings)
+    load(bytes, settings)?.0.build()
+}
+
+/// Load text format font with the specified import behavior settings, reporting warnings.
+///
+/// As [from_str_ext], additionally reporting any non-fatal [Warning](crate::Warning)s raised
+/// by lenient import behavior settings.
+pub fn from_str_report(src: &str, settings: &LoadSettings) -> crate::Result<LoadReport> {
+    from_bytes_report(src.as_bytes(), settings)
+}
+
+/// Load text format font with the specified import behavior settings, reporting warnings.
+///
+/// As [from_bytes_ext], additionally reporting any non-fatal [Warning](crate::Warning)s raised
+/// by lenient import behavior settings.
+pub fn from_bytes_report(bytes: &[u8], settings: &LoadSettings) -> crate::Result<LoadReport> {
+    let (builder, encoding) = load(bytes, settings)?;
+    let mut report = builder.build_report()?;
+    // String values decoded with the fallback encoding imply a legacy encoded file.
+    report.encoding = report
+        .warnings
+        .iter()
+        .find_map(|u| match u {
+            Warning::Transcoded { encoding, .. } => Some(*encoding),
+            _ => None,
+        })
+        .unwrap_or(encoding);
+    Ok(report)
+}
+
+/// Load text format font as a borrowed view.
+///
+/// Load a [FontRef] from the specified text format [str]. The face and page strings borrow
+/// from the source. Unlike binary data, text has no fixed layout to view, the character and
+/// kerning pair tables are decoded and allocated on load.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let src = fs::read_to_string("font.txt")?;
+///     let font = bmfont_rs::text::from_str_ref(&src)?;
+///     println!("{}", font.info.face);
+///     Ok(())
+/// }
+/// ```
+pub fn from_str_ref(src: &str) -> crate::Result<FontRef<'_>> {
+    from_str_ref_ext(src, &Default::default())
+}
+
+/// Load text format font as a borrowed view with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files. The encoding settings are not applicable.
+pub fn from_str_ref_ext<'a>(src: &'a str, settings: &LoadSettings) -> crate::Result<FontRef<'a>> {
+    from_bytes_ref_ext(src.as_bytes(), settings)
+}
+
+/// Load text format font as a borrowed view.
+///
+/// Load a [FontRef] from the specified text format byte slice. The face and page strings borrow
+/// from the source. Unlike binary data, text has no fixed layout to view, the character and
+/// kerning pair tables are decoded and allocated on load.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let buf = fs::read("font.txt")?;
+///     let font = bmfont_rs::text::from_bytes_ref(&buf)?;
+///     println!("{}", font.info.face);
+///     Ok(())
+/// }
+/// ```
+pub fn from_bytes_ref(bytes: &[u8]) -> crate::Result<FontRef<'_>> {
+    from_bytes_ref_ext(bytes, &Default::default())
+}
+
+/// Load text format font as a borrowed view with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files. The encoding settings are not applicable.
+pub fn from_bytes_ref_ext<'a>(
+    bytes: &'a [u8],
+    settings: &LoadSettings,
+) -> crate::Result<FontRef<'a>> {
+    let hiero = settings.text_dialect == TextDialect::Hiero;
+    let mut parser = Parser::with_settings(bytes, settings).borrow();
+    let mut info: Option<InfoRef> = None;
+    let mut common: Option<Common> = None;
+    let mut pages: Vec<&str> = Vec::default();
+    let mut chars = Vec::default();
+    let mut kernings = Vec::default();
+    let mut char_count: Option<u32> = None;
+    let mut kerning_count: Option<u32> = None;
+    while let Some(event) = parser.next() {
+        let line = parser.line();
+        match event? {
+            Event::Info(u) => {
+                if info.is_some() {
+                    return Err(crate::Error::DuplicateInfoBlock { line });
+                }
+                info = Some(InfoRef::from_info(parser.borrowed()?.unwrap_or_default(), u));
+            }
+            Event::Common(u) => {
+                if common.is_some() {
+                    return Err(crate::Error::DuplicateCommonBlock { line });
+                }
+                common = Some(u);
+            }
+            Event::Page { id, .. } => {
+                if id as usize != pages.len() {
+                    return Err(crate::Error::BrokenPageList);
+                }
+                pages.push(parser.borrowed()?.unwrap_or_default());
+            }
+            Event::CharCount(_) | Event::KerningCount(_) if hiero => {}
+            Event::CharCount(count) => {
+                if char_count.replace(count).is_some() {
+                    return Err(crate::Error::DuplicateCharCount { line });
+                }
+                chars.reserve(count as usize);
+            }
+            Event::Char(u) => chars.push(u),
+            Event::KerningCount(count) => {
+                if kerning_count.replace(count).is_some() {
+                    return Err(crate::Error::DuplicateKerningCount { line });
+                }
+                kernings.reserve(count as usize);
+            }
+            Event::Kerning(u) => kernings.push(u),
+        }
+    }
+    let info = info.ok_or(crate::Error::NoInfoBlock)?;
+    let common = common.ok_or(crate::Error::NoCommonBlock)?;
+    if !settings.ignore_counts {
+        if let Some(specified) = char_count.filter(|&u| u as usize != chars.len()) {
+            return Err(crate::Error::InvalidCharCount { specified, realized: chars.len() });
+        }
+        if let Some(specified) = kerning_count.filter(|&u| u as usize != kernings.len()) {
+            return Err(crate::Error::InvalidKerningCount { specified, realized: kernings.len() });
+        }
+    }
+    builder::check_pages(&common, pages.len(), settings)?;
+    if !settings.allow_string_control_characters {
+        builder::check_strings(&info.charset, info.face, &pages)?;
+    }
+    let chars = CharsRef::decoded(chars);
+    let kernings = KerningsRef::decoded(kernings);
+    Ok(FontRef { info, common, pages, chars, kernings })
+}
+
+/// Detect text format font encoding.
+///
+/// Detects UTF-8 with a byte order mark and UTF-16, with or without a byte order mark. Otherwise
+/// returns [TextEncoding::Utf8] for valid UTF-8, or [TextEncoding::Windows1252].
+///
+/// # Example
+///
+/// ```
+/// use bmfont_rs::TextEncoding;
+///
+/// assert_eq!(bmfont_rs::text::detect_encoding(b"\xEF\xBB\xBFinfo"), TextEncoding::Utf8Bom);
+/// assert_eq!(bmfont_rs::text::detect_encoding(b"i\x00n\x00"), TextEncoding::Utf16Le);
+/// assert_eq!(bmfont_rs::text::detect_encoding(b"info"), TextEncoding::Utf8);
+/// assert_eq!(bmfont_rs::text::detect_encoding(b"Caf\xE9"), TextEncoding::Windows1252);
+/// ```
+pub fn detect_encoding(bytes: &[u8]) -> TextEncoding {
+    match encoding::detect(bytes) {
+        TextEncoding::Utf8 if core::str::from_utf8(bytes).is_err() => TextEncoding::Windows1252,
+        encoding => encoding,
+    }
+}
+
+fn load(bytes: &[u8], settings: &LoadSettings) -> crate::Result<(FontBuilder, TextEncoding)> {
+    let encoding =
+        if settings.detect_encoding { encoding::detect(bytes) } else { TextEncoding::Utf8 };
+    let bytes = encoding::transcode(bytes, encoding)?;
+    let builder = FontBuilderText::new(settings).load_bytes(&bytes)?;
+    Ok((builder, encoding))
 }
 
 /// Read text format font.
@@ -97,6 +298,7 @@ pub fn from_bytes_ext(bytes: &[u8], settings: &LoadSettings) -> crate::Result<Fo
 ///     Ok(())
 /// }
 /// ```
+#[cfg(feature = "std")]
 pub fn from_reader<R: io::Read>(reader: R) -> crate::Result<Font> {
     from_reader_ext(reader, &Default::default())
 }
@@ -105,49 +307,72 @@ pub fn from_reader<R: io::Read>(reader: R) -> crate::Result<Font> {
 ///
 /// This function specifies Font import behavior, allowing us to import certain partially
 /// broken/ non-compliant BMFont files.
+#[cfg(feature = "std")]
 pub fn from_reader_ext<R: io::Read>(mut reader: R, settings: &LoadSettings) -> crate::Result<Font> {
     let mut vec = Vec::default();
     reader.read_to_end(&mut vec)?;
     from_bytes_ext(&vec, settings)
 }
 
-#[derive(Debug, Default)]
+/// Read text format font from an async reader.
+///
+/// As [from_reader], but reads from a `futures-io` [AsyncRead](futures_io::AsyncRead).
+/// This method buffers data internally, a buffered reader is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncRead;
+///
+/// async fn load<R: AsyncRead + Unpin>(reader: R) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::text::from_async_reader(reader).await?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn from_async_reader<R: AsyncRead + Unpin>(reader: R) -> crate::Result<Font> {
+    from_async_reader_ext(reader, &Default::default()).await
+}
+
+/// Read text format font from an async reader with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+#[cfg(feature = "async")]
+pub async fn from_async_reader_ext<R: AsyncRead + Unpin>(
+    mut reader: R,
+    settings: &LoadSettings,
+) -> crate::Result<Font> {
+    let mut vec = Vec::default();
+    async_io::read_to_end(&mut reader, &mut vec).await?;
+    from_bytes_ext(&vec, settings)
+}
+
+#[derive(Debug)]
 pub struct FontBuilderText {
     builder: FontBuilder,
 }
 
 impl FontBuilderText {
-    pub fn load_bytes(
-        mut self,
-        bytes: &[u8],
-        settings: &LoadSettings,
-    ) -> crate::Result<FontBuilder> {
-        let mut attributes = TaggedAttributes::from_bytes(bytes);
-        while let Some(Tag { tag, line }) = attributes.next_tag()? {
-            match tag {
-                b"info" => self.builder.set_info_attributes(line, &mut attributes),
-                b"common" => self.builder.set_common_attributes(line, &mut attributes),
-                b"page" => self.builder.add_page_attributes(&mut attributes),
-                b"chars" => self.builder.set_char_count_attributes(line, &mut attributes),
-                b"char" => self.builder.add_char_attributes(&mut attributes),
-                b"kernings" => self.builder.set_kerning_count_attributes(line, &mut attributes),
-                b"kerning" => self.builder.add_kerning_attributes(&mut attributes),
-                tag => {
-                    if settings.ignore_invalid_tags {
-                        Ok(())
-                    } else {
-                        let line = Some(attributes.line());
-                        let tag =
-                            String::from_utf8(tag.into()).map_err(|e| crate::Error::Parse {
-                                line,
-                                entity: "tag".to_owned(),
-                                err: e.to_string(),
-                            })?;
-                        Err(crate::Error::InvalidTag { line, tag })
-                    }
-                }
-            }?;
+    pub fn new(settings: &LoadSettings) -> Self {
+        Self { builder: FontBuilder::new(settings) }
+    }
+
+    pub fn load_bytes(mut self, bytes: &[u8]) -> crate::Result<FontBuilder> {
+        let mut parser = Parser::with_settings(bytes, self.builder.settings());
+        let hiero = self.builder.settings().text_dialect == TextDialect::Hiero;
+        while let Some(event) = parser.next() {
+            match event? {
+                Event::CharCount(_) | Event::KerningCount(_) if hiero => continue,
+                event => self.builder.add_event(parser.line(), event)?,
+            }
         }
+        self.builder.extend_warnings(parser.take_warnings());
         Ok(self.builder)
     }
 }
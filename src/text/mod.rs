@@ -1,7 +1,22 @@
 //! Text format operations.
 
 mod load;
+mod parser;
+#[cfg(feature = "std")]
 mod store;
 
-pub use load::{from_bytes, from_bytes_ext, from_reader, from_reader_ext, from_str, from_str_ext};
-pub use store::{to_string, to_vec, to_writer};
+pub use load::{detect_encoding, from_bytes_report, from_str_report};
+pub use load::{from_bytes, from_bytes_ext, from_str, from_str_ext};
+pub use load::{from_bytes_ref, from_bytes_ref_ext, from_str_ref, from_str_ref_ext};
+#[cfg(feature = "std")]
+pub use load::{from_reader, from_reader_ext};
+pub use parser::Parser;
+#[cfg(feature = "std")]
+pub(crate) use store::StoreText;
+#[cfg(feature = "std")]
+pub use store::{to_string, to_string_ext, to_vec, to_vec_ext, to_writer, to_writer_ext};
+
+#[cfg(feature = "async")]
+pub use load::{from_async_reader, from_async_reader_ext};
+#[cfg(feature = "async")]
+pub use store::{to_async_writer, to_async_writer_ext};
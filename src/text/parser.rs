@@ -0,0 +1,259 @@
+use crate::builder::attributes::{self, Attribute, Attributes, Intercept, Retain};
+use crate::builder::load::Load;
+use crate::builder::tags::{Tag, Tags};
+use crate::builder::Count;
+use crate::event::Event;
+use crate::font::{Char, Common, Info, Kerning, Page};
+use crate::prelude::*;
+use crate::tagged_attributes::TaggedAttributes;
+use crate::{LoadSettings, TextDialect, Warning};
+
+/// Text format pull parser.
+///
+/// Yields the font descriptor entries as typed [Event]s in source order, without materializing
+/// the [Font](crate::Font). Counts, page ids and references are not validated.
+///
+/// Parsing halts on the first error.
+///
+/// # Example
+///
+/// ```no_run
+/// use bmfont_rs::Event;
+/// use std::fs;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let buf = fs::read("font.txt")?;
+///     let mut wide = 0;
+///     for event in bmfont_rs::text::Parser::new(&buf) {
+///         if let Event::Char(char) = event? {
+///             wide += (char.width > 32) as u32;
+///         }
+///     }
+///     println!("wide chars: {}", wide);
+///     Ok(())
+/// }
+/// ```
+pub struct Parser<'a> {
+    attributes: TaggedAttributes<'a>,
+    settings: LoadSettings,
+    warnings: Vec<Warning>,
+    line: Option<usize>,
+    borrow: bool,
+    borrowed: Option<Attribute<'a>>,
+    done: bool,
+}
+
+impl<'a> Parser<'a> {
+    /// Construct a new Parser over the specified text format byte slice.
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self::with_settings(bytes, &Default::default())
+    }
+
+    /// Construct a new Parser over the specified text format byte slice with the specified import
+    /// behavior settings.
+    ///
+    /// The encoding, count and duplicate settings are not applicable. With the
+    /// [Hiero](TextDialect::Hiero) dialect, info padding is reordered and unknown page attributes
+    /// are discarded.
+    pub fn with_settings(bytes: &'a [u8], settings: &LoadSettings) -> Self {
+        Self {
+            attributes: TaggedAttributes::from_bytes(bytes),
+            settings: *settings,
+            warnings: Vec::default(),
+            line: None,
+            borrow: false,
+            borrowed: None,
+            done: false,
+        }
+    }
+
+    /// Borrow the info face and page file values, as returned by `borrowed`. These values are
+    /// withheld from the info and page events, which are left empty.
+    pub(crate) fn borrow(mut self) -> Self {
+        self.borrow = true;
+        self
+    }
+
+    /// The borrowed info face or page file value of the most recent event, if borrowed.
+    pub(crate) fn borrowed(&self) -> crate::Result<Option<&'a str>> {
+        attributes::value_str(self.borrowed)
+    }
+
+    /// The line number of the most recent event.
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// Warnings raised by lenient import behavior settings so far.
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    pub(crate) fn take_warnings(&mut self) -> Vec<Warning> {
+        core::mem::take(&mut self.warnings)
+    }
+
+    fn next_event(&mut self) -> crate::Result<Option<Event>> {
+        let attributes = &mut self.attributes;
+        let settings = &self.settings;
+        let warnings = &mut self.warnings;
+        let borrowed = &mut self.borrowed;
+        let borrow = self.borrow;
+        let hiero = settings.text_dialect == TextDialect::Hiero;
+        while let Some(Tag { tag, line }) = attributes.next_tag()? {
+            self.line = line;
+            *borrowed = None;
+            let event = match tag {
+                b"info" => {
+                    let key = borrow.then_some(b"face".as_ref());
+                    let mut info: Info = load(attributes, key, settings, warnings, borrowed)?;
+                    if hiero {
+                        hiero_padding(&mut info);
+                    }
+                    Event::Info(info)
+                }
+                b"common" => Event::Common(Common::load(attributes, settings, warnings)?),
+                b"page" => {
+                    let key = borrow.then_some(b"file".as_ref());
+                    let Page { id, file } = if hiero {
+                        let mut retain = Retain::new(attributes, PAGE_KEYS);
+                        load(&mut retain, key, settings, warnings, borrowed)?
+                    } else {
+                        load(attributes, key, settings, warnings, borrowed)?
+                    };
+                    Event::Page { id, file }
+                }
+                b"chars" => Event::CharCount(Count::load(attributes, settings, warnings)?.count),
+                b"char" => Event::Char(Char::load(attributes, settings, warnings)?),
+                b"kernings" => {
+                    Event::KerningCount(Count::load(attributes, settings, warnings)?.count)
+                }
+                b"kerning" => Event::Kerning(Kerning::load(attributes, settings, warnings)?),
+                tag => {
+                    if settings.ignore_invalid_tags {
+                        continue;
+                    }
+                    let line = Some(attributes.line());
+                    let tag = String::from_utf8(tag.into()).map_err(|e| crate::Error::Parse {
+                        line,
+                        entity: "tag".to_owned(),
+                        err: e.to_string(),
+                    })?;
+                    return Err(crate::Error::InvalidTag { line, tag });
+                }
+            };
+            return Ok(Some(event));
+        }
+        Ok(None)
+    }
+}
+
+/// Load, withholding and borrowing the specified key if any.
+fn load<'a, T: Load, A: Attributes<'a>>(
+    attributes: &mut A,
+    key: Option<&'static [u8]>,
+    settings: &LoadSettings,
+    warnings: &mut Vec<Warning>,
+    borrowed: &mut Option<Attribute<'a>>,
+) -> crate::Result<T> {
+    match key {
+        Some(key) => {
+            let mut intercept = Intercept::new(attributes, key);
+            let t = T::load(&mut intercept, settings, warnings)?;
+            *borrowed = intercept.value();
+            Ok(t)
+        }
+        None => T::load(attributes, settings, warnings),
+    }
+}
+
+/// Page keys retained by the Hiero dialect.
+pub(crate) const PAGE_KEYS: &[&[u8]] = &[b"id", b"file"];
+
+/// Reorder Hiero up, left, down, right padding.
+pub(crate) fn hiero_padding(info: &mut Info) {
+    core::mem::swap(&mut info.padding.left, &mut info.padding.right);
+}
+
+impl Iterator for Parser<'_> {
+    type Item = crate::Result<Event>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let event = self.next_event().transpose();
+        self.done = !matches!(event, Some(Ok(_)));
+        event
+    }
+}
+
+impl core::iter::FusedIterator for Parser<'_> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::font::Padding;
+
+    #[test]
+    fn events() -> crate::Result<()> {
+        let src = b"page id=0 file=\"a.png\"\nchars count=1\nchar id=32\nkernings count=1\n\
+                    kerning first=32 second=32 amount=-1\n";
+        let events = Parser::new(src).collect::<crate::Result<Vec<_>>>()?;
+        assert_eq!(
+            events,
+            vec![
+                Event::Page { id: 0, file: "a.png".to_owned() },
+                Event::CharCount(1),
+                Event::Char(Char { id: 32, ..Default::default() }),
+                Event::KerningCount(1),
+                Event::Kerning(Kerning::new(32, 32, -1)),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn line() -> crate::Result<()> {
+        let mut parser = Parser::new(b"\nchars count=1\n\nchar id=32\n");
+        assert_eq!(parser.line(), None);
+        parser.next().transpose()?;
+        assert_eq!(parser.line(), Some(2));
+        parser.next().transpose()?;
+        assert_eq!(parser.line(), Some(4));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_tag() {
+        let mut parser = Parser::new(b"chars count=1\nbad\nchar id=32\n");
+        assert!(matches!(parser.next(), Some(Ok(Event::CharCount(1)))));
+        assert!(matches!(parser.next(), Some(Err(crate::Error::InvalidTag { .. }))));
+        assert!(parser.next().is_none());
+    }
+
+    #[test]
+    fn hiero() -> crate::Result<()> {
+        let settings = LoadSettings::default().text_dialect(TextDialect::Hiero);
+        let src = b"info padding=1,2,3,4\npage id=0 file=\"a.png\" type=png\n";
+        let events = Parser::with_settings(src, &settings).collect::<crate::Result<Vec<_>>>()?;
+        let padding = Padding { up: 1, right: 4, down: 3, left: 2 };
+        assert_eq!(
+            events,
+            vec![
+                Event::Info(Info { padding, ..Default::default() }),
+                Event::Page { id: 0, file: "a.png".to_owned() },
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn ignore_invalid_tag() -> crate::Result<()> {
+        let settings = LoadSettings::default().ignore_invalid_tags();
+        let parser = Parser::with_settings(b"bad\nchar id=32\n", &settings);
+        let events = parser.collect::<crate::Result<Vec<_>>>()?;
+        assert_eq!(events, vec![Event::Char(Char { id: 32, ..Default::default() })]);
+        Ok(())
+    }
+}
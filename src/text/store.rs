@@ -1,4 +1,11 @@
-use crate::font::{Char, Common, Font, Info, Kerning};
+use crate::font::{Char, Common, Font, Info, Kerning, Padding};
+use crate::writer::{FontWriter, Format, StoreStream};
+use crate::{StoreSettings, TextDialect};
+
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncWrite;
 
 use std::io;
 
@@ -21,7 +28,30 @@ use std::io;
 /// }
 /// ```
 pub fn to_string(font: &Font) -> crate::Result<String> {
-    let vec = to_vec(font)?;
+    to_string_ext(font, &Default::default())
+}
+
+/// Store text format font with the specified export behavior settings.
+///
+/// This function specifies Font export behavior, allowing us to emit the text dialects of other
+/// BMFont tools.
+///
+/// # Example
+///
+/// ```
+/// use bmfont_rs::{StoreSettings, TextDialect};
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let mut font = bmfont_rs::Font::default();
+///     font.info.padding = bmfont_rs::Padding::new(1, 2, 3, 4);
+///     let settings = StoreSettings::default().text_dialect(TextDialect::Hiero);
+///     let string = bmfont_rs::text::to_string_ext(&font, &settings)?;
+///     assert!(string.contains(" padding=1,4,3,2 "));
+///     Ok(())
+/// }
+/// ```
+pub fn to_string_ext(font: &Font, settings: &StoreSettings) -> crate::Result<String> {
+    let vec = to_vec_ext(font, settings)?;
     String::from_utf8(vec).map_err(|e| crate::Error::Parse {
         line: None,
         entity: "font".to_owned(),
@@ -48,8 +78,16 @@ pub fn to_string(font: &Font) -> crate::Result<String> {
 /// }
 /// ```
 pub fn to_vec(font: &Font) -> crate::Result<Vec<u8>> {
+    to_vec_ext(font, &Default::default())
+}
+
+/// Store text format font with the specified export behavior settings.
+///
+/// This function specifies Font export behavior, allowing us to emit the text dialects of other
+/// BMFont tools.
+pub fn to_vec_ext(font: &Font, settings: &StoreSettings) -> crate::Result<Vec<u8>> {
     let mut vec: Vec<u8> = Vec::default();
-    to_writer(&mut vec, font)?;
+    to_writer_ext(&mut vec, font, settings)?;
     Ok(vec)
 }
 
@@ -76,27 +114,124 @@ pub fn to_vec(font: &Font) -> crate::Result<Vec<u8>> {
 ///     Ok(())
 /// }
 /// ```
-pub fn to_writer<W: io::Write>(mut writer: W, font: &Font) -> crate::Result<()> {
-    font.store(&mut writer)
+pub fn to_writer<W: io::Write>(writer: W, font: &Font) -> crate::Result<()> {
+    to_writer_ext(writer, font, &Default::default())
 }
 
-trait StoreFnt {
-    fn store<W: io::Write>(&self, writer: W) -> crate::Result<()>;
+/// Write text format font with the specified export behavior settings.
+///
+/// This function specifies Font export behavior, allowing us to emit the text dialects of other
+/// BMFont tools.
+pub fn to_writer_ext<W: io::Write>(
+    writer: W,
+    font: &Font,
+    settings: &StoreSettings,
+) -> crate::Result<()> {
+    let mut writer = FontWriter::with_settings(writer, Format::Text, settings);
+    writer.write_font(font)?;
+    writer.finish()?;
+    Ok(())
 }
 
-impl StoreFnt for Font {
-    fn store<W: io::Write>(&self, mut writer: W) -> crate::Result<()> {
-        self.info.store(&mut writer)?;
-        self.common.store(&mut writer)?;
-        for (i, page) in self.pages.iter().enumerate() {
-            write!(writer, "page id={} file=\"{}\"\r\n", i, check_value("page id", page)?)?;
+/// Write text format font to an async writer.
+///
+/// As [to_writer], but writes to a `futures-io` [AsyncWrite](futures_io::AsyncWrite). The writer
+/// is flushed on completion.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncWrite;
+///
+/// async fn store<W: AsyncWrite + Unpin>(writer: W) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     bmfont_rs::text::to_async_writer(writer, &font).await?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn to_async_writer<W: AsyncWrite + Unpin>(writer: W, font: &Font) -> crate::Result<()> {
+    to_async_writer_ext(writer, font, &Default::default()).await
+}
+
+/// Write text format font to an async writer with the specified export behavior settings.
+///
+/// This function specifies Font export behavior, allowing us to emit the text dialects of other
+/// BMFont tools.
+#[cfg(feature = "async")]
+pub async fn to_async_writer_ext<W: AsyncWrite + Unpin>(
+    mut writer: W,
+    font: &Font,
+    settings: &StoreSettings,
+) -> crate::Result<()> {
+    let vec = to_vec_ext(font, settings)?;
+    async_io::write_all(&mut writer, &vec).await?;
+    Ok(())
+}
+
+/// Text format streaming store.
+#[derive(Debug, Default)]
+pub(crate) struct StoreText {
+    dialect: TextDialect,
+}
+
+impl StoreText {
+    pub fn new(dialect: TextDialect) -> Self {
+        Self { dialect }
+    }
+}
+
+impl StoreStream for StoreText {
+    fn info<W: io::Write>(&mut self, writer: &mut W, info: &Info) -> crate::Result<()> {
+        match self.dialect {
+            TextDialect::BmFont => info.store(writer),
+            TextDialect::Hiero => {
+                let Padding { up, right, down, left } = info.padding;
+                let padding = Padding { up, right: left, down, left: right };
+                Info { padding, ..info.clone() }.store(writer)
+            }
         }
-        write!(writer, "chars count={}\r\n", self.chars.len())?;
-        self.chars.iter().try_for_each(|u| u.store(&mut writer))?;
-        write!(writer, "kernings count={}\r\n", self.kernings.len())?;
-        self.kernings.iter().try_for_each(|u| u.store(&mut writer))?;
+    }
+
+    fn common<W: io::Write>(&mut self, writer: &mut W, common: &Common) -> crate::Result<()> {
+        common.store(writer)
+    }
+
+    fn page<W: io::Write>(&mut self, writer: &mut W, id: u32, file: &str) -> crate::Result<()> {
+        write!(writer, "page id={} file=\"{}\"\r\n", id, check_value("page id", file)?)?;
         Ok(())
     }
+
+    fn chars<W: io::Write>(&mut self, writer: &mut W, count: u32) -> crate::Result<()> {
+        write!(writer, "chars count={}\r\n", count)?;
+        Ok(())
+    }
+
+    fn char<W: io::Write>(&mut self, writer: &mut W, char: &Char) -> crate::Result<()> {
+        char.store(writer)
+    }
+
+    fn kernings<W: io::Write>(&mut self, writer: &mut W, count: u32) -> crate::Result<()> {
+        write!(writer, "kernings count={}\r\n", count)?;
+        Ok(())
+    }
+
+    fn kerning<W: io::Write>(&mut self, writer: &mut W, kerning: &Kerning) -> crate::Result<()> {
+        kerning.store(writer)
+    }
+
+    fn finish<W: io::Write>(&mut self, _: &mut W) -> crate::Result<()> {
+        Ok(())
+    }
+}
+
+trait StoreFnt {
+    fn store<W: io::Write>(&self, writer: W) -> crate::Result<()>;
 }
 
 impl StoreFnt for Char {
@@ -0,0 +1,56 @@
+//! TextMesh Pro operations.
+//!
+//! Export fonts as JSON in the layout of the Unity TextMesh Pro font asset, for use with font
+//! asset importers.
+//!
+//! Characters map onto glyphs, numbered in font order, with a matching character table entry:
+//! * `m_Metrics`: `width`, `height`, bearing X `xoffset`, bearing Y `base - yoffset`, advance
+//!   `xadvance`
+//! * `m_GlyphRect`: `x`, `scaleH - y - height`, `width`, `height`, the atlas origin is bottom left
+//! * `m_AtlasIndex`: `page`
+//!
+//! The face info takes the family name, style and point size from the info block. The ascent line
+//! is the common `base` above the baseline, with the descent line `lineHeight - base` below it.
+//! Cap and mean lines are the bearing Y of `H` and `x` respectively, tab width the advance of the
+//! space, zero if absent. Kerning pairs map onto glyph pair adjustment records, the amount
+//! adjusting the advance of the first glyph.
+//!
+//! ```json
+//! {
+//! "m_FaceInfo": {
+//!     "m_FamilyName": "Arial",
+//!     "m_StyleName": "Regular",
+//!     "m_PointSize": 32,
+//!     ...
+//! },
+//! "m_AtlasWidth": 256,
+//! "m_AtlasHeight": 256,
+//! "m_GlyphTable": [
+//!     {"m_Index":0,"m_Metrics":{"m_Width":7,"m_Height":20,"m_HorizontalBearingX":1,...}
+//! ],
+//! "m_CharacterTable": [
+//!     {"m_ElementType":1,"m_Unicode":65,"m_GlyphIndex":0,"m_Scale":1}
+//! ],
+//! "m_FontFeatureTable": {"m_GlyphPairAdjustmentRecords": [
+//! ]}
+//! }
+//! ```
+//!
+//! # Example
+//!
+//! ```no_run
+//! use std::fs;
+//!
+//! fn main() -> bmfont_rs::Result<()> {
+//!     let font = bmfont_rs::text::from_bytes(&fs::read("font.fnt")?)?;
+//!     fs::write("font.tmp.json", bmfont_rs::textmeshpro::to_string(&font)?)?;
+//!     Ok(())
+//! }
+//! ```
+
+mod store;
+
+pub use store::{to_string, to_vec};
+
+#[cfg(feature = "std")]
+pub use store::to_writer;
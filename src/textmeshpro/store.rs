@@ -0,0 +1,196 @@
+use crate::font::{Char, Font};
+use crate::json_escape::Escape;
+use crate::prelude::*;
+
+use alloc::collections::BTreeMap;
+use core::fmt::{self, Write};
+
+#[cfg(feature = "std")]
+use std::io;
+
+/// Store TextMesh Pro font asset.
+///
+/// Store a font into a [String] as TextMesh Pro font asset JSON.
+///
+/// Packed fonts, those with character data in individual texture channels, are not supported.
+/// Kerning pairs that reference absent characters are not retained.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) if the font is packed or character ids are not unique.
+///
+/// # Example
+///
+/// ```
+/// fn main() -> bmfont_rs::Result<()> {
+///     let mut font = bmfont_rs::Font::default();
+///     font.chars.push(bmfont_rs::Char { id: 65, ..Default::default() });
+///     let string = bmfont_rs::textmeshpro::to_string(&font)?;
+///     assert!(string.contains("\"m_Unicode\":65,\"m_GlyphIndex\":0"));
+///     Ok(())
+/// }
+/// ```
+pub fn to_string(font: &Font) -> crate::Result<String> {
+    if font.common.packed {
+        return Err(unsupported("packed fonts are not supported".to_owned()));
+    }
+    let mut glyphs = BTreeMap::default();
+    for (index, char) in font.chars.iter().enumerate() {
+        if glyphs.insert(char.id, index).is_some() {
+            return Err(crate::Error::DuplicateChar { line: None, id: char.id });
+        }
+    }
+    let mut dst = String::default();
+    store(&mut dst, font, &glyphs).map_err(|e| crate::Error::Internal { err: e.to_string() })?;
+    Ok(dst)
+}
+
+/// Store TextMesh Pro font asset.
+///
+/// Store a font into a [Vec] as TextMesh Pro font asset JSON.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) if the font is packed or character ids are not unique.
+pub fn to_vec(font: &Font) -> crate::Result<Vec<u8>> {
+    to_string(font).map(String::into_bytes)
+}
+
+/// Write TextMesh Pro font asset.
+///
+/// Write a font to the specified writer as TextMesh Pro font asset JSON.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) if the font is packed or character ids are not unique.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let mut writer = File::create("font.tmp.json")?;
+///     bmfont_rs::textmeshpro::to_writer(&mut writer, &font)?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "std")]
+pub fn to_writer<W: io::Write>(mut writer: W, font: &Font) -> crate::Result<()> {
+    let string = to_string(font)?;
+    writer.write_all(string.as_bytes())?;
+    Ok(())
+}
+
+fn store(dst: &mut String, font: &Font, glyphs: &BTreeMap<u32, usize>) -> fmt::Result {
+    let base = font.common.base as i32;
+    let bearing_y =
+        |id: char| glyphs.get(&(id as u32)).map_or(0, |&i| base - font.chars[i].yoffset as i32);
+    let tab_width = glyphs.get(&0x20).map_or(0, |&i| font.chars[i].xadvance);
+    let style = match (font.info.bold, font.info.italic) {
+        (false, false) => "Regular",
+        (true, false) => "Bold",
+        (false, true) => "Italic",
+        (true, true) => "Bold Italic",
+    };
+    writeln!(dst, "{{")?;
+    writeln!(dst, "\"m_FaceInfo\": {{")?;
+    writeln!(dst, "\t\"m_FamilyName\": \"{}\",", Escape(&font.info.face))?;
+    writeln!(dst, "\t\"m_StyleName\": \"{}\",", style)?;
+    writeln!(dst, "\t\"m_PointSize\": {},", font.info.size.unsigned_abs())?;
+    writeln!(dst, "\t\"m_Scale\": 1,")?;
+    writeln!(dst, "\t\"m_LineHeight\": {},", font.common.line_height)?;
+    writeln!(dst, "\t\"m_AscentLine\": {},", base)?;
+    writeln!(dst, "\t\"m_CapLine\": {},", bearing_y('H'))?;
+    writeln!(dst, "\t\"m_MeanLine\": {},", bearing_y('x'))?;
+    writeln!(dst, "\t\"m_Baseline\": 0,")?;
+    writeln!(dst, "\t\"m_DescentLine\": {},", base - font.common.line_height as i32)?;
+    writeln!(dst, "\t\"m_TabWidth\": {}", tab_width)?;
+    writeln!(dst, "}},")?;
+    writeln!(dst, "\"m_AtlasWidth\": {},", font.common.scale_w)?;
+    writeln!(dst, "\"m_AtlasHeight\": {},", font.common.scale_h)?;
+    writeln!(dst, "\"m_GlyphTable\": [")?;
+    for (index, char) in font.chars.iter().enumerate() {
+        store_glyph(dst, font, index, char)?;
+        writeln!(dst, "{}", if index + 1 == font.chars.len() { "" } else { "," })?;
+    }
+    writeln!(dst, "],")?;
+    writeln!(dst, "\"m_CharacterTable\": [")?;
+    for (index, char) in font.chars.iter().enumerate() {
+        write!(
+            dst,
+            "\t{{\"m_ElementType\":1,\"m_Unicode\":{},\"m_GlyphIndex\":{},\"m_Scale\":1}}",
+            char.id, index
+        )?;
+        writeln!(dst, "{}", if index + 1 == font.chars.len() { "" } else { "," })?;
+    }
+    writeln!(dst, "],")?;
+    writeln!(dst, "\"m_FontFeatureTable\": {{\"m_GlyphPairAdjustmentRecords\": [")?;
+    let pairs = font
+        .kernings
+        .iter()
+        .filter_map(|u| Some((glyphs.get(&u.first)?, glyphs.get(&u.second)?, u.amount)))
+        .collect::<Vec<_>>();
+    for (i, (first, second, amount)) in pairs.iter().enumerate() {
+        write!(
+            dst,
+            "\t{{\"m_FirstAdjustmentRecord\":{{\"m_GlyphIndex\":{},\"m_GlyphValueRecord\":{}}},",
+            first,
+            ValueRecord(*amount)
+        )?;
+        write!(
+            dst,
+            "\"m_SecondAdjustmentRecord\":{{\"m_GlyphIndex\":{},\"m_GlyphValueRecord\":{}}},",
+            second,
+            ValueRecord(0)
+        )?;
+        write!(dst, "\"m_FeatureLookupFlags\":0}}")?;
+        writeln!(dst, "{}", if i + 1 == pairs.len() { "" } else { "," })?;
+    }
+    writeln!(dst, "]}}")?;
+    writeln!(dst, "}}")
+}
+
+fn store_glyph(dst: &mut String, font: &Font, index: usize, char: &Char) -> fmt::Result {
+    let (width, height) = (char.width as i32, char.height as i32);
+    write!(
+        dst,
+        "\t{{\"m_Index\":{},\"m_Metrics\":{{\"m_Width\":{},\"m_Height\":{},",
+        index, width, height
+    )?;
+    write!(
+        dst,
+        "\"m_HorizontalBearingX\":{},\"m_HorizontalBearingY\":{},\"m_HorizontalAdvance\":{}}},",
+        char.xoffset,
+        font.common.base as i32 - char.yoffset as i32,
+        char.xadvance
+    )?;
+    write!(
+        dst,
+        "\"m_GlyphRect\":{{\"m_X\":{},\"m_Y\":{},\"m_Width\":{},\"m_Height\":{}}},",
+        char.x,
+        font.common.scale_h as i32 - char.y as i32 - height,
+        width,
+        height
+    )?;
+    write!(dst, "\"m_Scale\":1,\"m_AtlasIndex\":{}}}", char.page)
+}
+
+fn unsupported(err: String) -> crate::Error {
+    crate::Error::UnsupportedEncoding { line: None, entity: "textmeshpro".to_owned(), err }
+}
+
+/// Glyph value record, advance adjustment only.
+struct ValueRecord(i16);
+
+impl fmt::Display for ValueRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{{\"m_XPlacement\":0,\"m_YPlacement\":0,\"m_XAdvance\":{},\"m_YAdvance\":0}}",
+            self.0
+        )
+    }
+}
@@ -0,0 +1,184 @@
+use crate::font::{Char, Common, Font, Info, Kerning};
+use crate::native_bool::{CommonDef, InfoDef};
+
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncWrite;
+
+use serde::Serialize;
+
+use std::fmt::{self, Write};
+use std::io;
+
+/// Store TOML format font.
+///
+/// Store a font into a [String] in TOML format.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let string = bmfont_rs::toml::to_string(&font)?;
+///     println!("{}", string);
+///     Ok(())
+/// }
+/// ```
+pub fn to_string(font: &Font) -> crate::Result<String> {
+    let mut dst = ser(&Pages { pages: &font.pages })?;
+    inline_array(&mut dst, "chars", &font.chars, char)
+        .and_then(|_| inline_array(&mut dst, "kernings", &font.kernings, kerning))
+        .map_err(|e| crate::Error::Internal { err: e.to_string() })?;
+    dst.push('\n');
+    dst.push_str(&ser(&Head { info: &font.info, common: &font.common })?);
+    Ok(dst)
+}
+
+/// Store TOML format font.
+///
+/// Store a font into a [Vec] in TOML format.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let vec = bmfont_rs::toml::to_vec(&font)?;
+///     println!("{:02X?}", font);
+///     Ok(())
+/// }
+/// ```
+pub fn to_vec(font: &Font) -> crate::Result<Vec<u8>> {
+    to_string(font).map(String::into_bytes)
+}
+
+/// Write TOML format font.
+///
+/// Write a font to the specified writer in TOML format.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io;
+/// use std::io::prelude::*;
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let mut writer = File::create("font.toml")?;
+///     bmfont_rs::toml::to_writer(&mut writer, &font)?;
+///     Ok(())
+/// }
+/// ```
+pub fn to_writer<W: io::Write>(mut writer: W, font: &Font) -> crate::Result<()> {
+    let toml = to_string(font)?;
+    writer.write_all(toml.as_bytes()).map_err(Into::into)
+}
+
+/// Write TOML format font to an async writer.
+///
+/// As [to_writer], but writes to a `futures-io` [AsyncWrite](futures_io::AsyncWrite). The writer
+/// is flushed on completion.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncWrite;
+///
+/// async fn store<W: AsyncWrite + Unpin>(writer: W) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     bmfont_rs::toml::to_async_writer(writer, &font).await?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn to_async_writer<W: AsyncWrite + Unpin>(
+    mut writer: W,
+    font: &Font,
+) -> crate::Result<()> {
+    let vec = to_vec(font)?;
+    async_io::write_all(&mut writer, &vec).await?;
+    Ok(())
+}
+
+fn ser<T: Serialize>(value: &T) -> crate::Result<String> {
+    ::toml::to_string(value).map_err(|e| crate::Error::UnsupportedEncoding {
+        line: None,
+        entity: "toml".to_owned(),
+        err: e.to_string(),
+    })
+}
+
+/// Root level arrays, these must precede the tables.
+#[derive(Serialize)]
+struct Pages<'a> {
+    pages: &'a [String],
+}
+
+#[derive(Serialize)]
+struct Head<'a> {
+    #[serde(with = "InfoDef")]
+    info: &'a Info,
+    #[serde(with = "CommonDef")]
+    common: &'a Common,
+}
+
+fn inline_array<T>(
+    dst: &mut String,
+    key: &str,
+    entries: &[T],
+    f: fn(&mut String, &T) -> fmt::Result,
+) -> fmt::Result {
+    if entries.is_empty() {
+        writeln!(dst, "{} = []", key)
+    } else {
+        writeln!(dst, "{} = [", key)?;
+        entries.iter().try_for_each(|u| f(dst, u))?;
+        writeln!(dst, "]")
+    }
+}
+
+fn char(dst: &mut String, char: &Char) -> fmt::Result {
+    writeln!(
+        dst,
+        "    {{ id = {}, x = {}, y = {}, width = {}, height = {}, xoffset = {}, yoffset = {}, \
+         xadvance = {}, page = {}, chnl = {} }},",
+        char.id,
+        char.x,
+        char.y,
+        char.width,
+        char.height,
+        char.xoffset,
+        char.yoffset,
+        char.xadvance,
+        char.page,
+        u8::from(char.chnl)
+    )
+}
+
+fn kerning(dst: &mut String, kerning: &Kerning) -> fmt::Result {
+    writeln!(
+        dst,
+        "    {{ first = {}, second = {}, amount = {} }},",
+        kerning.first, kerning.second, kerning.amount
+    )
+}
@@ -0,0 +1,389 @@
+use crate::font::{Char, Common, Font, Info, Kerning};
+use crate::StoreSettings;
+
+use std::io;
+
+/// Font writer output format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Format {
+    /// Text format.
+    Text,
+    /// Binary format.
+    Binary,
+    /// XML format.
+    #[cfg(feature = "xml")]
+    Xml,
+    /// XML property list format.
+    #[cfg(feature = "plist")]
+    Plist,
+}
+
+/// Streaming font writer.
+///
+/// Writes a font incrementally, without buffering the characters or kerning pairs in a [Font].
+/// The output is identical to that of the corresponding `to_writer` method.
+///
+/// Entries must be written in order: info, common, pages, characters and kerning pairs. The
+/// character and kerning pair counts must be declared prior to writing the entries, these are
+/// verified on subsequent declarations and on [finish](Self::finish). Empty page, character and
+/// kerning pair sections may be omitted.
+///
+/// Output is written as entries are supplied, a buffered writer is recommended. Binary format
+/// pages are buffered until the character count is declared.
+///
+/// # Errors
+///
+/// * [Error::InvalidWriteOrder](crate::Error::InvalidWriteOrder) if entries are written out of
+///   order.
+/// * [Error::InvalidCharCount](crate::Error::InvalidCharCount)/
+///   [Error::InvalidKerningCount](crate::Error::InvalidKerningCount) if the number of entries
+///   written does not match the declared count.
+///
+/// # Example
+///
+/// ```
+/// use bmfont_rs::{Char, Common, Format, FontWriter, Info};
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let mut writer = FontWriter::new(Vec::default(), Format::Text);
+///     writer.write_info(&Info::default())?;
+///     writer.write_common(&Common { pages: 1, ..Default::default() })?;
+///     writer.write_page("sheet_0.png")?;
+///     writer.declare_chars(128)?;
+///     for id in 0..128 {
+///         writer.write_char(&Char { id, ..Default::default() })?;
+///     }
+///     let vec = writer.finish()?;
+///     assert_eq!(bmfont_rs::text::from_bytes(&vec)?.chars.len(), 128);
+///     Ok(())
+/// }
+/// ```
+pub struct FontWriter<W: io::Write> {
+    writer: W,
+    sink: Sink,
+    state: State,
+    page_id: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    Start,
+    Info,
+    Common,
+    Pages,
+    Chars { specified: u32, realized: u32 },
+    Kernings { specified: u32, realized: u32 },
+}
+
+impl<W: io::Write> FontWriter<W> {
+    /// Construct a new FontWriter with the specified writer and output format.
+    pub fn new(writer: W, format: Format) -> Self {
+        Self::with_settings(writer, format, &Default::default())
+    }
+
+    /// Construct a new FontWriter with the specified writer, output format and export behavior
+    /// settings.
+    pub fn with_settings(writer: W, format: Format, settings: &StoreSettings) -> Self {
+        let sink = match format {
+            Format::Text => Sink::Text(crate::text::StoreText::new(settings.text_dialect)),
+            Format::Binary => Sink::Binary(Default::default()),
+            #[cfg(feature = "xml")]
+            Format::Xml => Sink::Xml(crate::xml::StoreXmlStream::new(settings.xml_dialect)),
+            #[cfg(feature = "plist")]
+            Format::Plist => Sink::Plist(Default::default()),
+        };
+        Self { writer, sink, state: State::Start, page_id: 0 }
+    }
+
+    /// Write the info block. This must be the first entry.
+    pub fn write_info(&mut self, info: &Info) -> crate::Result<()> {
+        self.transition("info", &[State::Start], State::Info)?;
+        self.sink.info(&mut self.writer, info)
+    }
+
+    /// Write the common block. This must follow the info block.
+    pub fn write_common(&mut self, common: &Common) -> crate::Result<()> {
+        self.transition("common", &[State::Info], State::Common)?;
+        self.sink.common(&mut self.writer, common)
+    }
+
+    /// Write the next page file name, page ids are assigned sequentially from zero.
+    pub fn write_page(&mut self, file: &str) -> crate::Result<()> {
+        self.transition("page", &[State::Common, State::Pages], State::Pages)?;
+        let id = self.page_id;
+        self.page_id += 1;
+        self.sink.page(&mut self.writer, id, file)
+    }
+
+    /// Declare the character count. This must precede the characters.
+    pub fn declare_chars(&mut self, count: u32) -> crate::Result<()> {
+        let state = State::Chars { specified: count, realized: 0 };
+        self.transition("chars", &[State::Common, State::Pages], state)?;
+        self.sink.chars(&mut self.writer, count)
+    }
+
+    /// Write the next character.
+    pub fn write_char(&mut self, char: &Char) -> crate::Result<()> {
+        match &mut self.state {
+            State::Chars { specified, realized } => {
+                *realized += 1;
+                if realized > specified {
+                    let (specified, realized) = (*specified, *realized as usize);
+                    return Err(crate::Error::InvalidCharCount { specified, realized });
+                }
+            }
+            _ => return Err(invalid_write_order("char")),
+        }
+        self.sink.char(&mut self.writer, char)
+    }
+
+    /// Declare the kerning pair count. This must precede the kerning pairs.
+    pub fn declare_kernings(&mut self, count: u32) -> crate::Result<()> {
+        self.close_chars("kernings")?;
+        self.state = State::Kernings { specified: count, realized: 0 };
+        self.sink.kernings(&mut self.writer, count)
+    }
+
+    /// Write the next kerning pair.
+    pub fn write_kerning(&mut self, kerning: &Kerning) -> crate::Result<()> {
+        match &mut self.state {
+            State::Kernings { specified, realized } => {
+                *realized += 1;
+                if realized > specified {
+                    let (specified, realized) = (*specified, *realized as usize);
+                    return Err(crate::Error::InvalidKerningCount { specified, realized });
+                }
+            }
+            _ => return Err(invalid_write_order("kerning")),
+        }
+        self.sink.kerning(&mut self.writer, kerning)
+    }
+
+    /// Finish writing, verifying the declared counts. Returns the underlying writer.
+    pub fn finish(mut self) -> crate::Result<W> {
+        match self.state {
+            State::Kernings { specified, realized } => {
+                if specified != realized {
+                    let realized = realized as usize;
+                    return Err(crate::Error::InvalidKerningCount { specified, realized });
+                }
+            }
+            _ => self.declare_kernings(0)?,
+        }
+        self.sink.finish(&mut self.writer)?;
+        Ok(self.writer)
+    }
+
+    /// Write the specified font.
+    pub(crate) fn write_font(&mut self, font: &Font) -> crate::Result<()> {
+        self.write_info(&font.info)?;
+        self.write_common(&font.common)?;
+        font.pages.iter().try_for_each(|u| self.write_page(u))?;
+        self.declare_chars(font.chars.len() as u32)?;
+        font.chars.iter().try_for_each(|u| self.write_char(u))?;
+        self.declare_kernings(font.kernings.len() as u32)?;
+        font.kernings.iter().try_for_each(|u| self.write_kerning(u))
+    }
+
+    fn close_chars(&mut self, entity: &str) -> crate::Result<()> {
+        match self.state {
+            State::Common | State::Pages => self.declare_chars(0),
+            State::Chars { specified, realized } if specified != realized => {
+                let realized = realized as usize;
+                Err(crate::Error::InvalidCharCount { specified, realized })
+            }
+            State::Chars { .. } => Ok(()),
+            _ => Err(invalid_write_order(entity)),
+        }
+    }
+
+    fn transition(&mut self, entity: &str, from: &[State], to: State) -> crate::Result<()> {
+        if from.contains(&self.state) {
+            self.state = to;
+            Ok(())
+        } else {
+            Err(invalid_write_order(entity))
+        }
+    }
+}
+
+fn invalid_write_order(entity: &str) -> crate::Error {
+    crate::Error::InvalidWriteOrder { entity: entity.to_owned() }
+}
+
+/// Format specific streaming store.
+///
+/// Methods are called in strict order: info, common, page*, chars, char*, kernings, kerning*,
+/// finish.
+pub(crate) trait StoreStream {
+    fn info<W: io::Write>(&mut self, writer: &mut W, info: &Info) -> crate::Result<()>;
+
+    fn common<W: io::Write>(&mut self, writer: &mut W, common: &Common) -> crate::Result<()>;
+
+    fn page<W: io::Write>(&mut self, writer: &mut W, id: u32, file: &str) -> crate::Result<()>;
+
+    fn chars<W: io::Write>(&mut self, writer: &mut W, count: u32) -> crate::Result<()>;
+
+    fn char<W: io::Write>(&mut self, writer: &mut W, char: &Char) -> crate::Result<()>;
+
+    fn kernings<W: io::Write>(&mut self, writer: &mut W, count: u32) -> crate::Result<()>;
+
+    fn kerning<W: io::Write>(&mut self, writer: &mut W, kerning: &Kerning) -> crate::Result<()>;
+
+    fn finish<W: io::Write>(&mut self, writer: &mut W) -> crate::Result<()>;
+}
+
+enum Sink {
+    Text(crate::text::StoreText),
+    Binary(crate::binary::StoreBinary),
+    #[cfg(feature = "xml")]
+    Xml(crate::xml::StoreXmlStream),
+    #[cfg(feature = "plist")]
+    Plist(crate::plist::StorePlist),
+}
+
+macro_rules! dispatch {
+    ($self:expr, $method:ident($($arg:expr),*)) => {
+        match $self {
+            Sink::Text(u) => u.$method($($arg),*),
+            Sink::Binary(u) => u.$method($($arg),*),
+            #[cfg(feature = "xml")]
+            Sink::Xml(u) => u.$method($($arg),*),
+            #[cfg(feature = "plist")]
+            Sink::Plist(u) => u.$method($($arg),*),
+        }
+    };
+}
+
+impl StoreStream for Sink {
+    fn info<W: io::Write>(&mut self, writer: &mut W, info: &Info) -> crate::Result<()> {
+        dispatch!(self, info(writer, info))
+    }
+
+    fn common<W: io::Write>(&mut self, writer: &mut W, common: &Common) -> crate::Result<()> {
+        dispatch!(self, common(writer, common))
+    }
+
+    fn page<W: io::Write>(&mut self, writer: &mut W, id: u32, file: &str) -> crate::Result<()> {
+        dispatch!(self, page(writer, id, file))
+    }
+
+    fn chars<W: io::Write>(&mut self, writer: &mut W, count: u32) -> crate::Result<()> {
+        dispatch!(self, chars(writer, count))
+    }
+
+    fn char<W: io::Write>(&mut self, writer: &mut W, char: &Char) -> crate::Result<()> {
+        dispatch!(self, char(writer, char))
+    }
+
+    fn kernings<W: io::Write>(&mut self, writer: &mut W, count: u32) -> crate::Result<()> {
+        dispatch!(self, kernings(writer, count))
+    }
+
+    fn kerning<W: io::Write>(&mut self, writer: &mut W, kerning: &Kerning) -> crate::Result<()> {
+        dispatch!(self, kerning(writer, kerning))
+    }
+
+    fn finish<W: io::Write>(&mut self, writer: &mut W) -> crate::Result<()> {
+        dispatch!(self, finish(writer))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn writer() -> crate::Result<FontWriter<Vec<u8>>> {
+        let mut writer = FontWriter::new(Vec::default(), Format::Text);
+        writer.write_info(&Info::default())?;
+        writer.write_common(&Common::default())?;
+        Ok(writer)
+    }
+
+    #[test]
+    fn empty() -> crate::Result<()> {
+        let vec = writer()?.finish()?;
+        assert_eq!(vec, crate::text::to_vec(&Font::default())?);
+        Ok(())
+    }
+
+    #[test]
+    fn order_common() {
+        let mut writer = FontWriter::new(Vec::default(), Format::Text);
+        assert!(matches!(
+            writer.write_common(&Common::default()),
+            Err(crate::Error::InvalidWriteOrder { .. })
+        ));
+    }
+
+    #[test]
+    fn order_page() -> crate::Result<()> {
+        let mut writer = writer()?;
+        writer.declare_chars(0)?;
+        assert!(matches!(writer.write_page("a"), Err(crate::Error::InvalidWriteOrder { .. })));
+        Ok(())
+    }
+
+    #[test]
+    fn order_char() -> crate::Result<()> {
+        let mut writer = writer()?;
+        assert!(matches!(
+            writer.write_char(&Char::default()),
+            Err(crate::Error::InvalidWriteOrder { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn order_finish() {
+        let writer = FontWriter::new(Vec::default(), Format::Text);
+        assert!(matches!(writer.finish(), Err(crate::Error::InvalidWriteOrder { .. })));
+    }
+
+    #[test]
+    fn char_count_overflow() -> crate::Result<()> {
+        let mut writer = writer()?;
+        writer.declare_chars(0)?;
+        assert!(matches!(
+            writer.write_char(&Char::default()),
+            Err(crate::Error::InvalidCharCount { specified: 0, realized: 1 })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn char_count_underflow() -> crate::Result<()> {
+        let mut writer = writer()?;
+        writer.declare_chars(1)?;
+        assert!(matches!(
+            writer.finish(),
+            Err(crate::Error::InvalidCharCount { specified: 1, realized: 0 })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn binary_char_count_block_overflow() -> crate::Result<()> {
+        let mut writer = FontWriter::new(Vec::default(), Format::Binary);
+        writer.write_info(&Info::default())?;
+        writer.write_common(&Common::default())?;
+        assert!(matches!(
+            writer.declare_chars(u32::MAX),
+            Err(crate::Error::UnsupportedValueEncoding { .. })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn kerning_count_underflow() -> crate::Result<()> {
+        let mut writer = writer()?;
+        writer.declare_kernings(2)?;
+        writer.write_kerning(&Kerning::default())?;
+        assert!(matches!(
+            writer.finish(),
+            Err(crate::Error::InvalidKerningCount { specified: 2, realized: 1 })
+        ));
+        Ok(())
+    }
+}
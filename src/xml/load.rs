@@ -1,9 +1,14 @@
 extern crate roxmltree as xml;
 
-use crate::builder::attributes::{Attribute, Attributes};
+use crate::builder::attributes::{Attribute, Attributes, Intercept};
 use crate::builder::FontBuilder;
 use crate::font::Font;
-use crate::LoadSettings;
+use crate::{LoadReport, LoadSettings, XmlDialect};
+
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncRead;
 
 use std::io;
 
@@ -38,7 +43,15 @@ pub fn from_str(src: &str) -> crate::Result<Font> {
 /// This function specifies Font import behavior, allowing us to import certain partially
 /// broken/ non-compliant BMFont files.
 pub fn from_str_ext(src: &str, settings: &LoadSettings) -> crate::Result<Font> {
-    FontBuilderXml::default().load_str(src, settings)?.build(settings)
+    FontBuilderXml::new(settings).load_str(src)?.build()
+}
+
+/// Load XML format font with the specified import behavior settings, reporting warnings.
+///
+/// As [from_str_ext], additionally reporting any non-fatal [Warning](crate::Warning)s raised
+/// by lenient import behavior settings.
+pub fn from_str_report(src: &str, settings: &LoadSettings) -> crate::Result<LoadReport> {
+    FontBuilderXml::new(settings).load_str(src)?.build_report()
 }
 
 /// Load XML format font.
@@ -72,14 +85,23 @@ pub fn from_bytes(bytes: &[u8]) -> crate::Result<Font> {
 /// This function specifies Font import behavior, allowing us to import certain partially
 /// broken/ non-compliant BMFont files.
 pub fn from_bytes_ext(bytes: &[u8], settings: &LoadSettings) -> crate::Result<Font> {
-    from_str_ext(
-        std::str::from_utf8(bytes).map_err(|e| crate::Error::Parse {
-            line: None,
-            entity: "font".to_owned(),
-            err: e.to_string(),
-        })?,
-        settings,
-    )
+    from_str_ext(utf8(bytes)?, settings)
+}
+
+/// Load XML format font with the specified import behavior settings, reporting warnings.
+///
+/// As [from_bytes_ext], additionally reporting any non-fatal [Warning](crate::Warning)s raised
+/// by lenient import behavior settings.
+pub fn from_bytes_report(bytes: &[u8], settings: &LoadSettings) -> crate::Result<LoadReport> {
+    from_str_report(utf8(bytes)?, settings)
+}
+
+fn utf8(bytes: &[u8]) -> crate::Result<&str> {
+    std::str::from_utf8(bytes).map_err(|e| crate::Error::Parse {
+        line: None,
+        entity: "font".to_owned(),
+        err: e.to_string(),
+    })
 }
 
 /// Read XML format font.
@@ -119,13 +141,57 @@ pub fn from_reader_ext<R: io::Read>(mut reader: R, settings: &LoadSettings) -> c
     from_bytes_ext(&vec, settings)
 }
 
-#[derive(Debug, Default)]
+/// Read XML format font from an async reader.
+///
+/// As [from_reader], but reads from a `futures-io` [AsyncRead](futures_io::AsyncRead).
+/// This method buffers data internally, a buffered reader is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncRead;
+///
+/// async fn load<R: AsyncRead + Unpin>(reader: R) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::xml::from_async_reader(reader).await?;
+///     println!("{:?}", font);
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn from_async_reader<R: AsyncRead + Unpin>(reader: R) -> crate::Result<Font> {
+    from_async_reader_ext(reader, &Default::default()).await
+}
+
+/// Read XML format font from an async reader with the specified import behavior settings.
+///
+/// This function specifies Font import behavior, allowing us to import certain partially
+/// broken/ non-compliant BMFont files.
+#[cfg(feature = "async")]
+pub async fn from_async_reader_ext<R: AsyncRead + Unpin>(
+    mut reader: R,
+    settings: &LoadSettings,
+) -> crate::Result<Font> {
+    let mut vec = Vec::default();
+    async_io::read_to_end(&mut reader, &mut vec).await?;
+    from_bytes_ext(&vec, settings)
+}
+
+#[derive(Debug)]
 pub struct FontBuilderXml {
     builder: FontBuilder,
+    dialect: XmlDialect,
 }
 
 impl FontBuilderXml {
-    pub fn load_str(mut self, src: &str, settings: &LoadSettings) -> crate::Result<FontBuilder> {
+    pub fn new(settings: &LoadSettings) -> Self {
+        Self { builder: FontBuilder::new(settings), dialect: settings.xml_dialect }
+    }
+
+    pub fn load_str(mut self, src: &str) -> crate::Result<FontBuilder> {
         let document = xml::Document::parse(src).map_err(|e| crate::Error::Parse {
             line: None,
             entity: "font".to_owned(),
@@ -134,7 +200,8 @@ impl FontBuilderXml {
         let root = document.root_element();
         check_tag_name(&root, "font")?;
         check_null_attributes(&root)?;
-        child_elements(&root, |root| self.root_child(root, settings.ignore_invalid_tags))?;
+        let ignore_invalid_tags = self.builder.settings().ignore_invalid_tags;
+        child_elements(&root, |root| self.root_child(root, ignore_invalid_tags))?;
         Ok(self.builder)
     }
 
@@ -157,7 +224,16 @@ impl FontBuilderXml {
 
     fn info(&mut self, node: &xml::Node) -> crate::Result<()> {
         debug_assert!(node.node_type() == xml::NodeType::Element);
-        self.builder.set_info_attributes(None, &mut node.attributes())
+        if !self.dialect.letter_spacing() {
+            return self.builder.set_info_attributes(None, &mut node.attributes());
+        }
+        let mut attributes = node.attributes();
+        let mut intercept = Intercept::new(&mut attributes, b"letterSpacing");
+        self.builder.set_info_attributes(None, &mut intercept)?;
+        match intercept.value() {
+            Some(attribute) => self.builder.set_letter_spacing_attributes(&mut Some(attribute)),
+            None => Ok(()),
+        }
     }
 
     fn common(&mut self, node: &xml::Node) -> crate::Result<()> {
@@ -175,19 +251,23 @@ impl FontBuilderXml {
 
     fn chars(&mut self, node: &xml::Node) -> crate::Result<()> {
         debug_assert!(node.node_type() == xml::NodeType::Element);
-        self.builder.set_char_count_attributes(None, &mut node.attributes())?;
+        if self.dialect.char_count() || node.has_attribute("count") {
+            self.builder.set_char_count_attributes(None, &mut node.attributes())?;
+        }
         child_elements(node, |node| {
             check_tag_name(node, "char")?;
-            self.builder.add_char_attributes(&mut node.attributes())
+            self.builder.add_char_attributes(None, &mut node.attributes())
         })
     }
 
     fn kernings(&mut self, node: &xml::Node) -> crate::Result<()> {
         debug_assert!(node.node_type() == xml::NodeType::Element);
-        self.builder.set_kerning_count_attributes(None, &mut node.attributes())?;
+        if self.dialect.kerning_count() || node.has_attribute("count") {
+            self.builder.set_kerning_count_attributes(None, &mut node.attributes())?;
+        }
         child_elements(node, |node| {
             check_tag_name(node, "kerning")?;
-            self.builder.add_kerning_attributes(&mut node.attributes())
+            self.builder.add_kerning_attributes(None, &mut node.attributes())
         })
     }
 }
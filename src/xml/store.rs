@@ -1,4 +1,11 @@
 use crate::font::{Char, Common, Font, Info, Kerning};
+use crate::writer::{FontWriter, Format, StoreStream};
+use crate::{StoreSettings, XmlDialect};
+
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncWrite;
 
 use std::io;
 
@@ -24,7 +31,29 @@ const ESCAPER_CAPACITY: usize = 256;
 /// }
 /// ```
 pub fn to_string(font: &Font) -> crate::Result<String> {
-    let vec = to_vec(font)?;
+    to_string_ext(font, &Default::default())
+}
+
+/// Store XML format font with the specified export behavior settings.
+///
+/// This function specifies Font export behavior, allowing us to emit the XML dialects of other
+/// BMFont consumers.
+///
+/// # Example
+///
+/// ```
+/// use bmfont_rs::{StoreSettings, XmlDialect};
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let settings = StoreSettings::default().xml_dialect(XmlDialect::Sparrow);
+///     let string = bmfont_rs::xml::to_string_ext(&font, &settings)?;
+///     assert!(string.starts_with("<font>"));
+///     Ok(())
+/// }
+/// ```
+pub fn to_string_ext(font: &Font, settings: &StoreSettings) -> crate::Result<String> {
+    let vec = to_vec_ext(font, settings)?;
     String::from_utf8(vec).map_err(|e| crate::Error::Parse {
         line: None,
         entity: "font".to_owned(),
@@ -51,8 +80,16 @@ pub fn to_string(font: &Font) -> crate::Result<String> {
 /// }
 /// ```
 pub fn to_vec(font: &Font) -> crate::Result<Vec<u8>> {
+    to_vec_ext(font, &Default::default())
+}
+
+/// Store XML format font with the specified export behavior settings.
+///
+/// This function specifies Font export behavior, allowing us to emit the XML dialects of other
+/// BMFont consumers.
+pub fn to_vec_ext(font: &Font, settings: &StoreSettings) -> crate::Result<Vec<u8>> {
     let mut vec: Vec<u8> = Vec::default();
-    to_writer(&mut vec, font)?;
+    to_writer_ext(&mut vec, font, settings)?;
     Ok(vec)
 }
 
@@ -79,44 +116,155 @@ pub fn to_vec(font: &Font) -> crate::Result<Vec<u8>> {
 ///     Ok(())
 /// }
 /// ```
-pub fn to_writer<W: io::Write>(mut writer: W, font: &Font) -> crate::Result<()> {
-    let mut escaper = Escaper::with_capacity(ESCAPER_CAPACITY);
-    font.store(&mut writer, &mut escaper)
+pub fn to_writer<W: io::Write>(writer: W, font: &Font) -> crate::Result<()> {
+    to_writer_ext(writer, font, &Default::default())
 }
 
-trait StoreXml {
-    fn store<W: io::Write>(&self, writer: W, escaper: &mut Escaper) -> crate::Result<()>;
+/// Write XML format font with the specified export behavior settings.
+///
+/// This function specifies Font export behavior, allowing us to emit the XML dialects of other
+/// BMFont consumers.
+pub fn to_writer_ext<W: io::Write>(
+    writer: W,
+    font: &Font,
+    settings: &StoreSettings,
+) -> crate::Result<()> {
+    let mut writer = FontWriter::with_settings(writer, Format::Xml, settings);
+    writer.write_font(font)?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Write XML format font to an async writer.
+///
+/// As [to_writer], but writes to a `futures-io` [AsyncWrite](futures_io::AsyncWrite). The writer
+/// is flushed on completion.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncWrite;
+///
+/// async fn store<W: AsyncWrite + Unpin>(writer: W) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     bmfont_rs::xml::to_async_writer(writer, &font).await?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn to_async_writer<W: AsyncWrite + Unpin>(writer: W, font: &Font) -> crate::Result<()> {
+    to_async_writer_ext(writer, font, &Default::default()).await
+}
+
+/// Write XML format font to an async writer with the specified export behavior settings.
+///
+/// This function specifies Font export behavior, allowing us to emit the XML dialects of other
+/// BMFont consumers.
+#[cfg(feature = "async")]
+pub async fn to_async_writer_ext<W: AsyncWrite + Unpin>(
+    mut writer: W,
+    font: &Font,
+    settings: &StoreSettings,
+) -> crate::Result<()> {
+    let vec = to_vec_ext(font, settings)?;
+    async_io::write_all(&mut writer, &vec).await?;
+    Ok(())
+}
+
+/// XML format streaming store.
+#[derive(Debug)]
+pub(crate) struct StoreXmlStream {
+    escaper: Escaper,
+    dialect: XmlDialect,
+}
+
+impl StoreXmlStream {
+    pub fn new(dialect: XmlDialect) -> Self {
+        Self { escaper: Escaper::with_capacity(ESCAPER_CAPACITY), dialect }
+    }
 }
 
-impl StoreXml for Font {
-    fn store<W: io::Write>(&self, mut writer: W, escaper: &mut Escaper) -> crate::Result<()> {
-        writeln!(writer, "<?xml version=\"1.0\"?>")?;
+impl StoreStream for StoreXmlStream {
+    fn info<W: io::Write>(&mut self, writer: &mut W, info: &Info) -> crate::Result<()> {
+        if self.dialect.declaration() {
+            writeln!(writer, "<?xml version=\"1.0\"?>")?;
+        }
         writeln!(writer, "<font>")?;
-        self.info.store(&mut writer, escaper)?;
-        self.common.store(&mut writer, escaper)?;
+        info.store(writer, &mut self.escaper, self.dialect)
+    }
+
+    fn common<W: io::Write>(&mut self, writer: &mut W, common: &Common) -> crate::Result<()> {
+        common.store(&mut *writer, &mut self.escaper, self.dialect)?;
         writeln!(writer, "  <pages>")?;
-        for (i, page) in self.pages.iter().enumerate() {
-            write!(
-                writer,
-                "    <page id=\"{}\" file=\"{}\" />",
-                i,
-                escaper.escape_value("page id", page)?
-            )?;
-        }
+        Ok(())
+    }
+
+    fn page<W: io::Write>(&mut self, writer: &mut W, id: u32, file: &str) -> crate::Result<()> {
+        write!(
+            writer,
+            "    <page id=\"{}\" file=\"{}\" />",
+            id,
+            self.escaper.escape_value("page id", file)?
+        )?;
+        Ok(())
+    }
+
+    fn chars<W: io::Write>(&mut self, writer: &mut W, count: u32) -> crate::Result<()> {
         writeln!(writer, "  </pages>")?;
-        writeln!(writer, "  <chars count=\"{}\">", self.chars.len())?;
-        self.chars.iter().try_for_each(|u| u.store(&mut writer, escaper))?;
+        if self.dialect.char_count() {
+            writeln!(writer, "  <chars count=\"{}\">", count)?;
+        } else {
+            writeln!(writer, "  <chars>")?;
+        }
+        Ok(())
+    }
+
+    fn char<W: io::Write>(&mut self, writer: &mut W, char: &Char) -> crate::Result<()> {
+        char.store(writer, &mut self.escaper, self.dialect)
+    }
+
+    fn kernings<W: io::Write>(&mut self, writer: &mut W, count: u32) -> crate::Result<()> {
         writeln!(writer, "  </chars>")?;
-        writeln!(writer, "  <kernings count=\"{}\">", self.kernings.len())?;
-        self.kernings.iter().try_for_each(|u| u.store(&mut writer, escaper))?;
+        if self.dialect.kerning_count() {
+            writeln!(writer, "  <kernings count=\"{}\">", count)?;
+        } else {
+            writeln!(writer, "  <kernings>")?;
+        }
+        Ok(())
+    }
+
+    fn kerning<W: io::Write>(&mut self, writer: &mut W, kerning: &Kerning) -> crate::Result<()> {
+        kerning.store(writer, &mut self.escaper, self.dialect)
+    }
+
+    fn finish<W: io::Write>(&mut self, writer: &mut W) -> crate::Result<()> {
         writeln!(writer, "  </kernings>")?;
         writeln!(writer, "</font>")?;
         Ok(())
     }
 }
 
+trait StoreXml {
+    fn store<W: io::Write>(
+        &self,
+        writer: W,
+        escaper: &mut Escaper,
+        dialect: XmlDialect,
+    ) -> crate::Result<()>;
+}
+
 impl StoreXml for Char {
-    fn store<W: io::Write>(&self, mut writer: W, _: &mut Escaper) -> crate::Result<()> {
+    fn store<W: io::Write>(
+        &self,
+        mut writer: W,
+        _: &mut Escaper,
+        _: XmlDialect,
+    ) -> crate::Result<()> {
         writeln!(
             writer,
             "    <char \
@@ -147,7 +295,12 @@ impl StoreXml for Char {
 }
 
 impl StoreXml for Common {
-    fn store<W: io::Write>(&self, mut writer: W, _: &mut Escaper) -> crate::Result<()> {
+    fn store<W: io::Write>(
+        &self,
+        mut writer: W,
+        _: &mut Escaper,
+        _: XmlDialect,
+    ) -> crate::Result<()> {
         writeln!(
             writer,
             "  <common \
@@ -178,7 +331,12 @@ impl StoreXml for Common {
 }
 
 impl StoreXml for Info {
-    fn store<W: io::Write>(&self, mut writer: W, escaper: &mut Escaper) -> crate::Result<()> {
+    fn store<W: io::Write>(
+        &self,
+        mut writer: W,
+        escaper: &mut Escaper,
+        dialect: XmlDialect,
+    ) -> crate::Result<()> {
         writeln!(
             writer,
             "  <info \
@@ -193,7 +351,7 @@ impl StoreXml for Info {
                    aa=\"{}\" \
                    padding=\"{},{},{},{}\" \
                    spacing=\"{},{}\" \
-                   outline=\"{}\" \
+                   outline=\"{}\"{} \
                />",
             escaper.escape_value("info face", &self.face)?,
             self.size,
@@ -210,14 +368,20 @@ impl StoreXml for Info {
             self.padding.left,
             self.spacing.horizontal,
             self.spacing.vertical,
-            self.outline
+            self.outline,
+            if dialect.letter_spacing() { " letterSpacing=\"0\"" } else { "" }
         )
         .map_err(Into::into)
     }
 }
 
 impl StoreXml for Kerning {
-    fn store<W: io::Write>(&self, mut writer: W, _: &mut Escaper) -> crate::Result<()> {
+    fn store<W: io::Write>(
+        &self,
+        mut writer: W,
+        _: &mut Escaper,
+        _: XmlDialect,
+    ) -> crate::Result<()> {
         writeln!(
             writer,
             "    <kerning first=\"{}\" second=\"{}\" amount=\"{}\" />",
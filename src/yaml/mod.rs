@@ -0,0 +1,14 @@
+//! YAML format operations.
+//!
+//! Characters and kerning pairs are written as compact one line entries.
+
+mod load;
+mod store;
+
+pub use load::{from_bytes, from_bytes_ext, from_reader, from_reader_ext, from_str, from_str_ext};
+pub use store::{to_string, to_vec, to_writer};
+
+#[cfg(feature = "async")]
+pub use load::{from_async_reader, from_async_reader_ext};
+#[cfg(feature = "async")]
+pub use store::to_async_writer;
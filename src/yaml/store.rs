@@ -0,0 +1,181 @@
+use crate::font::{Char, Common, Font, Info, Kerning};
+use crate::native_bool::{CommonDef, InfoDef};
+
+#[cfg(feature = "async")]
+use crate::async_io;
+#[cfg(feature = "async")]
+use futures_io::AsyncWrite;
+
+use serde::Serialize;
+
+use std::fmt::{self, Write};
+use std::io;
+
+/// Store YAML format font.
+///
+/// Store a font into a [String] in YAML format.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let string = bmfont_rs::yaml::to_string(&font)?;
+///     println!("{}", string);
+///     Ok(())
+/// }
+/// ```
+pub fn to_string(font: &Font) -> crate::Result<String> {
+    let mut dst = serde_yaml::to_string(&Head::from(font)).map_err(|e| {
+        crate::Error::UnsupportedEncoding {
+            line: None,
+            entity: "yaml".to_owned(),
+            err: e.to_string(),
+        }
+    })?;
+    flow_seq(&mut dst, "chars", &font.chars, char)
+        .and_then(|_| flow_seq(&mut dst, "kernings", &font.kernings, kerning))
+        .map_err(|e| crate::Error::Internal { err: e.to_string() })?;
+    Ok(dst)
+}
+
+/// Store YAML format font.
+///
+/// Store a font into a [Vec] in YAML format.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let vec = bmfont_rs::yaml::to_vec(&font)?;
+///     println!("{:02X?}", font);
+///     Ok(())
+/// }
+/// ```
+pub fn to_vec(font: &Font) -> crate::Result<Vec<u8>> {
+    to_string(font).map(String::into_bytes)
+}
+
+/// Write YAML format font.
+///
+/// Write a font to the specified writer in YAML format.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use std::io;
+/// use std::io::prelude::*;
+/// use std::fs::File;
+///
+/// fn main() -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     let mut writer = File::create("font.yaml")?;
+///     bmfont_rs::yaml::to_writer(&mut writer, &font)?;
+///     Ok(())
+/// }
+/// ```
+pub fn to_writer<W: io::Write>(mut writer: W, font: &Font) -> crate::Result<()> {
+    let yaml = to_string(font)?;
+    writer.write_all(yaml.as_bytes()).map_err(Into::into)
+}
+
+/// Write YAML format font to an async writer.
+///
+/// As [to_writer], but writes to a `futures-io` [AsyncWrite](futures_io::AsyncWrite). The writer
+/// is flushed on completion.
+/// This method buffers data internally, a buffered writer is not needed.
+///
+/// # Errors
+///
+/// * [Error](crate::Error) detailing the nature of any errors.
+///
+/// # Example
+///
+/// ```no_run
+/// use futures_io::AsyncWrite;
+///
+/// async fn store<W: AsyncWrite + Unpin>(writer: W) -> bmfont_rs::Result<()> {
+///     let font = bmfont_rs::Font::default();
+///     bmfont_rs::yaml::to_async_writer(writer, &font).await?;
+///     Ok(())
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub async fn to_async_writer<W: AsyncWrite + Unpin>(
+    mut writer: W,
+    font: &Font,
+) -> crate::Result<()> {
+    let vec = to_vec(font)?;
+    async_io::write_all(&mut writer, &vec).await?;
+    Ok(())
+}
+
+/// Block style header, chars and kernings follow as flow sequences.
+#[derive(Serialize)]
+struct Head<'a> {
+    #[serde(with = "InfoDef")]
+    info: &'a Info,
+    #[serde(with = "CommonDef")]
+    common: &'a Common,
+    pages: &'a [String],
+}
+
+impl<'a> From<&'a Font> for Head<'a> {
+    fn from(font: &'a Font) -> Self {
+        Self { info: &font.info, common: &font.common, pages: &font.pages }
+    }
+}
+
+fn flow_seq<T>(
+    dst: &mut String,
+    key: &str,
+    entries: &[T],
+    f: fn(&mut String, &T) -> fmt::Result,
+) -> fmt::Result {
+    if entries.is_empty() {
+        writeln!(dst, "{}: []", key)
+    } else {
+        writeln!(dst, "{}:", key)?;
+        entries.iter().try_for_each(|u| f(dst, u))
+    }
+}
+
+fn char(dst: &mut String, char: &Char) -> fmt::Result {
+    writeln!(
+        dst,
+        "- {{id: {}, x: {}, y: {}, width: {}, height: {}, xoffset: {}, yoffset: {}, \
+         xadvance: {}, page: {}, chnl: {}}}",
+        char.id,
+        char.x,
+        char.y,
+        char.width,
+        char.height,
+        char.xoffset,
+        char.yoffset,
+        char.xadvance,
+        char.page,
+        u8::from(char.chnl)
+    )
+}
+
+fn kerning(dst: &mut String, kerning: &Kerning) -> fmt::Result {
+    writeln!(
+        dst,
+        "- {{first: {}, second: {}, amount: {}}}",
+        kerning.first, kerning.second, kerning.amount
+    )
+}